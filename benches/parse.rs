@@ -0,0 +1,25 @@
+// benches/parse.rs
+//! 파서 처리량: cargo bench --bench parse [-- <fixture 디렉터리> <반복 횟수>]
+//! 기본은 tests/fixtures를 200번
+use std::path::PathBuf;
+
+fn main() -> anyhow::Result<()> {
+    // cargo bench는 "--bench"를 넘기므로 플래그는 무시
+    let mut args = std::env::args().skip(1).filter(|a| !a.starts_with("--"));
+    let dir = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures"));
+    let iterations = args.next().and_then(|s| s.parse().ok()).unwrap_or(200);
+
+    let report = etc_crawler::bench::run(&dir, iterations)?;
+    println!(
+        "parse: {} files x {} iterations, {} items in {:.3}s ({:.0} items/sec)",
+        report.files,
+        report.iterations,
+        report.items,
+        report.elapsed.as_secs_f64(),
+        report.items_per_sec()
+    );
+    Ok(())
+}
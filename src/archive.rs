@@ -0,0 +1,244 @@
+// src/archive.rs
+use anyhow::{Context, Result};
+use chrono::{Local, NaiveDate};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+use crate::atomic_write::write_atomic;
+use crate::html_write::escape;
+use crate::json_write::to_json_value;
+use crate::labels::{self, Lang};
+use crate::notice::{is_expired, Notice};
+
+/// archive.html 틀: {title} {desc} {generated} {months}와 라벨({lang} {l_*}) 치환
+const TEMPLATE: &str = r##"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<style>
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #222; }
+table { border-collapse: collapse; margin-bottom: 1.5rem; }
+th, td { padding: .3rem .8rem; border-bottom: 1px solid #ddd; text-align: left; }
+th { background: #f5f5f5; }
+td.count { text-align: right; }
+.meta { color: #777; font-size: .9rem; }
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<p class="meta">{desc} · {l_generated}: {generated}</p>
+{months}</body>
+</html>
+"##;
+
+/// 이번 실행 목록과 지난 상태(pending.json)를 합쳐 마감이 지난 항목을 마감 월 파일(YYYY-MM.jsonl)에 추가하고
+/// archive.html을 다시 만든다. 새로 추가한 건수를 반환
+/// - 상태에는 아직 마감 전인 항목만 남김(수집 목록에서 먼저 사라져도 마감이 지나면 기록됨). 마감일 없는 항목은 기록하지 않음
+/// - 월 파일에 이미 있는 guid(Notice::stable_guid)는 건너뜀(같은 날 다시 실행해도 중복 없음)
+/// - 한 줄 = json_write::to_json_value + "guid"
+pub fn update(dir: &str, live: &[Notice], today: NaiveDate, strip: &[String], lang: Lang) -> Result<usize> {
+    std::fs::create_dir_all(dir).with_context(|| format!("create {dir}"))?;
+    let dir = Path::new(dir);
+
+    // 이번 목록 우선, 상태에서는 이번 목록에 없는 guid만
+    let mut seen: HashSet<String> = HashSet::new();
+    let candidates: Vec<Notice> = live
+        .iter()
+        .cloned()
+        .chain(load_pending(dir))
+        .filter(|n| n.end.is_some() && seen.insert(n.stable_guid(strip)))
+        .collect();
+    let (expired, pending): (Vec<Notice>, Vec<Notice>) = candidates.into_iter().partition(|n| is_expired(n, today));
+
+    let mut by_month: BTreeMap<String, Vec<Notice>> = BTreeMap::new();
+    for n in expired {
+        let month = n.end.as_deref().and_then(|e| e.get(..7)).unwrap_or_default().to_string();
+        by_month.entry(month).or_default().push(n);
+    }
+    let mut appended = 0;
+    for (month, notices) in &by_month {
+        appended += append_month(&month_path(dir, month), notices, strip)?;
+    }
+
+    write_atomic(&dir.join("pending.json").display().to_string(), &serde_json::to_vec(&pending)?)?;
+    write_index(dir, lang)?;
+    if appended > 0 {
+        info!(dir = %dir.display(), appended, "archived expired notices");
+    }
+    Ok(appended)
+}
+
+/// 월 파일 경로: <dir>/YYYY-MM.jsonl
+pub fn month_path(dir: &Path, month: &str) -> PathBuf {
+    dir.join(format!("{month}.jsonl"))
+}
+
+/// 상태 파일 읽기: 없으면 빈 목록, 깨졌으면 경고 후 빈 목록
+fn load_pending(dir: &Path) -> Vec<Notice> {
+    let path = dir.join("pending.json");
+    let Ok(body) = std::fs::read(&path) else { return Vec::new(); };
+    serde_json::from_slice(&body).unwrap_or_else(|e| {
+        warn!(file = %path.display(), error = %e, "ignoring unreadable archive state");
+        Vec::new()
+    })
+}
+
+/// 월 파일에 없는 guid만 덧붙이고 추가한 건수를 반환
+fn append_month(path: &Path, notices: &[Notice], strip: &[String]) -> Result<usize> {
+    let mut guids: HashSet<String> = read_lines(path).iter().filter_map(|v| v["guid"].as_str().map(str::to_string)).collect();
+    let mut body = String::new();
+    for n in notices {
+        let guid = n.stable_guid(strip);
+        if !guids.insert(guid.clone()) {
+            continue;
+        }
+        let mut v = to_json_value(n);
+        v["guid"] = Value::String(guid);
+        body.push_str(&serde_json::to_string(&v)?);
+        body.push('\n');
+    }
+    if body.is_empty() {
+        return Ok(0);
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("open {}", path.display()))?;
+    file.write_all(body.as_bytes()).with_context(|| format!("append {}", path.display()))?;
+    Ok(body.lines().count())
+}
+
+/// JSONL 한 줄씩 파싱(깨진 줄은 건너뜀)
+fn read_lines(path: &Path) -> Vec<Value> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect()
+}
+
+/// <dir>/archive.html: 최근 월부터, 월마다 출처 × 구분 건수 표와 월 파일 링크
+fn write_index(dir: &Path, lang: Lang) -> Result<()> {
+    let mut months: Vec<String> = std::fs::read_dir(dir)
+        .with_context(|| format!("read {}", dir.display()))?
+        .filter_map(|e| e.ok()?.file_name().to_str()?.strip_suffix(".jsonl").map(str::to_string))
+        .filter(|m| NaiveDate::parse_from_str(&format!("{m}-01"), "%Y-%m-%d").is_ok())
+        .collect();
+    months.sort_unstable_by(|a, b| b.cmp(a));
+
+    let l = labels::table_labels(lang);
+    let mut sections = String::new();
+    for month in &months {
+        let mut counts: BTreeMap<(String, String), usize> = BTreeMap::new();
+        let mut total = 0;
+        for v in read_lines(&month_path(dir, month)) {
+            let Ok(n) = serde_json::from_value::<Notice>(v) else { continue; };
+            let key = (labels::source_label(&n.source, lang).to_string(), labels::kind_label(&n.kind, lang).to_string());
+            *counts.entry(key).or_default() += 1;
+            total += 1;
+        }
+        let rows: String = counts
+            .iter()
+            .map(|((source, kind), c)| format!("<tr><td>{}</td><td>{}</td><td class=\"count\">{c}</td></tr>\n", escape(source), escape(kind)))
+            .collect();
+        sections.push_str(&format!(
+            "<h2 id=\"{month}\">{month} <span class=\"meta\">{}</span></h2>\n<p><a href=\"{month}.jsonl\">{month}.jsonl</a></p>\n\
+             <table>\n<thead><tr><th>{}</th><th>{}</th><th>{}</th></tr></thead>\n<tbody>\n{rows}</tbody>\n</table>\n",
+            labels::count(total, lang),
+            l.source,
+            l.kind,
+            l.count,
+        ));
+    }
+
+    let (title, desc) = labels::channel_meta("archive_index", lang);
+    let body = TEMPLATE
+        .replace("{lang}", l.html_lang)
+        .replace("{l_generated}", l.generated)
+        .replace("{title}", title)
+        .replace("{desc}", desc)
+        .replace("{generated}", &Local::now().format("%Y-%m-%d %H:%M").to_string())
+        .replace("{months}", &sections);
+    write_atomic(&dir.join("archive.html").display().to_string(), body.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::{default_strip_params, Kind, Source};
+
+    fn notice(source: Source, id: u32, end: &str) -> Notice {
+        Notice {
+            source,
+            kind: Kind::Contest,
+            title: format!("공모전 {id}"),
+            raw_title: String::new(),
+            url: format!("https://example.com/{id}"),
+            start: None,
+            end: Some(end.into()),
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    fn day(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    fn titles(path: &Path) -> Vec<String> {
+        read_lines(path).iter().map(|v| v["title"].as_str().unwrap().to_string()).collect()
+    }
+
+    #[test]
+    fn expired_notices_land_in_their_deadline_month_across_runs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("archive");
+        let strip = default_strip_params();
+        let live = [notice(Source::Wevity, 1, "2026-10-30"), notice(Source::Dacon, 2, "2026-10-31"), notice(Source::Dacon, 3, "2026-11-01")];
+
+        // 10/31: 1만 마감이 지남, 2·3은 상태로 넘어감
+        assert_eq!(update(dir.to_str().unwrap(), &live, day(10, 31), &strip, Lang::Ko).unwrap(), 1);
+        assert_eq!(titles(&month_path(&dir, "2026-10")), ["공모전 1"]);
+        assert!(!month_path(&dir, "2026-11").exists());
+
+        // 11/02: 소스 목록에서 사라졌어도 상태에 남은 2·3이 각자 마감 월로
+        assert_eq!(update(dir.to_str().unwrap(), &[], day(11, 2), &strip, Lang::Ko).unwrap(), 2);
+        assert_eq!(titles(&month_path(&dir, "2026-10")), ["공모전 1", "공모전 2"]);
+        assert_eq!(titles(&month_path(&dir, "2026-11")), ["공모전 3"]);
+        assert_eq!(read_lines(&month_path(&dir, "2026-11"))[0]["guid"], live[2].stable_guid(&strip));
+
+        let html = std::fs::read_to_string(dir.join("archive.html")).unwrap();
+        let (nov, oct) = (html.find("<h2 id=\"2026-11\">").unwrap(), html.find("<h2 id=\"2026-10\">").unwrap());
+        assert!(nov < oct, "{html}");
+        assert!(html.contains("2026-10 <span class=\"meta\">(2건)</span>"), "{html}");
+        assert!(html[oct..].contains("<td>위비티</td><td>공모전</td><td class=\"count\">1</td>"), "{html}");
+        assert!(html[oct..].contains("<td>데이콘</td><td>공모전</td><td class=\"count\">1</td>"), "{html}");
+    }
+
+    #[test]
+    fn rerunning_the_same_day_adds_no_duplicates() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("archive");
+        let dir_str = dir.to_str().unwrap();
+        let strip = default_strip_params();
+        // 마감이 지났는데도 목록에 계속 나오는 항목
+        let live = [notice(Source::Wevity, 1, "2026-10-10"), notice(Source::Wevity, 2, "2026-10-20")];
+
+        assert_eq!(update(dir_str, &live, day(10, 14), &strip, Lang::Ko).unwrap(), 1);
+        assert_eq!(update(dir_str, &live, day(10, 14), &strip, Lang::Ko).unwrap(), 0);
+        // 상태 파일을 잃어도 월 파일의 guid로 중복을 막음
+        std::fs::remove_file(dir.join("pending.json")).unwrap();
+        assert_eq!(update(dir_str, &live, day(10, 14), &strip, Lang::Ko).unwrap(), 0);
+        assert_eq!(titles(&month_path(&dir, "2026-10")), ["공모전 1"]);
+    }
+}
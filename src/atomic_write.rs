@@ -0,0 +1,118 @@
+// src/atomic_write.rs
+use anyhow::Result;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// 출력 파일의 디렉터리를 만들거나 그 안에 쓸 수 없을 때의 오류
+/// (anyhow로 감싸 반환하므로 필요하면 downcast_ref::<NotWritable>()로 구분)
+#[derive(Debug)]
+pub struct NotWritable {
+    pub dir: PathBuf,
+    pub source: io::Error,
+}
+
+impl fmt::Display for NotWritable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "output directory not writable: {}", self.dir.display())
+    }
+}
+
+impl std::error::Error for NotWritable {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// 같은 디렉터리의 임시 파일 경로(rename이 원자적이도록 같은 파일시스템)
+pub fn tmp_path(output_file: &str) -> String {
+    format!("{output_file}.tmp")
+}
+
+/// 임시 파일에 쓰고 rename(중간에 죽어도 대상 파일이 비거나 반쯤 쓰인 채로 남지 않음)
+pub fn write_atomic(output_file: &str, body: &[u8]) -> Result<()> {
+    write_atomic_checked(output_file, body, |_| Ok(()))
+}
+
+/// write_atomic + rename 전에 임시 파일 검사(check가 Err면 임시 파일을 지우고 기존 파일 유지)
+/// 상위 디렉터리가 없으면 만든다
+pub fn write_atomic_checked(
+    output_file: &str,
+    body: &[u8],
+    check: impl FnOnce(&str) -> Result<()>,
+) -> Result<()> {
+    let dir = match Path::new(output_file).parent() {
+        Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    std::fs::create_dir_all(&dir).map_err(|source| NotWritable { dir: dir.clone(), source })?;
+
+    let tmp = tmp_path(output_file);
+    if let Err(e) = std::fs::write(&tmp, body) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(match e.kind() {
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => NotWritable { dir, source: e }.into(),
+            _ => e.into(),
+        });
+    }
+    if let Err(e) = check(&tmp) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e);
+    }
+    std::fs::rename(&tmp, output_file).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn successful_write_replaces_file_and_leaves_no_tmp() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("feed.xml").display().to_string();
+        write_atomic(&out, b"first").unwrap();
+        write_atomic(&out, b"second").unwrap();
+        assert_eq!(std::fs::read(&out).unwrap(), b"second");
+        assert!(!Path::new(&tmp_path(&out)).exists());
+    }
+
+    #[test]
+    fn deep_missing_directories_are_created() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("site/feeds/2026/merged_rss.xml").display().to_string();
+        write_atomic(&out, b"<rss/>").unwrap();
+        assert_eq!(std::fs::read(&out).unwrap(), b"<rss/>");
+        assert!(!Path::new(&tmp_path(&out)).exists());
+    }
+
+    #[test]
+    fn failed_check_keeps_previous_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("feed.xml").display().to_string();
+        write_atomic(&out, b"old").unwrap();
+        // rename 전 임시 파일에 새 내용이 있고, 검사가 실패하면 대상은 그대로
+        let err = write_atomic_checked(&out, b"new", |tmp| {
+            assert_eq!(std::fs::read(tmp).unwrap(), b"new");
+            anyhow::bail!("bad output")
+        });
+        assert!(err.is_err());
+        assert_eq!(std::fs::read(&out).unwrap(), b"old");
+        assert!(!Path::new(&tmp_path(&out)).exists());
+    }
+
+    #[test]
+    fn unusable_directory_is_a_not_writable_error() {
+        let dir = tempfile::tempdir().unwrap();
+        // 디렉터리 자리에 일반 파일이 있으면 만들 수 없음
+        let blocker = dir.path().join("out");
+        std::fs::write(&blocker, "").unwrap();
+        let out = blocker.join("nested/feed.xml").display().to_string();
+        let err = write_atomic(&out, b"x").unwrap_err();
+        let not_writable = err.downcast_ref::<NotWritable>().expect("NotWritable");
+        assert_eq!(not_writable.dir, blocker.join("nested"));
+        assert!(err.to_string().starts_with("output directory not writable"), "{err}");
+    }
+}
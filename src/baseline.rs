@@ -0,0 +1,111 @@
+// src/baseline.rs
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 소스별 최근 수집 건수 기록(BASELINE_FILE에 JSON으로 저장)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default)]
+    history: BTreeMap<String, Vec<usize>>,
+}
+
+impl Baseline {
+    /// 파일이 없거나 깨져 있으면 빈 기록으로 시작
+    pub fn load(path: &str) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        crate::atomic_write::write_atomic(path, serde_json::to_string_pretty(self)?.as_bytes())
+    }
+
+    /// 기준값(최근 기록의 최솟값) 대비 0건이거나 ratio 미만으로 떨어졌으면 경고 문구
+    pub fn check(&self, source: &str, count: usize, ratio: f64) -> Option<String> {
+        let base = *self.history.get(source)?.iter().min()?;
+        if base == 0 {
+            return None;
+        }
+        if count == 0 {
+            Some(format!("{source} dropped to 0 items (baseline {base})"))
+        } else if (count as f64) < base as f64 * ratio {
+            Some(format!("{source} dropped to {count} items (baseline {base})"))
+        } else {
+            None
+        }
+    }
+
+    /// 정상 건수만 기록(0건은 고장일 가능성이 커서 기준값에 넣지 않음), 최근 keep회 유지
+    pub fn record(&mut self, source: &str, count: usize, keep: usize) {
+        if count == 0 {
+            return;
+        }
+        let runs = self.history.entry(source.to_string()).or_default();
+        runs.push(count);
+        if runs.len() > keep {
+            let drop_n = runs.len() - keep;
+            runs.drain(..drop_n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_runs(source: &str, runs: &[usize]) -> Baseline {
+        let mut base = Baseline::default();
+        for &n in runs {
+            base.record(source, n, 10);
+        }
+        base
+    }
+
+    #[test]
+    fn zero_items_against_a_positive_baseline_warns() {
+        let base = with_runs("wevity", &[40, 30]);
+        assert_eq!(base.check("wevity", 0, 0.5).as_deref(), Some("wevity dropped to 0 items (baseline 30)"));
+        // 기록이 없는 소스는 비교할 기준이 없음
+        assert_eq!(base.check("dacon", 0, 0.5), None);
+    }
+
+    #[test]
+    fn warns_only_below_the_ratio_of_the_minimum() {
+        let base = with_runs("dacon", &[120, 100]);
+        assert_eq!(base.check("dacon", 50, 0.5), None);
+        assert_eq!(base.check("dacon", 49, 0.5).as_deref(), Some("dacon dropped to 49 items (baseline 100)"));
+        assert_eq!(base.check("dacon", 150, 0.5), None);
+    }
+
+    #[test]
+    fn zero_counts_are_not_recorded() {
+        let mut base = with_runs("linkareer", &[0]);
+        assert!(!base.history.contains_key("linkareer"));
+        base.record("linkareer", 20, 10);
+        base.record("linkareer", 0, 10);
+        assert_eq!(base.history["linkareer"], [20]);
+    }
+
+    #[test]
+    fn keeps_only_the_latest_runs() {
+        let mut base = Baseline::default();
+        for n in [100, 20, 100, 100] {
+            base.record("campuspick", n, 2);
+        }
+        assert_eq!(base.history["campuspick"], [100, 100]);
+        // 잘려 나간 20이 기준값에 남아 있었다면 경고하지 않았을 건수
+        assert!(base.check("campuspick", 40, 0.5).is_some());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json").display().to_string();
+        with_runs("wevity", &[10, 12]).save(&path).unwrap();
+        assert_eq!(Baseline::load(&path).history["wevity"], [10, 12]);
+        assert!(Baseline::load(&dir.path().join("missing.json").display().to_string()).history.is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+// src/bench.rs
+//! 저장해 둔 페이지/응답(fixture)으로 파서 처리량 측정(네트워크 없음)
+//! 파일 이름 앞부분으로 파서를 고름:
+//! - wevity_list*.html / wevity_detail*.html
+//! - campuspick_*list*.json / campuspick_detail*.json
+//! - dacon*.json
+//!
+//! 그 외 파일은 건너뜀
+use anyhow::{bail, Context, Result};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::{campuspick, dacon, wevity};
+
+/// 측정 결과(items는 모든 반복을 합친 파싱 건수)
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub files: usize,
+    pub iterations: usize,
+    pub items: usize,
+    pub elapsed: Duration,
+}
+
+impl BenchReport {
+    pub fn items_per_sec(&self) -> f64 {
+        self.items as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Parser {
+    WevityList,
+    WevityDetail,
+    CampuspickList,
+    CampuspickDetail,
+    Dacon,
+}
+
+fn parser_for(name: &str) -> Option<Parser> {
+    match name {
+        n if n.starts_with("wevity_list") => Some(Parser::WevityList),
+        n if n.starts_with("wevity_detail") => Some(Parser::WevityDetail),
+        n if n.starts_with("campuspick_detail") => Some(Parser::CampuspickDetail),
+        n if n.starts_with("campuspick_") && n.contains("list") => Some(Parser::CampuspickList),
+        n if n.starts_with("dacon") => Some(Parser::Dacon),
+        _ => None,
+    }
+}
+
+/// 파일 하나를 파싱해 얻은 건수
+fn parse_once(parser: Parser, body: &str, base: &url::Url) -> Result<usize> {
+    Ok(match parser {
+        Parser::WevityList => wevity::parse_list_page(body, base).len(),
+        Parser::WevityDetail => {
+            let c = wevity::parse_detail_page(body, base.to_string(), String::new(), None, "공모전");
+            usize::from(c.is_some())
+        }
+        Parser::CampuspickList => campuspick::parse_list_json(body, None)?.len(),
+        Parser::CampuspickDetail => usize::from(campuspick::parse_detail_json(body).is_some()),
+        Parser::Dacon => dacon::parse_items(body)?.iter().map(dacon::to_notice_from_dacon).count(),
+    })
+}
+
+/// dir의 fixture를 iterations번 파싱(파일 읽기는 측정에서 제외)
+/// 인식한 파일이 없으면 Err
+pub fn run(dir: &Path, iterations: usize) -> Result<BenchReport> {
+    let mut inputs = Vec::new();
+    for entry in std::fs::read_dir(dir).with_context(|| format!("read {}", dir.display()))? {
+        let path = entry?.path();
+        let Some(parser) = path.file_name().and_then(|n| n.to_str()).and_then(parser_for) else { continue };
+        let body = std::fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+        inputs.push((parser, body));
+    }
+    if inputs.is_empty() {
+        bail!("no fixtures recognized in {}", dir.display());
+    }
+
+    let base = url::Url::parse(wevity::BASE_URL)?;
+    let iterations = iterations.max(1);
+    let started = Instant::now();
+    let mut items = 0;
+    for _ in 0..iterations {
+        for (parser, body) in &inputs {
+            items += black_box(parse_once(*parser, black_box(body), &base)?);
+        }
+    }
+    Ok(BenchReport { files: inputs.len(), iterations, items, elapsed: started.elapsed() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn processes_fixtures_and_reports_a_rate() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let report = run(&dir, 2).unwrap();
+        assert_eq!(report.files, 8);
+        assert_eq!(report.iterations, 2);
+        // wevity 목록 3 + 상세 3 + campuspick 목록 6 + 상세 1 + dacon 3, 두 번씩
+        assert_eq!(report.items, 2 * 16);
+        assert!(report.items_per_sec() > 0.0);
+    }
+
+    #[test]
+    fn empty_directory_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(run(dir.path(), 1).is_err());
+    }
+}
@@ -1,494 +1,943 @@
-use anyhow::{Context, Result};
-use chrono::{Datelike, Local, NaiveDate};
-use clap::Parser;
-use regex::Regex;
-use reqwest::header::{ACCEPT, CONTENT_TYPE};
-use scraper::{Html, Selector};
-use serde_json::Value;
-use std::{collections::HashSet, time::Duration};
-use crate::notice::{Notice, Source, Kind, infer_kind_from_label};
-
-/// 캠퍼스픽 웹 사이트 URL
-const WEB_BASE: &str = "https://www.campuspick.com/";
-
-#[derive(Parser, Debug)]
-#[command(
-    name="campuspick-filter",
-    about="Campuspick crawler (contest: category108, activity: title keywords) + detail start~end/company fill"
-)]
-struct Args {
-    /// 대외활동 목록 API
-    #[arg(long, default_value = "https://api2.campuspick.com/find/activity/list")]
-    activity_api: String,
-    /// 공모전 목록 API
-    #[arg(long, default_value = "https://api2.campuspick.com/find/activity/list")]
-    contest_api: String,
-
-    /// 대외활동 목록 HTTP 메서드
-    #[arg(long, default_value = "POST")]
-    activity_method: String,
-    /// 공모전 목록 HTTP 메서드
-    #[arg(long, default_value = "POST")]
-    contest_method: String,
-
-    #[arg(long, default_value = "target=2&limit={limit}&offset={offset}")]
-    activity_body: String,
-    #[arg(long, default_value = "target=1&limit={limit}&offset={offset}&category=108")]
-    contest_body: String,
-
-    /// 페이지당 개수
-    #[arg(long, default_value_t = 100)]
-    limit: usize,
-    /// 페이지 수
-    #[arg(long, default_value_t = 5)]
-    pages: usize,
-
-    /// 마감일까지 남은 일수 필터(20일 이내만)
-#[arg(long, default_value_t = 20)]
-deadline_days: i64,
-
-    #[arg(long, default_value_t = 300)]
-    delay_ms: u64,
-}
-
-pub async fn collect() -> Result<Vec<Row>> {
-    let args = Args::parse();
-    let client = reqwest::Client::builder()
-        .user_agent("campuspick-filter/0.6.0 (+contact@example.com)")
-        .build()?;
-
-    let mut out = Vec::<Row>::new();
-
-    // 대외활동 수집
-    out.extend(
-        fetch_one_kind(
-            &client, "activity",
-            &args.activity_api, &args.activity_method, &args.activity_body,
-            args.pages, args.limit, args.deadline_days, args.delay_ms
-        ).await?
-    );
-
-    // 공모전 수집
-    out.extend(
-        fetch_one_kind(
-            &client, "contest",
-            &args.contest_api, &args.contest_method, &args.contest_body,
-            args.pages, args.limit, args.deadline_days, args.delay_ms
-        ).await?
-    );
-
-    out.sort_by(|a,b| a.start.is_none().cmp(&b.start.is_none())
-        .then(a.start.cmp(&b.start))
-        .then(a.end.cmp(&b.end))
-        .then(a.title.cmp(&b.title)));
-    Ok(out)
-}
-
-#[derive(Clone, Debug)]
-pub struct Row {
-    pub kind: String,          // activity / contest
-    pub title: String,         // 제목
-    pub url: String,           // 상세 URL
-    pub start: Option<String>, // 시작일(YYYY-MM-DD)
-    pub end: Option<String>,   // 마감일(YYYY-MM-DD)
-    pub company: Option<String>, // 주최/주관(가능하면 여러 값을 " / "로 결합)
-}
-
-async fn fetch_one_kind(
-    client: &reqwest::Client,
-    kind: &str,
-    api: &str, method: &str, body_tpl: &str,
-    pages: usize, limit: usize, deadline_days: i64,
-    delay_ms: u64,
-) -> Result<Vec<Row>> {
-    let mut out = Vec::<Row>::new();
-    let mut seen = HashSet::<(String, String)>::new(); // (kind, id) 중복방지
-
-    for page in 1..=pages {
-        let offset = (page - 1) * limit;
-        let body = body_tpl.replace("{limit}", &limit.to_string())
-                           .replace("{offset}", &offset.to_string());
-
-        let mut req = if method.eq_ignore_ascii_case("POST") {
-            client.post(api)
-                .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-                .body(body)
-        } else {
-            let url = if api.contains('?') { format!("{api}&{body}") } else { format!("{api}?{body}") };
-            client.get(url)
-        };
-        req = req.header(ACCEPT, "application/json, text/plain, */*")
-                 .header("Origin", WEB_BASE)
-                 .header("Referer", format!("{WEB_BASE}{kind}"));
-
-        let resp   = req.send().await?;
-        let status = resp.status();
-        let headers = resp.headers().clone(); 
-        let text   = resp.text().await?;
-        let ctype  = headers.get(CONTENT_TYPE).and_then(|h| h.to_str().ok()).unwrap_or("");
-
-        if !status.is_success() || !ctype.starts_with("application/json") { break; }
-
-        let v: Value = serde_json::from_str(&text).with_context(|| "invalid JSON")?;
-        let Some(arr) = find_array(&v) else { break; };
-
-        'each: for it in arr {
-            // 식별자 확보
-            let Some(id) = get_id(it) else { continue };
-            if !seen.insert((kind.to_string(), id.clone())) { continue; }
-
-            // 제목 확보
-            let title = first_text(it, &["title","name","subject"]).unwrap_or_default();
-            if title.is_empty() { continue; }
-
-            // 종류(대외활동 or 공모전)별 1차 필터
-            if kind == "contest" && !match_category_108(it) { continue 'each; }
-            if kind == "activity" && !title_keyword_hit(&title) { continue 'each; }
-
-            // 목록 JSON에서 날짜/주최 추정
-            let start0 = it.get("startDate").and_then(|x| x.as_str()).map(normalize_date);
-            let end0   = it.get("endDate").and_then(|x| x.as_str()).map(normalize_date)
-                        .or_else(|| it.get("deadline").and_then(|x| x.as_str()).map(normalize_date));
-            let company0 = first_company(it);
-
-            // 상세에서 startDate/endDate/company 보완 수집
-            let (start1, end1, company1) = fill_detail_fields(client, kind, &id, end0.as_deref()).await;
-
-            let start = start0.or(start1);
-            let end   = end0.or(end1);
-            let company = company0.or(company1);
-            // D-day 필터링: 기본 20일(오늘~20일)만, 지난 마감들 제외
-            let Some(ref e) = end else { continue 'each; };
-            let days = days_until(e);
-            if !(0..=deadline_days).contains(&days) { continue 'each; }
-
-            out.push(Row {
-                kind: kind.to_string(),
-                title,
-                url: build_detail_url(kind, &id),
-                start, end, company,
-            });
-        }
-        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
-    }
-    Ok(out)
-}
-
-fn find_array<'a>(v: &'a Value) -> Option<&'a Vec<Value>> {
-    if let Some(a) = v.as_array() { return Some(a); }
-    for k in ["items","list","data","results","content","rows","posts","payload"] {
-        if let Some(a) = v.get(k).and_then(|x| x.as_array()) { return Some(a); }
-    }
-    if let Some(obj) = v.as_object() {
-        for vv in obj.values() {
-            if let Some(a) = find_array(vv) { return Some(a); }
-        }
-    }
-    None
-}
-
-fn first_text(v: &Value, keys: &[&str]) -> Option<String> {
-    for k in keys {
-        if let Some(s) = v.get(*k).and_then(|x| x.as_str()) {
-            let t = s.trim();
-            if !t.is_empty() { return Some(t.to_string()); }
-        }
-    }
-    None
-}
-
-/// ID 문자열을 얻음
-fn get_id(v: &Value) -> Option<String> {
-    for k in ["id","idx","activityId","contestId","postId","aid","cid"] {
-        if let Some(x) = v.get(k) {
-            if let Some(s) = x.as_str() { if !s.is_empty() { return Some(s.to_string()); } }
-            if let Some(n) = x.as_i64() { return Some(n.to_string()); }
-            if let Some(n) = x.as_u64() { return Some(n.to_string()); }
-        }
-    }
-    None
-}
-
-/// 상세 페이지 URL 구성
-fn build_detail_url(kind: &str, id: &str) -> String {
-    match kind { "activity" => format!("{WEB_BASE}activity/view?id={id}"),
-                 _          => format!("{WEB_BASE}contest/view?id={id}") }
-}
-
-/// 카테고리 필드가 108(IT/소프트웨어/게임)인지 판별
-fn match_category_108(v: &Value) -> bool {
-    for k in ["category","categoryId","category_idx","categoryId1","category1","categories"] {
-        if let Some(x) = v.get(k) {
-            if let Some(n) = x.as_i64() { if n == 108 { return true; } }
-            if let Some(s) = x.as_str() {
-                if s.trim() == "108" { return true; }
-                if s.split(|c:char| c.is_ascii_punctuation() || c.is_whitespace()).any(|t| t=="108") { return true; }
-            }
-            if let Some(a) = x.as_array() {
-                if a.iter().any(|e| e.as_i64()==Some(108) || e.as_str()==Some("108")) { return true; }
-            }
-        }
-    }
-    false
-}
-
-/// 활동 제목에 키워드가 포함 검사
-fn title_keyword_hit(title: &str) -> bool {
-    const KWS: &[&str] = &[
-        "IT","SW","코딩","소프트웨어","컴퓨터","보안","정보보호","KISIA","개인정보","개발자","AI","엔지니어","부트캠프"
-    ];
-    let t = normalize(title);
-    KWS.iter().any(|kw| t.contains(&normalize(kw)))
-}
-
-/// 날짜 문자열을 YYYY-MM-DD로 통일
-fn normalize_date(s: &str) -> String {
-    let mut t = s.trim().to_string();
-    t = t.replace('.', "-").replace('/', "-");
-    if t.len() >= 10 { t[..10].to_string() } else { t }
-}
-
-/// today→마감일까지 남은 일수 계산
-fn days_until(end_ymd: &str) -> i64 {
-    let today = Local::now().date_naive();
-    NaiveDate::parse_from_str(end_ymd, "%Y-%m-%d")
-        .map(|d| (d - today).num_days())
-        .unwrap_or(i64::MAX)
-}
-
-fn normalize(s: &str) -> String {
-    s.to_lowercase()
-        .replace('\u{00A0}', " ")
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
-fn first_company(v: &Value) -> Option<String> {
-    let keys = [
-        "company","company_name","company1","company2","company3",
-        "org","organization","host","hostName","organizer","sponsor","hostOrg","host_org"
-    ];
-    for k in keys {
-        if let Some(val) = v.get(k) {
-            if let Some(s) = val.as_str() {
-                let s = s.trim();
-                if !s.is_empty() { return Some(s.to_string()); }
-            }
-            if let Some(arr) = val.as_array() {
-                let joined = arr.iter().filter_map(|e| e.as_str()).map(|s| s.trim())
-                    .filter(|s| !s.is_empty()).collect::<Vec<_>>().join(" / ");
-                if !joined.is_empty() { return Some(joined); }
-            }
-        }
-    }
-    None
-}
-
-fn extract_company_from_text(text: &str) -> Option<String> {
-    let re = Regex::new(r"(주최|주관)\s*[:：]?\s*([^\n]+)").ok()?;
-    let cap = re.captures(text)?;
-    let raw = cap.get(2)?.as_str().trim();
-    let parts = raw.split(|c: char| c == '/' || c == '|' || c == '·' || c == ',' )
-        .map(|s| s.trim()).filter(|s| !s.is_empty()).collect::<Vec<_>>();
-    if parts.is_empty() { None } else { Some(parts.join(" / ")) }
-}
-
-async fn fill_detail_fields(
-    client: &reqwest::Client,
-    kind: &str,
-    id: &str,
-    end_hint: Option<&str>,
-) -> (Option<String>, Option<String>, Option<String>) {
-    let page_url = build_detail_url(kind, id);
-    if let Ok(resp) = client.get(&page_url).send().await {
-        if resp.status().is_success() {
-            if let Ok(html) = resp.text().await {
-                let doc = Html::parse_document(&html);
-                let script_sel = Selector::parse("script").unwrap();
-                let mut scripts_text = String::new();
-                for s in doc.select(&script_sel) {
-                    scripts_text.push_str(&s.text().collect::<String>());
-                    scripts_text.push('\n');
-                }
-
-                let re_sd = Regex::new(r#"startDate\s*:\s*\"([0-9]{4}[-./][0-9]{2}[-./][0-9]{2})\""#).unwrap();
-                let re_ed = Regex::new(r#"endDate\s*:\s*\"([0-9]{4}[-./][0-9]{2}[-./][0-9]{2})\""#).unwrap();
-                let re_company = Regex::new(r#"company\d*\s*:\s*\"([^\"]+)\""#).unwrap();
-
-                let s = re_sd.captures(&scripts_text).map(|c| normalize_date(c.get(1).unwrap().as_str()));
-                let e = re_ed.captures(&scripts_text).map(|c| normalize_date(c.get(1).unwrap().as_str()));
-
-                let mut companies: Vec<String> = Vec::new();
-                for cap in re_company.captures_iter(&scripts_text) {
-                    let v = cap.get(1).unwrap().as_str().trim();
-                    if !v.is_empty() { companies.push(v.to_string()); }
-                }
-                companies.sort();
-                companies.dedup();
-                let company_inline = if companies.is_empty() { None } else { Some(companies.join(" / ")) };
-
-                if s.is_some() || e.is_some() || company_inline.is_some() {
-                    return (s, e, company_inline);
-                }
-
-                let text = extract_relevant_text(&doc);
-                if let Some((s2,e2)) = parse_dates_from_korean_or_numeric(&text, end_hint) {
-                    let comp = extract_company_from_text(&text);
-                    return (s2, e2, comp);
-                }
-            }
-        }
-    }
-
-    let json_candidates = [
-        format!("https://api2.campuspick.com/find/{kind}/view?id={id}"),
-        format!("https://api2.campuspick.com/{kind}/view?id={id}"),
-        format!("https://api2.campuspick.com/find/{kind}/detail?id={id}"),
-        format!("https://api2.campuspick.com/{kind}/detail?id={id}"),
-    ];
-    for url in json_candidates {
-        if let Ok(resp) = client.get(&url).header(ACCEPT, "application/json").send().await {
-            let status = resp.status();
-            let headers = resp.headers().clone();
-            let txt = resp.text().await.unwrap_or_default();
-            let is_json = headers.get(CONTENT_TYPE).and_then(|h| h.to_str().ok())
-                .map(|s| s.starts_with("application/json")).unwrap_or(false);
-            if !status.is_success() || !is_json { continue; }
-
-            if let Ok(v) = serde_json::from_str::<Value>(&txt) {
-                let s = v.get("startDate").and_then(|x| x.as_str())
-                         .or_else(|| v.pointer("/data/startDate").and_then(|x| x.as_str()))
-                         .map(normalize_date);
-                let e = v.get("endDate").and_then(|x| x.as_str())
-                         .or_else(|| v.get("deadline").and_then(|x| x.as_str()))
-                         .or_else(|| v.pointer("/data/endDate").and_then(|x| x.as_str()))
-                         .map(normalize_date);
-
-                let company = first_company(&v)
-                    .or_else(|| v.pointer("/data").and_then(first_company));
-
-                if s.is_some() || e.is_some() || company.is_some() {
-                    return (s, e, company);
-                }
-            }
-        }
-    }
-
-    if let Ok(resp) = client.get(&build_detail_url(kind, id)).send().await {
-        if resp.status().is_success() {
-            if let Ok(html) = resp.text().await {
-                let doc = Html::parse_document(&html);
-                let text = extract_relevant_text(&doc);
-                let de = parse_dates_from_korean_or_numeric(&text, end_hint);
-                let comp = extract_company_from_text(&text);
-                if de.is_some() || comp.is_some() {
-                    let (s,e) = de.unwrap_or((None, None));
-                    return (s, e, comp);
-                }
-            }
-        }
-    }
-
-    (None, None, None)
-}
-
-fn extract_relevant_text(doc: &Html) -> String {
-    let sec_sel = Selector::parse("#container .section, .section").unwrap();
-    let p_sel   = Selector::parse("p, li, dd, div").unwrap();
-    let mut candidate_text = String::new();
-    for sec in doc.select(&sec_sel) {
-        let sec_txt = sec.text().collect::<String>();
-        if ["접수 기간","모집 기간","활동 기간","교육 기간","신청 기간","운영 기간"]
-            .iter().any(|kw| sec_txt.contains(kw))
-        {
-            for node in sec.select(&p_sel) {
-                candidate_text.push_str(&node.text().collect::<String>());
-                candidate_text.push('\n');
-            }
-        }
-    }
-    if candidate_text.trim().is_empty() {
-        candidate_text = doc.root_element().text().collect::<String>();
-    }
-    normalize_whitespace(&candidate_text)
-}
-
-/// 한국어/숫자 범위 표기에서 날짜(시작/종료)를 파싱
-fn parse_dates_from_korean_or_numeric(text: &str, end_hint: Option<&str>) -> Option<(Option<String>, Option<String>)> {
-    // 숫자 yyyy-mm-dd ~ yyyy-mm-dd
-    let re_num = Regex::new(
-        r"(20\d{2}[-./]\d{1,2}[-./]\d{1,2})\s*[~\-–]\s*(20\d{2}[-./]\d{1,2}[-./]\d{1,2})"
-    ).unwrap();
-    if let Some(caps) = re_num.captures(text) {
-        let s = normalize_date(caps.get(1).unwrap().as_str());
-        let e = normalize_date(caps.get(2).unwrap().as_str());
-        return Some((Some(s), Some(e)));
-    }
-
-    // 한국어 "(연) m월 d일 ~ (연) m월 d일"
-    let re_kr = Regex::new(
-        r"(?:(?P<y1>20\d{2})\s*년\s*)?(?P<m1>\d{1,2})\s*월\s*(?P<d1>\d{1,2})\s*일(?:\([^)]*\))?\s*[~\-–]\s*(?:(?P<y2>20\d{2})\s*년\s*)?(?P<m2>\d{1,2})\s*월\s*(?P<d2>\d{1,2})\s*일"
-    ).unwrap();
-    if let Some(caps) = re_kr.captures(text) {
-        let y2 = caps.name("y2").and_then(|m| m.as_str().parse::<i32>().ok())
-            .or_else(|| end_hint.and_then(|e| e.get(0..4)).and_then(|y| y.parse().ok()))
-            .unwrap_or_else(|| Local::now().year());
-        let m2: u32 = caps.name("m2").unwrap().as_str().parse().unwrap_or(1);
-        let d2: u32 = caps.name("d2").unwrap().as_str().parse().unwrap_or(1);
-
-        let mut y1 = caps.name("y1").and_then(|m| m.as_str().parse::<i32>().ok()).unwrap_or(y2);
-        let m1: u32 = caps.name("m1").unwrap().as_str().parse().unwrap_or(1);
-        let d1: u32 = caps.name("d1").unwrap().as_str().parse().unwrap_or(1);
-
-        if caps.name("y1").is_none() && caps.name("y2").is_none() && m1 > m2 { y1 = y2 - 1; }
-
-        let s = format!("{:04}-{:02}-{:02}", y1, m1, d1);
-        let e = format!("{:04}-{:02}-{:02}", y2, m2, d2);
-        return Some((Some(s), Some(e)));
-    }
-
-    let re_single = Regex::new(
-        r"(?:(?P<y>20\d{2})\s*년\s*)?(?P<m>\d{1,2})\s*월\s*(?P<d>\d{1,2})\s*일\s*(?:마감|까지|접수마감)?"
-    ).unwrap();
-    if let Some(caps) = re_single.captures(text) {
-        let y = caps.name("y").and_then(|m| m.as_str().parse::<i32>().ok())
-            .or_else(|| end_hint.and_then(|e| e.get(0..4)).and_then(|y| y.parse().ok()))
-            .unwrap_or_else(|| Local::now().year());
-        let m: u32 = caps.name("m").unwrap().as_str().parse().unwrap_or(1);
-        let d: u32 = caps.name("d").unwrap().as_str().parse().unwrap_or(1);
-        let e = format!("{:04}-{:02}-{:02}", y, m, d);
-        return Some((None, Some(e)));
-    }
-
-    None
-}
-
-fn normalize_whitespace(s: &str) -> String {
-    let mut t = s.replace('\u{00A0}', " ");
-    t = Regex::new(r"\s+").unwrap().replace_all(&t, " ").into_owned();
-    t.trim().to_string()
-}
-
-// === Notice 어댑터 ===
-pub fn to_notice_from_campuspick(r: &Row) -> Notice {
-    let mut kind = infer_kind_from_label(&r.kind, Kind::Contest);
-
-    let url_lc = r.url.to_lowercase();
-    if url_lc.contains("/activity/") || url_lc.contains("activity/view") {
-        kind = Kind::Activity;
-    } else if url_lc.contains("/contest/") || url_lc.contains("contest/view") {
-        kind = Kind::Contest;
-    }
-
-    Notice {
-        source: Source::Campuspick,
-        kind,
-        title: r.title.clone(),
-        url:   r.url.clone(),
-        start: r.start.clone(),
-        end:   r.end.clone(),
-        organizer: r.company.clone(),
-        field: None,
-    }
-}
\ No newline at end of file
+use anyhow::{Context, Result};
+use regex::Regex;
+use reqwest::header::{HeaderMap, ACCEPT, CONTENT_TYPE};
+use scraper::{Html, Selector};
+use serde_json::Value;
+use std::{collections::HashSet, time::{Duration, Instant}};
+use tokio::task::JoinSet;
+use tracing::warn;
+use serde::Deserialize;
+use crate::dates::{self, normalize_date};
+use crate::http::send_with_retry;
+use crate::keywords::KeywordMatcher;
+use crate::source::CollectStats;
+use crate::notice::{Notice, Source, Kind, clean_title, decode_entities, infer_kind_from_label, sort_join};
+
+/// 캠퍼스픽 웹 사이트 URL
+const WEB_BASE: &str = "https://www.campuspick.com/";
+use crate::http::USER_AGENT as UA;
+
+/// 캠퍼스픽 수집 설정(설정 파일 [campuspick] 섹션, Default는 기존 CLI 기본값과 동일)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CampuspickConfig {
+    /// 대외활동 목록 API
+    pub activity_api: String,
+    /// 공모전 목록 API
+    pub contest_api: String,
+
+    /// 대외활동 목록 HTTP 메서드
+    pub activity_method: String,
+    /// 공모전 목록 HTTP 메서드
+    pub contest_method: String,
+
+    /// 목록 요청 본문 템플릿({limit}, {offset} 치환)
+    pub activity_body: String,
+    pub contest_body: String,
+
+    /// 페이지당 개수
+    pub limit: usize,
+    /// 페이지 수
+    pub pages: usize,
+
+    /// 마감일까지 남은 일수 필터(기본 20일 이내만, [deadline_days]에서 결정)
+    #[serde(skip)]
+    pub deadline_days: i64,
+    /// 마감까지 최소 남은 일수(config의 min_deadline_days)
+    #[serde(skip)]
+    pub min_deadline_days: i64,
+
+    pub delay_ms: u64,
+    /// 목록/상세 요청 시도 횟수(연결 오류/타임아웃/5xx만 재시도)
+    pub retries: u32,
+    /// 첫 재시도 대기(ms), 이후 2배씩(+지터)
+    pub backoff_ms: u64,
+    /// 호스트당 초당 최대 요청 수(목록/상세 공통, 0이면 제한 없음)
+    pub max_rps: f64,
+    /// 목록/상세 요청에 붙일 세션 헤더(env CAMPUSPICK_COOKIE / CAMPUSPICK_HEADERS, config.rs의 session_headers)
+    /// 쿠키/인증 값은 sensitive로 표시해 Debug 출력에서 가려짐
+    #[serde(skip)]
+    pub session_headers: HeaderMap,
+    /// robots.txt의 Disallow를 따름(IGNORE_ROBOTS=1 / --ignore-robots면 끔)
+    pub respect_robots: bool,
+
+    /// 지난 마감도 유지(아카이브 피드용)
+    #[serde(skip)]
+    pub archive: bool,
+    /// 공통 HTTP 설정(config의 [http])
+    #[serde(skip)]
+    pub http: crate::http::HttpOptions,
+    /// 요청마다 덮어쓸 헤더([campuspick.headers], 같은 이름의 기본 헤더를 대체)
+    pub headers: std::collections::HashMap<String, String>,
+    /// 대외활동 제목 키워드
+    pub activity_keywords: Vec<String>,
+    /// 목록 배열 위치(JSON pointer, 예: "/data/list"). None이면 자동 탐색
+    pub list_path: Option<String>,
+    /// 웹 사이트 주소(목록 요청의 Origin/Referer, 피드 채널 링크). 테스트/미러용으로 바꿀 수 있음
+    pub web_base: String,
+    /// 상세 페이지 URL 템플릿({kind}/{id} 치환)
+    pub detail_url: String,
+    /// 상세 JSON 후보 URL 템플릿들
+    pub detail_json: Vec<String>,
+}
+
+impl Default for CampuspickConfig {
+    fn default() -> Self {
+        Self {
+            activity_api: "https://api2.campuspick.com/find/activity/list".into(),
+            contest_api: "https://api2.campuspick.com/find/activity/list".into(),
+            activity_method: "POST".into(),
+            contest_method: "POST".into(),
+            activity_body: "target=2&limit={limit}&offset={offset}".into(),
+            contest_body: "target=1&limit={limit}&offset={offset}&category=108".into(),
+            limit: 100,
+            pages: 5,
+            deadline_days: 20,
+            min_deadline_days: 0,
+            delay_ms: 300,
+            retries: 3,
+            backoff_ms: 500,
+            max_rps: 3.0,
+            session_headers: HeaderMap::new(),
+            respect_robots: true,
+            archive: false,
+            http: crate::http::HttpOptions::default(),
+            headers: std::collections::HashMap::new(),
+            activity_keywords: ACTIVITY_KEYWORDS.iter().map(|k| k.to_string()).collect(),
+            list_path: None,
+            web_base: WEB_BASE.into(),
+            detail_url: DEFAULT_DETAIL_URL.into(),
+            detail_json: DEFAULT_DETAIL_JSON.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+}
+
+pub async fn collect(mut config: CampuspickConfig, stats: &CollectStats) -> Result<Vec<Row>> {
+    // 설정 헤더는 세션 헤더와 함께 요청마다 마지막에 붙임(같은 이름이면 설정 헤더가 이김)
+    config.session_headers.extend(crate::http::header_map(&config.headers)?);
+    let client = crate::http::client_builder(&config.http)?
+        .user_agent(UA)
+        .build()?;
+
+    let mut out = Vec::<Row>::new();
+
+    // 대외활동 수집
+    out.extend(
+        fetch_one_kind(
+            &client, &config, "activity",
+            &config.activity_api, &config.activity_method, &config.activity_body,
+            stats,
+        ).await?
+    );
+
+    // 공모전 수집
+    out.extend(
+        fetch_one_kind(
+            &client, &config, "contest",
+            &config.contest_api, &config.contest_method, &config.contest_body,
+            stats,
+        ).await?
+    );
+
+    out.sort_by(|a,b| a.start.is_none().cmp(&b.start.is_none())
+        .then(a.start.cmp(&b.start))
+        .then(a.end.cmp(&b.end))
+        .then(a.title.cmp(&b.title)));
+    Ok(out)
+}
+
+#[derive(Clone, Debug)]
+pub struct Row {
+    pub kind: String,          // activity / contest
+    pub title: String,         // 제목
+    pub url: String,           // 상세 URL
+    pub start: Option<String>, // 시작일(YYYY-MM-DD)
+    pub end: Option<String>,   // 마감일(YYYY-MM-DD)
+    pub company: Option<String>, // 주최/주관(가능하면 여러 값을 " / "로 결합)
+    pub end_approx: bool,      // end를 목록의 D-day 배지로 추정했으면 true
+    pub field: Option<String>, // 목록의 분야/카테고리명(있으면)
+}
+
+async fn fetch_one_kind(
+    client: &reqwest::Client,
+    config: &CampuspickConfig,
+    kind: &str,
+    api: &str, method: &str, body_tpl: &str,
+    stats: &CollectStats,
+) -> Result<Vec<Row>> {
+    let mut out = Vec::<Row>::new();
+    let mut seen = HashSet::<(String, String)>::new(); // (kind, id) 중복방지
+    let (pages, limit, deadline_days) = (config.pages, config.limit, config.deadline_days);
+    let matcher = KeywordMatcher::new(&config.activity_keywords)?;
+
+    for page in 1..=pages {
+        let offset = (page - 1) * limit;
+        let body = body_tpl.replace("{limit}", &limit.to_string())
+                           .replace("{offset}", &offset.to_string());
+
+        let post = method.eq_ignore_ascii_case("POST");
+        let url = if post { api.to_string() }
+                  else if api.contains('?') { format!("{api}&{body}") } else { format!("{api}?{body}") };
+        if config.respect_robots && !crate::robots::allowed(client, UA, &url).await {
+            warn!(url = %url, "list API disallowed by robots.txt");
+            stats.add_robots_blocked();
+            break;
+        }
+        let mut req = if post {
+            client.post(&url)
+                .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(body)
+        } else {
+            client.get(&url)
+        };
+        req = req.header(ACCEPT, "application/json, text/plain, */*")
+                 .header("Origin", &config.web_base)
+                 .header("Referer", format!("{}/{kind}", config.web_base.trim_end_matches('/')));
+        req = crate::http::with_headers(req, &config.session_headers);
+
+        let list_started = Instant::now();
+        let resp   = send_with_retry(req, config.retries, backoff(config), config.max_rps, stats).await?;
+        let status = resp.status();
+        let headers = resp.headers().clone(); 
+        let text   = crate::http::read_text(resp, stats).await?;
+        let ctype  = headers.get(CONTENT_TYPE).and_then(|h| h.to_str().ok()).unwrap_or("");
+        stats.add_list_time(list_started.elapsed());
+
+        if !status.is_success() || !ctype.starts_with("application/json") { break; }
+
+        let parse_started = Instant::now();
+        let v: Value = serde_json::from_str(&text).with_context(|| "invalid JSON")?;
+        let Some(arr) = find_array(&v, config.list_path.as_deref()) else { break; };
+        stats.add_parse_time(parse_started.elapsed());
+
+        'each: for it in arr {
+            // 식별자 확보
+            let Some(id) = get_id(it) else { continue };
+            if !seen.insert((kind.to_string(), id.clone())) { continue; }
+            stats.add_raw(1);
+
+            // 제목 확보
+            let title = first_text(it, &["title","name","subject"]).unwrap_or_default();
+            if title.is_empty() { continue; }
+
+            // 종류(대외활동 or 공모전)별 1차 필터
+            if kind == "contest" && !match_category_108(it) { continue 'each; }
+            if kind == "activity" && !title_keyword_hit(&matcher, &title) { continue 'each; }
+
+            // 목록 JSON에서 날짜/주최 추정
+            let start0 = it.get("startDate").and_then(|x| x.as_str()).map(normalize_date);
+            let end0   = it.get("endDate").and_then(|x| x.as_str()).map(normalize_date)
+                        .or_else(|| it.get("deadline").and_then(|x| x.as_str()).map(normalize_date));
+            let company0 = list_company(it);
+
+            // 상세에서 startDate/endDate/company 보완 수집
+            let detail_started = Instant::now();
+            let (start1, end1, company1) = fill_detail_fields(client, config, kind, &id, end0.as_deref(), stats).await;
+            stats.add_detail_time(detail_started.elapsed());
+            if start1.is_none() && end1.is_none() && company1.is_none() {
+                stats.add_detail_failure();
+            }
+
+            let start = start0.or(start1);
+            let mut end = end0.or(end1);
+
+            // 날짜를 못 얻었으면 목록 배지("D-7", "D-day")로 마감일 추정
+            let mut end_approx = false;
+            if end.is_none()
+                && let Some(n) = first_text(it, &["dday","dDay","d_day","badge","deadlineText"]).and_then(|b| parse_dday_badge(&b)) {
+                end = Some((crate::clock::today() + chrono::Duration::days(n)).format("%Y-%m-%d").to_string());
+                end_approx = true;
+            }
+            let company = company0.or(company1);
+            let field = first_text(it, &["categoryName","category_name","fieldName","field"])
+                .or_else(|| category_names(it));
+            // D-day 필터링: 기본 20일(오늘~20일)만, 지난 마감들 제외(ARCHIVE 모드에서는 유지)
+            let Some(ref e) = end else { continue 'each; };
+            let days = dates::days_until(e, crate::clock::today()).unwrap_or(i64::MAX);
+            if !dates::within_deadline(days, config.min_deadline_days, deadline_days, config.archive) { continue 'each; }
+
+            out.push(Row {
+                kind: kind.to_string(),
+                title,
+                url: build_detail_url(&config.detail_url, kind, &id),
+                start, end, company, end_approx, field,
+            });
+        }
+        stats.pace(Duration::from_millis(config.delay_ms)).await;
+    }
+    Ok(out)
+}
+
+/// 목록 JSON 본문 → 목록 항목들(배열 위치는 find_array 규칙, 네트워크 없음)
+pub fn parse_list_json(body: &str, list_path: Option<&str>) -> Result<Vec<Value>> {
+    let v: Value = serde_json::from_str(body).context("invalid JSON")?;
+    Ok(find_array(&v, list_path).cloned().unwrap_or_default())
+}
+
+/// 목록 배열 찾기
+/// - list_path(JSON pointer, 예: "/data/list")가 있으면 그 위치를 사용
+/// - 없으면 응답 안의 배열 중 id류 필드(id/idx/activityId…)가 있는 객체가 가장 많은 배열
+///   그런 배열이 없으면 객체 원소가 가장 많은 배열(배너/태그 등 미끼 배열 회피)
+fn find_array<'a>(v: &'a Value, list_path: Option<&str>) -> Option<&'a Vec<Value>> {
+    if let Some(path) = list_path {
+        match v.pointer(path).and_then(|x| x.as_array()) {
+            Some(a) => return Some(a),
+            None => warn!(path, "list path not found; falling back"),
+        }
+    }
+
+    let mut candidates = Vec::new();
+    collect_arrays(v, &mut candidates);
+    // 1) id류 필드(get_id)가 있는 객체가 가장 많은 배열(태그/카테고리 같은 미끼 배열 회피)
+    // 2) 없으면 객체가 가장 많은 배열 → 첫 배열
+    let count_with_id = |a: &Vec<Value>| a.iter().filter(|e| e.is_object() && get_id(e).is_some()).count();
+    candidates
+        .iter()
+        .copied()
+        .filter(|a| count_with_id(a) > 0)
+        .max_by_key(|a| count_with_id(a))
+        .or_else(|| {
+            candidates
+                .iter()
+                .copied()
+                .filter(|a| a.iter().any(|e| e.is_object()))
+                .max_by_key(|a| a.iter().filter(|e| e.is_object()).count())
+        })
+        .or_else(|| candidates.first().copied())
+}
+
+/// 루트 배열 → 알려진 키 → 나머지 값 순서로 모든 배열을 수집
+fn collect_arrays<'a>(v: &'a Value, out: &mut Vec<&'a Vec<Value>>) {
+    if let Some(a) = v.as_array() { out.push(a); return; }
+    let Some(obj) = v.as_object() else { return; };
+    const KEYS: [&str; 8] = ["items","list","data","results","content","rows","posts","payload"];
+    for k in KEYS {
+        if let Some(vv) = obj.get(k) { collect_arrays(vv, out); }
+    }
+    for (k, vv) in obj {
+        if !KEYS.contains(&k.as_str()) { collect_arrays(vv, out); }
+    }
+}
+
+/// categories / tags 배열의 이름들(문자열 또는 {name})을 ", "로 결합
+fn category_names(v: &Value) -> Option<String> {
+    let arr = ["categories", "tags"].iter().find_map(|k| v.get(*k).and_then(|x| x.as_array()))?;
+    let names: Vec<&str> = arr
+        .iter()
+        .filter_map(|e| e.as_str().or_else(|| e.get("name").and_then(|n| n.as_str())))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    if names.is_empty() { None } else { Some(names.join(", ")) }
+}
+
+fn first_text(v: &Value, keys: &[&str]) -> Option<String> {
+    for k in keys {
+        if let Some(s) = v.get(*k).and_then(|x| x.as_str()) {
+            let t = s.trim();
+            if !t.is_empty() { return Some(t.to_string()); }
+        }
+    }
+    None
+}
+
+/// ID 문자열을 얻음
+fn get_id(v: &Value) -> Option<String> {
+    for k in ["id","idx","activityId","contestId","postId","aid","cid"] {
+        if let Some(x) = v.get(k) {
+            if let Some(s) = x.as_str() && !s.is_empty() { return Some(s.to_string()); }
+            if let Some(n) = x.as_i64() { return Some(n.to_string()); }
+            if let Some(n) = x.as_u64() { return Some(n.to_string()); }
+        }
+    }
+    None
+}
+
+/// 상세 페이지 URL 템플릿 기본값({kind}/{id} 치환)
+const DEFAULT_DETAIL_URL: &str = "https://www.campuspick.com/{kind}/view?id={id}";
+/// 상세 JSON 후보 URL 템플릿 기본값
+const DEFAULT_DETAIL_JSON: &[&str] = &[
+    "https://api2.campuspick.com/find/{kind}/view?id={id}",
+    "https://api2.campuspick.com/{kind}/view?id={id}",
+    "https://api2.campuspick.com/find/{kind}/detail?id={id}",
+    "https://api2.campuspick.com/{kind}/detail?id={id}",
+];
+
+fn fill_template(tpl: &str, kind: &str, id: &str) -> String {
+    tpl.replace("{kind}", kind).replace("{id}", id)
+}
+
+/// 상세 페이지 URL 구성(tpl: CampuspickConfig::detail_url)
+fn build_detail_url(tpl: &str, kind: &str, id: &str) -> String {
+    let kind = if kind == "activity" { "activity" } else { "contest" };
+    fill_template(tpl, kind, id)
+}
+
+/// 상세 JSON 후보 URL들(tpls: CampuspickConfig::detail_json)
+fn build_detail_json_urls(tpls: &[String], kind: &str, id: &str) -> Vec<String> {
+    tpls.iter().map(|t| fill_template(t, kind, id)).collect()
+}
+
+/// 카테고리 필드가 108(IT/소프트웨어/게임)인지 판별
+fn match_category_108(v: &Value) -> bool {
+    for k in ["category","categoryId","category_idx","categoryId1","category1","categories"] {
+        if let Some(x) = v.get(k) {
+            if let Some(n) = x.as_i64() && n == 108 { return true; }
+            if let Some(s) = x.as_str() {
+                if s.trim() == "108" { return true; }
+                if s.split(|c:char| c.is_ascii_punctuation() || c.is_whitespace()).any(|t| t=="108") { return true; }
+            }
+            if let Some(a) = x.as_array()
+                && a.iter().any(|e| e.as_i64()==Some(108) || e.as_str()==Some("108")) { return true; }
+        }
+    }
+    false
+}
+
+/// 대외활동 제목 키워드 기본값
+const ACTIVITY_KEYWORDS: &[&str] = &[
+    "IT","SW","코딩","소프트웨어","컴퓨터","보안","정보보호","KISIA","개인정보","개발자","AI","엔지니어","부트캠프"
+];
+
+/// 활동 제목에 키워드가 포함 검사
+fn title_keyword_hit(matcher: &KeywordMatcher, title: &str) -> bool {
+    matcher.is_match(&normalize(title))
+}
+
+/// 목록 배지 "D-7" / "D-day" / "오늘 마감" → 남은 일수
+fn parse_dday_badge(badge: &str) -> Option<i64> {
+    let b = badge.trim().to_lowercase().replace(' ', "");
+    if b == "d-day" || b == "d-0" || b.contains("오늘마감") { return Some(0); }
+    let rest = b.strip_prefix("d-")?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+fn normalize(s: &str) -> String {
+    s.to_lowercase()
+        .replace('\u{00A0}', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn first_company(v: &Value) -> Option<String> {
+    let keys = [
+        "company","company_name","company1","company2","company3",
+        "org","organization","host","hostName","organizer","sponsor","hostOrg","host_org",
+        "agency","agencyName","brand","brandName"
+    ];
+    for k in keys {
+        if let Some(val) = v.get(k) {
+            if let Some(s) = val.as_str() {
+                let s = s.trim();
+                if !s.is_empty() { return Some(s.to_string()); }
+            }
+            if let Some(arr) = val.as_array() {
+                let joined = arr.iter().filter_map(|e| e.as_str()).map(|s| s.trim())
+                    .filter(|s| !s.is_empty()).collect::<Vec<_>>().join(" / ");
+                if !joined.is_empty() { return Some(joined); }
+            }
+        }
+    }
+    None
+}
+
+/// 목록 항목의 주최: 최상위 키 → 중첩된 "/data" 밑
+fn list_company(it: &Value) -> Option<String> {
+    first_company(it).or_else(|| it.pointer("/data").and_then(first_company))
+}
+
+fn extract_company_from_text(text: &str) -> Option<String> {
+    let re = Regex::new(r"(주최|주관)\s*[:：]?\s*([^\n]+)").ok()?;
+    let cap = re.captures(text)?;
+    let raw = cap.get(2)?.as_str().trim();
+    let parts = raw.split(['/', '|', '·', ','])
+        .map(|s| s.trim()).filter(|s| !s.is_empty()).collect::<Vec<_>>();
+    if parts.is_empty() { None } else { Some(parts.join(" / ")) }
+}
+
+/// 재시도 첫 대기 시간(backoff_ms)
+fn backoff(config: &CampuspickConfig) -> Duration {
+    Duration::from_millis(config.backoff_ms)
+}
+
+async fn fill_detail_fields(
+    client: &reqwest::Client,
+    config: &CampuspickConfig,
+    kind: &str,
+    id: &str,
+    end_hint: Option<&str>,
+    stats: &CollectStats,
+) -> (Option<String>, Option<String>, Option<String>) {
+    let page_url = build_detail_url(&config.detail_url, kind, id);
+    // robots.txt가 막은 URL은 요청하지 않고 세기만 함
+    let page_allowed = !config.respect_robots || crate::robots::allowed(client, UA, &page_url).await;
+    if !page_allowed {
+        stats.add_robots_blocked();
+    }
+    let page_req = client.get(&page_url).headers(config.session_headers.clone());
+    if page_allowed
+        && let Ok(resp) = send_with_retry(page_req, config.retries, backoff(config), config.max_rps, stats).await
+        && resp.status().is_success()
+        && let Ok(html) = crate::http::read_text(resp, stats).await {
+        let doc = Html::parse_document(&html);
+        let script_sel = Selector::parse("script").unwrap();
+        let mut scripts_text = String::new();
+        for s in doc.select(&script_sel) {
+            scripts_text.push_str(&s.text().collect::<String>());
+            scripts_text.push('\n');
+        }
+
+        let re_sd = Regex::new(r#"startDate\s*:\s*\"([0-9]{4}[-./][0-9]{2}[-./][0-9]{2})\""#).unwrap();
+        let re_ed = Regex::new(r#"endDate\s*:\s*\"([0-9]{4}[-./][0-9]{2}[-./][0-9]{2})\""#).unwrap();
+        let re_company = Regex::new(r#"company\d*\s*:\s*\"([^\"]+)\""#).unwrap();
+
+        let s = re_sd.captures(&scripts_text).map(|c| normalize_date(c.get(1).unwrap().as_str()));
+        let e = re_ed.captures(&scripts_text).map(|c| normalize_date(c.get(1).unwrap().as_str()));
+
+        let mut companies: Vec<String> = Vec::new();
+        for cap in re_company.captures_iter(&scripts_text) {
+            let v = cap.get(1).unwrap().as_str().trim();
+            if !v.is_empty() { companies.push(v.to_string()); }
+        }
+        companies.sort();
+        companies.dedup();
+        let company_inline = if companies.is_empty() { None } else { Some(companies.join(" / ")) };
+
+        if s.is_some() || e.is_some() || company_inline.is_some() {
+            return (s, e, company_inline);
+        }
+
+        let text = extract_relevant_text(&doc);
+        if let (s2, e2 @ Some(_)) = dates::parse_range(&text, end_hint) {
+            let comp = extract_company_from_text(&text);
+            return (s2, e2, comp);
+        }
+    }
+
+    let json_candidates = build_detail_json_urls(&config.detail_json, kind, id);
+    // JSON 후보들을 동시에 요청하고, 먼저 성공한 응답을 사용(나머지는 취소)
+    let mut join = JoinSet::new();
+    for url in json_candidates {
+        if config.respect_robots && !crate::robots::allowed(client, UA, &url).await {
+            stats.add_robots_blocked();
+            continue;
+        }
+        let client = client.clone();
+        let (retries, backoff, max_rps) = (config.retries, backoff(config), config.max_rps);
+        let (headers, stats) = (config.session_headers.clone(), stats.clone());
+        join.spawn(async move { fetch_detail_json(&client, &url, headers, retries, backoff, max_rps, &stats).await });
+    }
+    while let Some(res) = join.join_next().await {
+        if let Ok(Some(found)) = res {
+            join.abort_all();
+            return found;
+        }
+    }
+
+    if !page_allowed {
+        return (None, None, None);
+    }
+    stats.add_rate_limit_wait(crate::rate_limit::throttle(&page_url, config.max_rps).await);
+    if let Ok(resp) = client.get(&page_url).headers(config.session_headers.clone()).send().await
+        && resp.status().is_success()
+        && let Ok(html) = crate::http::read_text(resp, stats).await {
+        let doc = Html::parse_document(&html);
+        let text = extract_relevant_text(&doc);
+        let (s, e) = dates::parse_range(&text, end_hint);
+        let comp = extract_company_from_text(&text);
+        if e.is_some() || comp.is_some() {
+            return (s, e, comp);
+        }
+    }
+
+    (None, None, None)
+}
+
+/// 상세 JSON 후보 URL 하나를 조회해 (start, end, company)를 추출
+async fn fetch_detail_json(
+    client: &reqwest::Client,
+    url: &str,
+    headers: HeaderMap,
+    retries: u32,
+    backoff: Duration,
+    max_rps: f64,
+    stats: &CollectStats,
+) -> Option<(Option<String>, Option<String>, Option<String>)> {
+    let req = crate::http::with_headers(client.get(url).header(ACCEPT, "application/json"), &headers);
+    let resp = send_with_retry(req, retries, backoff, max_rps, stats).await.ok()?;
+    let status = resp.status();
+    let headers = resp.headers().clone();
+    let txt = crate::http::read_text(resp, stats).await.unwrap_or_default();
+    let is_json = headers.get(CONTENT_TYPE).and_then(|h| h.to_str().ok())
+        .map(|s| s.starts_with("application/json")).unwrap_or(false);
+    if !status.is_success() || !is_json { return None; }
+    parse_detail_json(&txt)
+}
+
+/// 상세 JSON 본문 → (start, end, company). 루트 또는 data 밑의 startDate/endDate(deadline)/주최 키
+/// 셋 다 없으면 None
+pub fn parse_detail_json(txt: &str) -> Option<(Option<String>, Option<String>, Option<String>)> {
+    let v = serde_json::from_str::<Value>(txt).ok()?;
+    let s = v.get("startDate").and_then(|x| x.as_str())
+             .or_else(|| v.pointer("/data/startDate").and_then(|x| x.as_str()))
+             .map(normalize_date);
+    let e = v.get("endDate").and_then(|x| x.as_str())
+             .or_else(|| v.get("deadline").and_then(|x| x.as_str()))
+             .or_else(|| v.pointer("/data/endDate").and_then(|x| x.as_str()))
+             .map(normalize_date);
+
+    let company = first_company(&v)
+        .or_else(|| v.pointer("/data").and_then(first_company));
+
+    if s.is_some() || e.is_some() || company.is_some() {
+        Some((s, e, company))
+    } else {
+        None
+    }
+}
+
+fn extract_relevant_text(doc: &Html) -> String {
+    let sec_sel = Selector::parse("#container .section, .section").unwrap();
+    let p_sel   = Selector::parse("p, li, dd, div").unwrap();
+    let mut candidate_text = String::new();
+    for sec in doc.select(&sec_sel) {
+        let sec_txt = sec.text().collect::<String>();
+        if ["접수 기간","모집 기간","활동 기간","교육 기간","신청 기간","운영 기간"]
+            .iter().any(|kw| sec_txt.contains(kw))
+        {
+            for node in sec.select(&p_sel) {
+                candidate_text.push_str(&node.text().collect::<String>());
+                candidate_text.push('\n');
+            }
+        }
+    }
+    if candidate_text.trim().is_empty() {
+        candidate_text = doc.root_element().text().collect::<String>();
+    }
+    normalize_whitespace(&candidate_text)
+}
+
+fn normalize_whitespace(s: &str) -> String {
+    // HTML 파서가 디코딩한 텍스트(다시 디코딩하지 않음)
+    let mut t = s.replace('\u{00A0}', " ");
+    t = Regex::new(r"\s+").unwrap().replace_all(&t, " ").into_owned();
+    t.trim().to_string()
+}
+
+// === Notice 어댑터 ===
+pub fn to_notice_from_campuspick(r: &Row) -> Notice {
+    let mut kind = infer_kind_from_label(&r.kind, Kind::Contest);
+
+    let url_lc = r.url.to_lowercase();
+    if url_lc.contains("/activity/") || url_lc.contains("activity/view") {
+        kind = Kind::Activity;
+    } else if url_lc.contains("/contest/") || url_lc.contains("contest/view") {
+        kind = Kind::Contest;
+    }
+
+    Notice {
+        source: Source::Campuspick,
+        kind,
+        title: clean_title(&decode_entities(&r.title), &Source::Campuspick),
+        raw_title: r.title.clone(),
+        url:   r.url.clone(),
+        start: r.start.clone(),
+        end:   r.end.clone(),
+        organizer: r.company.as_deref().map(|c| sort_join(&decode_entities(c), '/', " / ")),
+        field: r.field.clone(),
+        end_approx: r.end_approx,
+        summary: None,
+        series_id: None,
+        changed_at: None,
+    }
+}
+
+// === Crawler 구현 ===
+pub struct CampuspickCrawler {
+    pub config: CampuspickConfig,
+    pub timeout: Duration,
+}
+
+#[async_trait::async_trait(?Send)]
+impl crate::source::Crawler for CampuspickCrawler {
+    fn name(&self) -> &str { "campuspick" }
+    fn site_url(&self) -> &str { self.config.web_base.trim_end_matches('/') }
+    fn timeout(&self) -> Duration { self.timeout }
+
+    async fn collect(&self, stats: &CollectStats) -> Result<Vec<Notice>> {
+        let rows = collect(self.config.clone(), stats).await?;
+        Ok(rows.iter().map(to_notice_from_campuspick).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockServer, Reply};
+    use chrono::NaiveDate;
+
+    #[tokio::test]
+    async fn collects_list_and_detail_json_fixtures() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let server = MockServer::start(vec![
+            ("/activity/list", vec![Reply::json(include_str!("../tests/fixtures/campuspick_activity_list.json"))]),
+            ("/contest/list", vec![Reply::json(include_str!("../tests/fixtures/campuspick_contest_list.json"))]),
+            ("/api/activity/view?id=5103", vec![Reply::json(include_str!("../tests/fixtures/campuspick_detail_5103.json"))]),
+        ])
+        .await;
+        let config = CampuspickConfig {
+            activity_api: server.url("/activity/list"),
+            contest_api: server.url("/contest/list"),
+            web_base: server.url("/"),
+            detail_url: server.url("/{kind}/view?id={id}"),
+            detail_json: vec![server.url("/api/{kind}/view?id={id}")],
+            pages: 1,
+            delay_ms: 0,
+            retries: 1,
+            max_rps: 0.0,
+            ..CampuspickConfig::default()
+        };
+
+        let rows = collect(config, &CollectStats::default()).await.unwrap();
+        let notices: Vec<Notice> = rows.iter().map(to_notice_from_campuspick).collect();
+        // 5102는 키워드 불일치, 7202는 카테고리 108 아님, 7203은 마감이 20일 밖
+        let mut titles: Vec<&str> = notices.iter().map(|n| n.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(titles, ["2026 공공데이터 활용 앱 개발 공모전", "SW 코딩 멘토단 모집", "[서울] AI 개발자 서포터즈 5기 모집"]);
+
+        let by_title = |t: &str| notices.iter().find(|n| n.title == t).unwrap();
+        let supporters = by_title("[서울] AI 개발자 서포터즈 5기 모집");
+        assert_eq!(supporters.kind, Kind::Activity);
+        assert_eq!(supporters.url, server.url("/activity/view?id=5101"));
+        assert_eq!((supporters.start.as_deref(), supporters.end.as_deref()), (Some("2026-10-01"), Some("2026-10-28")));
+        assert_eq!(supporters.organizer.as_deref(), Some("네이버 커넥트재단"));
+        assert_eq!(supporters.field.as_deref(), Some("서포터즈"));
+
+        // 목록에 날짜가 없으면 상세 JSON에서 보완(D-day 배지 추정보다 우선)
+        let mentors = by_title("SW 코딩 멘토단 모집");
+        assert_eq!((mentors.start.as_deref(), mentors.end.as_deref()), (Some("2026-10-05"), Some("2026-10-21")));
+        assert!(!mentors.end_approx);
+        assert_eq!(mentors.organizer.as_deref(), Some("한국정보보호산업협회"));
+        assert_eq!(mentors.field.as_deref(), Some("멘토링, 교육"));
+
+        let contest = by_title("2026 공공데이터 활용 앱 개발 공모전");
+        assert_eq!(contest.kind, Kind::Contest);
+        assert_eq!(contest.organizer.as_deref(), Some("한국지능정보사회진흥원 / 행정안전부"));
+
+        let list_req = &server.requests_to("/activity/list")[0];
+        assert_eq!(list_req.method, "POST");
+        assert_eq!(list_req.body, "target=2&limit=100&offset=0");
+        assert_eq!(list_req.headers.get("origin"), Some(&server.url("/")));
+        assert_eq!(list_req.headers.get("referer"), Some(&server.url("/activity")));
+    }
+
+    #[test]
+    fn list_helpers() {
+        let v: Value = serde_json::json!({"idx": "a1", "category": "104,108"});
+        assert_eq!(get_id(&v).as_deref(), Some("a1"));
+        assert!(match_category_108(&v));
+        assert_eq!(parse_dday_badge("D-7"), Some(7));
+        assert_eq!(parse_dday_badge("오늘 마감"), Some(0));
+        assert_eq!(parse_dday_badge("상시"), None);
+    }
+
+    #[test]
+    fn adapter_decodes_entities_once_and_keeps_raw_title() {
+        let row = Row {
+            kind: "contest".into(),
+            title: "R&amp;amp;D &amp;lt;AI&amp;gt; 공모전 - 캠퍼스픽".into(),
+            url: "https://www.campuspick.com/contest/view?id=1".into(),
+            start: None,
+            end: None,
+            company: None,
+            end_approx: false,
+            field: None,
+        };
+        let n = to_notice_from_campuspick(&row);
+        assert_eq!(n.title, "R&amp;D &lt;AI&gt; 공모전");
+        assert_eq!(n.raw_title, row.title);
+    }
+
+    #[tokio::test]
+    async fn json_candidates_race_and_first_success_wins() {
+        use std::time::{Duration, Instant};
+        let server = MockServer::start(vec![
+            ("/slow/activity", vec![Reply::json(r#"{"endDate": "2026-12-31"}"#).delayed(Duration::from_secs(3))]),
+            ("/fast/activity", vec![Reply::json(r#"{"startDate": "2026.10.01", "endDate": "2026.10.20", "brandName": "캠퍼스픽"}"#)]),
+            ("/missing/activity", vec![Reply::status(404)]),
+        ])
+        .await;
+        let config = CampuspickConfig {
+            // 상세 페이지는 404 → JSON 후보로 넘어감
+            detail_url: server.url("/page/{kind}?id={id}"),
+            detail_json: ["/slow/{kind}?id={id}", "/missing/{kind}?id={id}", "/fast/{kind}?id={id}"]
+                .iter()
+                .map(|p| server.url(p))
+                .collect(),
+            retries: 1,
+            max_rps: 0.0,
+            respect_robots: false,
+            ..CampuspickConfig::default()
+        };
+        let client = reqwest::Client::new();
+        let started = Instant::now();
+        let found = fill_detail_fields(&client, &config, "activity", "9", None, &CollectStats::default()).await;
+        assert!(started.elapsed() < Duration::from_secs(2), "waited for the slow candidate: {:?}", started.elapsed());
+        assert_eq!(
+            found,
+            (Some("2026-10-01".into()), Some("2026-10-20".into()), Some("캠퍼스픽".into()))
+        );
+        assert_eq!(server.requests_to("/fast/activity").len(), 1);
+    }
+
+    #[test]
+    fn find_array_skips_small_decoy_and_honors_list_path() {
+        // 배너(2건)가 먼저 나와도 더 큰 실제 목록(3건)을 선택
+        let body = r#"{"result": {"banners": [{"img": "a.png"}, {"img": "b.png"}],
+                       "section": {"entries": [{"title": "A"}, {"title": "B"}, {"title": "C"}]}}}"#;
+        let rows = parse_list_json(body, None).unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0]["title"], "A");
+
+        // CAMPUS_LIST_PATH(JSON pointer)가 있으면 그 배열을 그대로
+        let banners = parse_list_json(body, Some("/result/banners")).unwrap();
+        assert_eq!(banners[0]["img"], "a.png");
+        // 없는 경로면 자동 탐색으로
+        assert_eq!(parse_list_json(body, Some("/nope")).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn custom_detail_templates_fill_kind_and_id() {
+        let tpl = "https://m.campuspick.com/{kind}s/{id}?ref=rss";
+        assert_eq!(build_detail_url(tpl, "activity", "5101"), "https://m.campuspick.com/activitys/5101?ref=rss");
+        // activity 외의 종류는 contest 경로
+        assert_eq!(build_detail_url(tpl, "club", "7"), "https://m.campuspick.com/contests/7?ref=rss");
+        let tpls = vec!["https://api.example.com/{kind}/{id}".to_string(), "https://api.example.com/v2?id={id}".to_string()];
+        assert_eq!(
+            build_detail_json_urls(&tpls, "contest", "7201"),
+            ["https://api.example.com/contest/7201", "https://api.example.com/v2?id=7201"]
+        );
+    }
+
+    #[tokio::test]
+    async fn dday_badge_sets_approximate_end_when_detail_has_no_date() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let list = r#"{"data": {"list": [{"id": 9, "title": "해커톤 공모전", "category": 108, "dday": "D-5"}]}}"#;
+        let server = MockServer::start(vec![
+            ("/activity/list", vec![Reply::json(r#"{"data": {"list": []}}"#)]),
+            ("/contest/list", vec![Reply::json(list)]),
+            // 상세 JSON 실패(404)
+        ])
+        .await;
+        let config = CampuspickConfig {
+            activity_api: server.url("/activity/list"),
+            contest_api: server.url("/contest/list"),
+            web_base: server.url("/"),
+            detail_url: server.url("/{kind}/view?id={id}"),
+            detail_json: vec![server.url("/api/{kind}/view?id={id}")],
+            pages: 1,
+            delay_ms: 0,
+            retries: 1,
+            max_rps: 0.0,
+            ..CampuspickConfig::default()
+        };
+
+        let rows = collect(config, &CollectStats::default()).await.unwrap();
+        crate::clock::set_today(None);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].end.as_deref(), Some("2026-10-19"));
+        assert!(rows[0].end_approx);
+        assert!(to_notice_from_campuspick(&rows[0]).end_approx);
+        assert!(!server.requests_to("/api/contest/view").is_empty());
+    }
+
+    #[test]
+    fn first_company_reads_brand_name_and_nested_data() {
+        let item: Value = serde_json::from_str(r#"{"id": 1, "title": "t", "brandName": " 토스 "}"#).unwrap();
+        assert_eq!(first_company(&item).as_deref(), Some("토스"));
+        // 앞쪽 키가 비어 있으면 다음 키로
+        let item: Value = serde_json::from_str(r#"{"company": "", "agencyName": ["서울시", " ", "SBA"]}"#).unwrap();
+        assert_eq!(first_company(&item).as_deref(), Some("서울시 / SBA"));
+        // 목록 경로는 최상위에 없으면 /data 밑을 봄
+        let item: Value = serde_json::from_str(r#"{"id": 2, "data": {"brandName": "당근"}}"#).unwrap();
+        assert_eq!(first_company(&item), None);
+        assert_eq!(list_company(&item).as_deref(), Some("당근"));
+    }
+
+    #[test]
+    fn entity_title_is_decoded_and_escaped_once_in_the_feed() {
+        let row = Row {
+            kind: "contest".into(),
+            title: "R&amp;D 공모전".into(),
+            url: "https://www.campuspick.com/contest/view?id=1".into(),
+            start: None,
+            end: Some("2026-10-24".into()),
+            company: Some("한국R&amp;D진흥원".into()),
+            end_approx: false,
+            field: None,
+        };
+        let n = to_notice_from_campuspick(&row);
+        assert_eq!(n.title, "R&D 공모전");
+        assert_eq!(n.organizer.as_deref(), Some("한국R&D진흥원"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("campuspick_rss.xml").display().to_string();
+        crate::rss_write::write_rss_feed(&[n], "t", "https://example.com", "d", &out, &Default::default()).unwrap();
+        let xml = std::fs::read_to_string(&out).unwrap();
+        assert!(xml.contains("<title>R&amp;D 공모전</title>"), "{xml}");
+        assert!(!xml.contains("&amp;amp;"), "{xml}");
+    }
+
+    #[tokio::test]
+    async fn list_request_is_retried_after_a_transient_5xx() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let server = MockServer::start(vec![
+            ("/activity/list", vec![Reply::json(r#"{"data": {"list": []}}"#)]),
+            (
+                "/contest/list",
+                vec![Reply::status(502), Reply::json(include_str!("../tests/fixtures/campuspick_contest_list.json"))],
+            ),
+        ])
+        .await;
+        let config = CampuspickConfig {
+            activity_api: server.url("/activity/list"),
+            contest_api: server.url("/contest/list"),
+            web_base: server.url("/"),
+            detail_url: server.url("/{kind}/view?id={id}"),
+            detail_json: Vec::new(),
+            pages: 1,
+            delay_ms: 0,
+            retries: 2,
+            backoff_ms: 1,
+            max_rps: 0.0,
+            ..CampuspickConfig::default()
+        };
+
+        let rows = collect(config, &CollectStats::default()).await.unwrap();
+        crate::clock::set_today(None);
+        // 502 뒤 재시도한 응답으로 목록을 읽음(7201만 카테고리/기한 조건 통과)
+        assert_eq!(server.requests_to("/contest/list").len(), 2);
+        assert_eq!(rows.iter().map(|r| r.title.as_str()).collect::<Vec<_>>(), ["2026 공공데이터 활용 앱 개발 공모전"]);
+    }
+
+    #[test]
+    fn find_array_prefers_id_bearing_objects_over_larger_decoys() {
+        // 두 단계 아래 실제 목록(id 있음 2건)보다 태그 배열/객체 배열(id 없음)이 더 크고 먼저 나옴
+        let v: Value = serde_json::from_str(
+            r#"{"data": {"tags": ["AI", "IT", "게임", "디자인"],
+                         "filters": [{"name": "a"}, {"name": "b"}, {"name": "c"}],
+                         "wrapper": {"unexpected": [{"idx": 11, "title": "A"}, {"activityId": "12", "title": "B"}]}}}"#,
+        )
+        .unwrap();
+        let got = find_array(&v, None).unwrap();
+        assert_eq!(got.iter().filter_map(get_id).collect::<Vec<_>>(), ["11", "12"]);
+
+        // id가 있는 배열이 없으면 객체가 가장 많은 배열 → 그것도 없으면 첫 배열
+        let v: Value = serde_json::from_str(r#"{"tags": ["a", "b"], "rows": [{"title": "x"}]}"#).unwrap();
+        assert_eq!(find_array(&v, None).unwrap()[0]["title"], "x");
+        let v: Value = serde_json::from_str(r#"{"tags": ["a", "b"]}"#).unwrap();
+        assert_eq!(find_array(&v, None).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,36 @@
+// src/clock.rs
+use chrono::{Local, NaiveDate};
+use std::sync::OnceLock;
+
+#[cfg(test)]
+thread_local! {
+    static TEST_TODAY: std::cell::Cell<Option<NaiveDate>> = const { std::cell::Cell::new(None) };
+}
+
+/// 실행 전체의 고정 "오늘"(main이 Config::fake_today로 한 번 설정)
+static FIXED_TODAY: OnceLock<NaiveDate> = OnceLock::new();
+
+/// 테스트에서 이 스레드의 "오늘"을 고정(None이면 해제). 전역 값을 건드리지 않아 병렬 테스트끼리 섞이지 않음
+#[cfg(test)]
+pub fn set_today(d: Option<NaiveDate>) {
+    TEST_TODAY.with(|c| c.set(d));
+}
+
+/// 디버그 빌드에서 실행 전체의 "오늘"을 고정(Config::fake_today). 릴리스 빌드와 두 번째 호출은 무시
+pub fn fix_today(d: NaiveDate) {
+    if cfg!(debug_assertions) {
+        let _ = FIXED_TODAY.set(d);
+    }
+}
+
+/// 필터/표시에 쓰는 "오늘"(로컬 날짜). 테스트 스레드 값 → fix_today 값 → 실제 날짜
+pub fn today() -> NaiveDate {
+    #[cfg(test)]
+    if let Some(d) = TEST_TODAY.with(|c| c.get()) {
+        return d;
+    }
+    if let Some(d) = FIXED_TODAY.get() {
+        return *d;
+    }
+    Local::now().date_naive()
+}
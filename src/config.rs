@@ -0,0 +1,1152 @@
+// src/config.rs
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, COOKIE};
+use serde::Deserialize;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::warn;
+
+use crate::campuspick::CampuspickConfig;
+use crate::dacon::DaconOptions;
+use crate::http::HttpOptions;
+use crate::labels::Lang;
+use crate::linkareer::LinkareerOptions;
+use crate::md_write::{GroupBy, MarkdownOptions};
+use crate::rss_ingest::IngestFeed;
+use crate::rss_merged::{MergeOptions, MergeSort};
+use crate::rss_write::{FeedFormat, FeedOptions};
+use crate::wevity::WevityOptions;
+
+/// 설정 파일 기본 경로
+pub const DEFAULT_CONFIG_PATH: &str = "contest-crawler.toml";
+
+/// 전체 설정(contest-crawler.toml). 빠진 키는 기본값, 모르는 키는 에러
+/// env가 있으면 파일 값보다 우선(apply_env)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// 콘솔 프리뷰 건수
+    pub preview_n: usize,
+    pub output: OutputConfig,
+    pub timeouts: Timeouts,
+    pub deadline_days: DeadlineDays,
+    /// 마감까지 최소 남은 일수(이보다 빨리 마감하면 제외, 모든 소스 공통)
+    pub min_deadline_days: i64,
+    pub wevity: WevityOptions,
+    pub campuspick: CampuspickConfig,
+    pub dacon: DaconOptions,
+    pub linkareer: LinkareerOptions,
+    /// 통합 피드에 합칠 외부 RSS/Atom 피드([[ingest]])
+    pub ingest: Vec<IngestFeed>,
+    /// 분야 태그별 추가 피드([[profiles]])
+    pub profiles: Vec<FieldProfile>,
+    /// 마감일 창(포함 구간, 소스별 마감 필터 뒤에 추가로 적용). 한쪽만 있으면 다른 쪽은 열림
+    pub window_start: Option<NaiveDate>,
+    pub window_end: Option<NaiveDate>,
+    /// 디버그 빌드에서 "오늘"을 고정(FAKE_TODAY, clock::fix_today). 릴리스 빌드는 무시
+    pub fake_today: Option<NaiveDate>,
+    /// 통합 RSS/콘솔 프리뷰 최대 건수(마감 임박순 상위 N개, None이면 무제한). 소스별 RSS는 그대로
+    pub limit_total: Option<usize>,
+    /// daemon 실행 주기: 5필드 cron 식(schedule::Schedule, 예: "0 8-23/2 * * *")
+    pub schedule: Option<String>,
+    /// schedule을 계산할 UTC 오프셋("+09:00", 없으면 로컬 시간대)
+    pub schedule_tz: Option<String>,
+    /// 모집 기간(end - start)이 이 일수를 넘는 상시 모집성 항목 제외(None이면 끔)
+    pub max_duration_days: Option<i64>,
+    pub feed: FeedOptions,
+    pub merge: MergeOptions,
+    pub lock: LockConfig,
+    pub baseline: BaselineConfig,
+    pub history: HistoryConfig,
+    /// 지난 마감도 버리지 않고 수집(아카이브 피드용, 각 소스 옵션에 복사)
+    pub archive: bool,
+    /// 중복 판정용 URL 정규화에서 지울 쿼리 파라미터("접두*"는 접두 일치)
+    pub url_strip_params: Vec<String>,
+    pub http: HttpOptions,
+    pub markdown: MarkdownOptions,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            preview_n: 30,
+            output: OutputConfig::default(),
+            timeouts: Timeouts::default(),
+            deadline_days: DeadlineDays::default(),
+            min_deadline_days: 0,
+            wevity: WevityOptions::default(),
+            campuspick: CampuspickConfig::default(),
+            dacon: DaconOptions::default(),
+            linkareer: LinkareerOptions::default(),
+            ingest: Vec::new(),
+            profiles: Vec::new(),
+            window_start: None,
+            window_end: None,
+            fake_today: None,
+            limit_total: None,
+            schedule: None,
+            schedule_tz: None,
+            max_duration_days: None,
+            feed: FeedOptions::default(),
+            merge: MergeOptions::default(),
+            lock: LockConfig::default(),
+            baseline: BaselineConfig::default(),
+            history: HistoryConfig::default(),
+            archive: false,
+            url_strip_params: crate::notice::default_strip_params(),
+            http: HttpOptions::default(),
+            markdown: MarkdownOptions::default(),
+        }
+    }
+}
+
+/// 분야 태그 프로필: tags 중 하나라도 맞는 항목만 담은 피드(output 경로 키는 "field_{name}")
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldProfile {
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+impl FieldProfile {
+    /// Notice::field_tags 또는 표준 분야(classify_field)에 태그가 있으면 true(대소문자 무시)
+    pub fn matches(&self, n: &crate::notice::Notice) -> bool {
+        let mut tags = n.field_tags();
+        tags.extend(crate::notice::classify_field(&n.title, n.field.as_deref()));
+        self.tags.iter().any(|want| tags.iter().any(|t| t.eq_ignore_ascii_case(want.trim())))
+    }
+}
+
+/// 출력 경로([output]). 개별 경로가 없으면 dir 밑에 기본 파일명
+/// json ~ step_summary는 선택 출력(None이면 쓰지 않음, run_summary만 dir/run_summary.json이 기본)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutputConfig {
+    pub dir: String,
+    pub wevity: Option<String>,
+    pub campuspick: Option<String>,
+    pub dacon: Option<String>,
+    pub linkareer: Option<String>,
+    pub merged: Option<String>,
+    pub archive: Option<String>,
+    /// 개별 피드 채널 링크로 사이트 홈 대신 목록 페이지 URL 사용
+    pub list_link: bool,
+    /// 수집 원본 JSON(소스 간 중복 제거 전)
+    pub json: Option<String>,
+    /// 통합 JSON
+    pub json_merged: Option<String>,
+    /// 소스별로 묶은 통합 JSON(버전/generator 메타 포함)
+    pub json_grouped: Option<String>,
+    pub csv: Option<String>,
+    pub ics: Option<String>,
+    pub html: Option<String>,
+    pub markdown: Option<String>,
+    /// Prometheus textfile collector 경로
+    pub prom_textfile: Option<String>,
+    /// SQLite 누적 저장
+    pub sqlite: Option<String>,
+    /// 마감이 지난 공고를 월별 JSONL(YYYY-MM.jsonl) + archive.html로 쌓는 디렉터리(archive::update)
+    pub archive_dir: Option<String>,
+    pub run_summary: Option<String>,
+    /// 실행 통계 JSON(metrics::RunStats)
+    pub stats: Option<String>,
+    /// GitHub Actions job summary(Actions가 GITHUB_STEP_SUMMARY로 넘겨줌)
+    pub step_summary: Option<String>,
+}
+
+impl Default for OutputConfig {
+    fn default() -> Self {
+        Self {
+            dir: "etc-rss".into(),
+            wevity: None,
+            campuspick: None,
+            dacon: None,
+            linkareer: None,
+            merged: None,
+            archive: None,
+            list_link: false,
+            json: None,
+            json_merged: None,
+            json_grouped: None,
+            csv: None,
+            ics: None,
+            html: None,
+            markdown: None,
+            prom_textfile: None,
+            sqlite: None,
+            archive_dir: None,
+            run_summary: None,
+            stats: None,
+            step_summary: None,
+        }
+    }
+}
+
+impl OutputConfig {
+    /// 실행 요약 JSON 경로(없으면 dir/run_summary.json)
+    pub fn run_summary_path(&self) -> String {
+        self.run_summary
+            .clone()
+            .unwrap_or_else(|| Path::new(&self.dir).join("run_summary.json").to_string_lossy().into_owned())
+    }
+
+    /// 피드 키("wevity", "merged" …)별 출력 경로
+    pub fn path(&self, key: &str) -> String {
+        let explicit = match key {
+            "wevity" => &self.wevity,
+            "campuspick" => &self.campuspick,
+            "dacon" => &self.dacon,
+            "linkareer" => &self.linkareer,
+            "merged" => &self.merged,
+            "archive" => &self.archive,
+            _ => &None,
+        };
+        let file = match key {
+            "campuspick" => "campus_pick_rss.xml".to_string(),
+            _ => format!("{key}_rss.xml"),
+        };
+        explicit
+            .clone()
+            .unwrap_or_else(|| Path::new(&self.dir).join(file).to_string_lossy().into_owned())
+    }
+}
+
+/// 실행 잠금([lock]). file이 없으면 잠그지 않음
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LockConfig {
+    pub file: Option<String>,
+    /// 이 초보다 오래된 잠금은 죽은 실행이 남긴 것으로 보고 무시
+    pub stale_secs: i64,
+}
+
+impl Default for LockConfig {
+    fn default() -> Self {
+        Self { file: None, stale_secs: 3600 }
+    }
+}
+
+/// 소스별 기준 건수 대비 급감 경고([baseline]). file이 없으면 끔
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BaselineConfig {
+    pub file: Option<String>,
+    /// 기준으로 삼을 최근 실행 수
+    pub runs: usize,
+    /// 기준(최근 runs회 최솟값)의 이 비율 미만이면 경고
+    pub drop_ratio: f64,
+}
+
+impl Default for BaselineConfig {
+    fn default() -> Self {
+        Self { file: None, runs: 5, drop_ratio: 0.2 }
+    }
+}
+
+/// 날짜별 출력 사본([history]). dir이 없으면 끔
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HistoryConfig {
+    pub dir: Option<String>,
+    /// 이보다 오래된 날짜 디렉터리는 삭제
+    pub keep_days: i64,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self { dir: None, keep_days: 30 }
+    }
+}
+
+/// 소스별 전체 타임아웃(초, [timeouts]) + 수집 단계 전체 예산(total)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Timeouts {
+    pub wevity: u64,
+    pub campuspick: u64,
+    pub dacon: u64,
+    pub linkareer: u64,
+    pub total: u64,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self { wevity: 25, campuspick: 25, dacon: 25, linkareer: 25, total: 90 }
+    }
+}
+
+impl Timeouts {
+    /// 모든 소스 수집을 합친 상한
+    pub fn total_budget(&self) -> Duration {
+        Duration::from_secs(self.total)
+    }
+
+    pub fn get(&self, source: &str) -> Duration {
+        let secs = match source {
+            "wevity" => self.wevity,
+            "campuspick" => self.campuspick,
+            "dacon" => self.dacon,
+            "linkareer" => self.linkareer,
+            _ => 25,
+        };
+        Duration::from_secs(secs)
+    }
+}
+
+/// 마감 필터 일수([deadline_days]): 소스별 값 → default
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeadlineDays {
+    pub default: i64,
+    pub wevity: Option<i64>,
+    pub campuspick: Option<i64>,
+    pub dacon: Option<i64>,
+    pub linkareer: Option<i64>,
+}
+
+impl Default for DeadlineDays {
+    fn default() -> Self {
+        Self { default: 20, wevity: None, campuspick: None, dacon: None, linkareer: None }
+    }
+}
+
+impl DeadlineDays {
+    pub fn get(&self, source: &str) -> i64 {
+        match source {
+            "wevity" => self.wevity,
+            "campuspick" => self.campuspick,
+            "dacon" => self.dacon,
+            "linkareer" => self.linkareer,
+            _ => None,
+        }
+        .unwrap_or(self.default)
+    }
+}
+
+impl Config {
+    /// robots.txt 검사를 끔(페이지를 긁는 wevity/campuspick). 사이트 정책 위반 위험은 사용자 책임
+    pub fn ignore_robots(&mut self) {
+        self.wevity.respect_robots = false;
+        self.campuspick.respect_robots = false;
+    }
+
+    /// 파일을 읽고 env를 덮어씀. 파일이 없으면 기본값, 형식이 잘못되면 Err(문제 키/위치 포함)
+    pub fn load(path: &str) -> Result<Self> {
+        let mut config = match std::fs::read_to_string(path) {
+            Ok(body) => toml::from_str(&body).with_context(|| format!("invalid config {path}"))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e).with_context(|| format!("read config {path}")),
+        };
+        config.apply_env();
+        config.resolve();
+        // 프록시가 잘못돼 있으면 조용히 직결하지 않고 여기서 실패
+        config.http.proxy()?;
+        // 소스별 헤더도 이름/값을 시작할 때 검사
+        for (source, headers) in [
+            ("wevity", &config.wevity.headers),
+            ("campuspick", &config.campuspick.headers),
+            ("dacon", &config.dacon.headers),
+            ("linkareer", &config.linkareer.headers),
+        ] {
+            crate::http::header_map(headers).with_context(|| format!("invalid [{source}.headers] in {path}"))?;
+        }
+        Ok(config)
+    }
+
+    /// 기존 env 이름을 그대로 지원(설정 파일보다 우선)
+    pub fn apply_env(&mut self) {
+        self.apply_env_from(&|key| std::env::var(key).ok());
+    }
+
+    /// apply_env의 본체. env 조회를 lookup으로 받음(테스트용 가짜 env 등)
+    /// 값을 해석할 수 없는 env는 무시하고 파일 값을 유지
+    pub fn apply_env_from(&mut self, lookup: &dyn Fn(&str) -> Option<String>) {
+        let e = Env(lookup);
+        e.set("PREVIEW_N", &mut self.preview_n);
+        e.set_opt("SCHEDULE", &mut self.schedule);
+        e.set_opt("SCHEDULE_TZ", &mut self.schedule_tz);
+        if let Some(n) = e.parse::<usize>("LIMIT_TOTAL").filter(|&n| n > 0) {
+            self.limit_total = Some(n);
+        }
+
+        e.set("RSS_DIR", &mut self.output.dir);
+        e.set_opt("RSS_WEVITY", &mut self.output.wevity);
+        e.set_opt("RSS_CAMPUS", &mut self.output.campuspick);
+        e.set_opt("RSS_DACON", &mut self.output.dacon);
+        e.set_opt("RSS_LINKAREER", &mut self.output.linkareer);
+        e.set_opt("RSS_MERGED", &mut self.output.merged);
+        e.set_opt("RSS_ARCHIVE", &mut self.output.archive);
+        e.set_flag("CHANNEL_LIST_LINK", &mut self.output.list_link);
+
+        e.set("TO_WEVITY", &mut self.timeouts.wevity);
+        e.set("TO_CAMPUS", &mut self.timeouts.campuspick);
+        e.set("TO_DACON", &mut self.timeouts.dacon);
+        e.set("TO_LINKAREER", &mut self.timeouts.linkareer);
+        e.set("TOTAL_BUDGET_SECS", &mut self.timeouts.total);
+
+        // DEADLINE_DAYS_<SOURCE> → 파일의 소스별 값 → DEADLINE_DAYS → 파일의 default
+        // 전역 env는 default만 바꾸므로 파일에 소스별 값이 있으면 그쪽이 이김
+        let d = &mut self.deadline_days;
+        for (key, slot) in [
+            ("DEADLINE_DAYS_WEVITY", &mut d.wevity),
+            ("DEADLINE_DAYS_CAMPUSPICK", &mut d.campuspick),
+            ("DEADLINE_DAYS_DACON", &mut d.dacon),
+            ("DEADLINE_DAYS_LINKAREER", &mut d.linkareer),
+        ] {
+            if let Some(v) = e.parse(key) {
+                *slot = Some(v);
+            }
+        }
+        e.set("DEADLINE_DAYS", &mut d.default);
+        e.set("MIN_DEADLINE_DAYS", &mut self.min_deadline_days);
+        if let Some(d) = e.parse("WINDOW_START") {
+            self.window_start = Some(d);
+        }
+        if let Some(d) = e.parse("WINDOW_END") {
+            self.window_end = Some(d);
+        }
+        if let Some(d) = e.parse("FAKE_TODAY") {
+            self.fake_today = Some(d);
+        }
+        if let Some(n) = e.parse("MAX_DURATION_DAYS") {
+            self.max_duration_days = Some(n);
+        }
+
+        e.set_opt("JSON_OUT", &mut self.output.json);
+        e.set_opt("JSON_MERGED", &mut self.output.json_merged);
+        e.set_opt("JSON_GROUPED", &mut self.output.json_grouped);
+        e.set_opt("CSV_OUT", &mut self.output.csv);
+        e.set_opt("ICS_MERGED", &mut self.output.ics);
+        e.set_opt("HTML_OUT", &mut self.output.html);
+        e.set_opt("MD_OUT", &mut self.output.markdown);
+        e.set_opt("PROM_TEXTFILE", &mut self.output.prom_textfile);
+        e.set_opt("SQLITE_PATH", &mut self.output.sqlite);
+        e.set_opt("ARCHIVE_DIR", &mut self.output.archive_dir);
+        e.set_opt("RUN_SUMMARY_PATH", &mut self.output.run_summary);
+        e.set_opt("STATS_FILE", &mut self.output.stats);
+        e.set_opt("GITHUB_STEP_SUMMARY", &mut self.output.step_summary);
+
+        e.set_opt("LOCK_FILE", &mut self.lock.file);
+        e.set("LOCK_STALE_SECS", &mut self.lock.stale_secs);
+        e.set_opt("BASELINE_FILE", &mut self.baseline.file);
+        e.set("BASELINE_RUNS", &mut self.baseline.runs);
+        e.set("BASELINE_DROP_RATIO", &mut self.baseline.drop_ratio);
+        e.set_opt("ARCHIVE_DIR", &mut self.history.dir);
+        e.set("ARCHIVE_KEEP_DAYS", &mut self.history.keep_days);
+
+        let f = &mut self.feed;
+        match e.var("LABEL_LANG").as_deref() {
+            Some("en") => f.lang = Lang::En,
+            Some(_) => f.lang = Lang::Ko,
+            None => {}
+        }
+        e.set_flag("LABEL_KR_MARK", &mut f.kr_mark);
+        if let Some(t) = e.parse("FEED_TTL_MIN") {
+            f.ttl_min = Some(t);
+        }
+        e.set("FEED_GENERATOR", &mut f.generator);
+        if let Some(id) = e.var("CRAWL_ID")
+            && !id.trim().is_empty() {
+            f.generator = format!("{} (crawl {})", f.generator, id.trim());
+        }
+        e.set_flag("VERIFY_FEED", &mut f.verify);
+        match e.var("FEED_FORMAT").as_deref() {
+            Some("atom") => f.format = FeedFormat::Atom,
+            Some("both") => f.format = FeedFormat::Both,
+            Some(_) => f.format = FeedFormat::Rss,
+            None => {}
+        }
+        e.set_flag("HIDE_EMPTY_FIELDS", &mut f.hide_empty);
+        if let Some(d) = e.parse("FEED_GRACE_DAYS") {
+            f.grace_days = Some(d);
+        }
+        e.set("FEED_GRACE_MAX_ITEMS", &mut f.grace_max_items);
+        if let Some(url) = e.var("FEED_PUBLIC_URL") {
+            f.public_url = Some(url).filter(|s| !s.trim().is_empty());
+        }
+
+        let m = &mut self.merge;
+        match e.var("MERGE_SORT").as_deref() {
+            Some("opening_soon") => m.sort = MergeSort::OpeningSoon,
+            Some(_) => m.sort = MergeSort::Latest,
+            None => {}
+        }
+        if let Some(n) = e.parse("MAX_PER_ORG") {
+            m.max_per_org = Some(n);
+        }
+        if let Some(n) = e.parse::<usize>("MERGED_MAX_ITEMS") {
+            m.max_items = Some(n).filter(|&n| n > 0);
+        }
+        e.set_flag("MERGED_PAGED", &mut m.paged);
+
+        e.set("WEVITY_BUDGET_SECS", &mut self.wevity.budget_secs);
+        e.set("WEVITY_MAX_PAGES", &mut self.wevity.max_pages);
+        e.set("WEVITY_MAX_CONC", &mut self.wevity.max_conc);
+        e.set("WEVITY_MAX_RPS", &mut self.wevity.max_rps);
+        e.set("WEVITY_BASE_URL", &mut self.wevity.base_url);
+        e.set("WEVITY_ACTIVITY_URL", &mut self.wevity.activity_list_url);
+        if let Some(list) = e.list("WEVITY_CONTEST_URLS") {
+            self.wevity.contest_list_urls = list;
+        }
+
+        let c = &mut self.campuspick;
+        e.set("CAMPUS_ACTIVITY_API", &mut c.activity_api);
+        e.set("CAMPUS_CONTEST_API", &mut c.contest_api);
+        e.set("CAMPUS_ACTIVITY_METHOD", &mut c.activity_method);
+        e.set("CAMPUS_CONTEST_METHOD", &mut c.contest_method);
+        e.set("CAMPUS_ACTIVITY_BODY", &mut c.activity_body);
+        e.set("CAMPUS_CONTEST_BODY", &mut c.contest_body);
+        e.set("CAMPUS_LIMIT", &mut c.limit);
+        e.set("CAMPUS_PAGES", &mut c.pages);
+        e.set("CAMPUS_DELAY_MS", &mut c.delay_ms);
+        e.set("CAMPUS_RETRIES", &mut c.retries);
+        e.set("CAMPUS_BACKOFF_MS", &mut c.backoff_ms);
+        e.set("CAMPUS_MAX_RPS", &mut c.max_rps);
+        c.session_headers = session_headers(e.var("CAMPUSPICK_HEADERS"), e.var("CAMPUSPICK_COOKIE"));
+        e.set_opt("CAMPUS_LIST_PATH", &mut c.list_path);
+        e.set("CAMPUS_WEB_BASE", &mut c.web_base);
+        e.set("CAMPUS_DETAIL_URL", &mut c.detail_url);
+        // CAMPUS_DETAIL_JSON: 쉼표로 구분한 템플릿 목록
+        if let Some(list) = e.list("CAMPUS_DETAIL_JSON") {
+            c.detail_json = list;
+        }
+
+        if e.var("DACON_ALL").is_some_and(|v| v == "1") {
+            self.dacon.all_keywords = true;
+        }
+        e.set("DACON_RETRIES", &mut self.dacon.retries);
+        e.set("DACON_BACKOFF_MS", &mut self.dacon.backoff_ms);
+        e.set("DACON_LIST_API", &mut self.dacon.list_api);
+        e.set("DACON_MAX_RPS", &mut self.dacon.max_rps);
+        e.set("LINKAREER_API", &mut self.linkareer.api);
+        e.set("LINKAREER_MAX_RPS", &mut self.linkareer.max_rps);
+        e.set("LINKAREER_RETRIES", &mut self.linkareer.retries);
+        e.set("LINKAREER_BACKOFF_MS", &mut self.linkareer.backoff_ms);
+
+        // INGEST_FEEDS: "라벨=URL"을 쉼표로 구분(파일의 [[ingest]]를 대체)
+        if let Some(list) = e.var("INGEST_FEEDS") {
+            self.ingest = list
+                .split(',')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(label, url)| IngestFeed { label: label.trim().to_string(), url: url.trim().to_string() })
+                .filter(|f| !f.label.is_empty() && !f.url.is_empty())
+                .collect();
+        }
+
+        // FIELD_PROFILES: "이름=태그|태그"를 쉼표로 구분(파일의 [[profiles]]를 대체)
+        if let Some(list) = e.var("FIELD_PROFILES") {
+            self.profiles = list
+                .split(',')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(name, tags)| FieldProfile {
+                    name: name.trim().to_string(),
+                    tags: tags.split('|').map(str::trim).filter(|t| !t.is_empty()).map(str::to_string).collect(),
+                })
+                .filter(|p| !p.name.is_empty() && !p.tags.is_empty())
+                .collect();
+        }
+
+        if e.var("IGNORE_ROBOTS").is_some_and(|v| v == "1") {
+            self.ignore_robots();
+        }
+
+        if let Some(v) = e.var("ARCHIVE") {
+            self.archive = v == "1";
+        }
+        if let Some(list) = e.list("URL_STRIP_PARAMS") {
+            self.url_strip_params = list;
+        }
+
+        e.set("POOL_MAX_IDLE", &mut self.http.pool_max_idle);
+        if let Some(url) = ["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"]
+            .iter()
+            .find_map(|k| e.var(k).filter(|v| !v.trim().is_empty())) {
+            self.http.proxy = Some(url);
+        }
+        if let Some(list) = e.var("NO_PROXY").or_else(|| e.var("no_proxy")) {
+            self.http.no_proxy = Some(list);
+        }
+
+        match e.var("MD_GROUP_BY").as_deref() {
+            Some("kind") => self.markdown.group_by = GroupBy::Kind,
+            Some(_) => self.markdown.group_by = GroupBy::Week,
+            None => {}
+        }
+        if let Some(v) = e.var("MD_CHECKBOX") {
+            self.markdown.checkboxes = v != "0";
+        }
+    }
+
+    /// 크롤링 없이 설정을 점검(check-config): 키워드 정규식, 출력 디렉터리 쓰기 권한, 소스 URL 형식
+    /// 문제마다 한 줄씩 돌려줌(비어 있으면 통과). 디렉터리는 만들지 않고 가장 가까운 기존 상위로 판단
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if let Some(expr) = &self.schedule
+            && let Err(e) = crate::schedule::Schedule::parse(expr) {
+            problems.push(format!("schedule: invalid cron expression {expr:?}: {e:#}"));
+        }
+        if let Err(e) = crate::schedule::parse_offset(self.schedule_tz.as_deref()) {
+            problems.push(format!("schedule_tz: {e:#}"));
+        }
+
+        for (key, keywords) in [
+            ("wevity.activity_keywords", &self.wevity.activity_keywords),
+            ("campuspick.activity_keywords", &self.campuspick.activity_keywords),
+            ("dacon.keywords", &self.dacon.keywords),
+            ("linkareer.keywords", &self.linkareer.keywords),
+        ] {
+            if let Err(e) = crate::keywords::KeywordMatcher::new(keywords) {
+                problems.push(format!("{key}: invalid regex: {e}"));
+            }
+        }
+
+        let o = &self.output;
+        let mut dirs: Vec<std::path::PathBuf> = vec![Path::new(&o.dir).to_path_buf()];
+        dirs.extend(o.archive_dir.iter().map(|d| Path::new(d).to_path_buf()));
+        let mut files: Vec<String> = ["wevity", "campuspick", "dacon", "linkareer", "merged", "archive"]
+            .iter()
+            .map(|k| o.path(k))
+            .collect();
+        files.push(o.run_summary_path());
+        files.extend(
+            [&o.json, &o.json_merged, &o.json_grouped, &o.csv, &o.ics, &o.html, &o.markdown, &o.prom_textfile, &o.sqlite, &o.stats, &o.step_summary]
+                .into_iter()
+                .flatten()
+                .cloned(),
+        );
+        for file in &files {
+            if let Some(parent) = Path::new(file).parent() {
+                dirs.push(parent.to_path_buf());
+            }
+        }
+        dirs.sort();
+        dirs.dedup();
+        for dir in &dirs {
+            if let Err(e) = check_writable(dir) {
+                problems.push(format!("output dir {}: {e}", dir.display()));
+            }
+        }
+
+        let mut urls: Vec<(String, String)> = vec![
+            ("wevity.base_url".into(), self.wevity.base_url.clone()),
+            ("wevity.activity_list_url".into(), self.wevity.activity_list_url.clone()),
+            ("campuspick.activity_api".into(), self.campuspick.activity_api.clone()),
+            ("campuspick.contest_api".into(), self.campuspick.contest_api.clone()),
+            ("campuspick.web_base".into(), self.campuspick.web_base.clone()),
+            ("campuspick.detail_url".into(), fill_placeholders(&self.campuspick.detail_url)),
+            ("dacon.list_api".into(), self.dacon.list_api.clone()),
+            ("linkareer.api".into(), self.linkareer.api.clone()),
+            ("linkareer.detail_url".into(), fill_placeholders(&self.linkareer.detail_url)),
+        ];
+        urls.extend(self.wevity.contest_list_urls.iter().map(|u| ("wevity.contest_list_urls".into(), u.clone())));
+        urls.extend(self.campuspick.detail_json.iter().map(|u| ("campuspick.detail_json".into(), fill_placeholders(u))));
+        urls.extend(self.ingest.iter().map(|f| (format!("ingest {}", f.label), f.url.clone())));
+        for (key, url) in urls {
+            match reqwest::Url::parse(&url) {
+                Ok(u) if matches!(u.scheme(), "http" | "https") => {}
+                Ok(u) => problems.push(format!("{key}: unsupported scheme {:?} in {url}", u.scheme())),
+                Err(e) => problems.push(format!("{key}: invalid URL {url:?}: {e}")),
+            }
+        }
+
+        if let (Some(start), Some(end)) = (self.window_start, self.window_end)
+            && start > end
+        {
+            problems.push(format!("window_start {start} is after window_end {end}"));
+        }
+        problems
+    }
+
+    /// 이번 설정으로 쓰는 출력 파일 경로(history 사본 대상). RSS_MERGED/JSON_OUT 등으로 dir 밖을 가리켜도 포함
+    /// - 피드: 소스별/통합/아카이브/분야 프로필, feed.format에 따라 RSS·Atom, 통합 피드의 2번째 이후 페이지
+    /// - step_summary는 Actions가 관리하는 파일이라 제외
+    pub fn output_files(&self) -> Vec<String> {
+        let o = &self.output;
+        let mut feeds: Vec<String> = ["wevity", "campuspick", "dacon", "linkareer", "merged", "archive"]
+            .iter()
+            .map(|k| o.path(k))
+            .collect();
+        feeds.extend(self.profiles.iter().map(|p| o.path(&format!("field_{}", p.name))));
+
+        let mut files = Vec::new();
+        for feed in feeds {
+            if self.feed.format != FeedFormat::Rss {
+                files.push(crate::rss_write::atom_path(&feed));
+            }
+            if self.feed.format != FeedFormat::Atom {
+                if feed == o.path("merged") {
+                    files.extend(crate::rss_merged::extra_pages(&feed));
+                }
+                files.push(feed);
+            }
+        }
+        files.push(o.run_summary_path());
+        files.extend(
+            [&o.json, &o.json_merged, &o.json_grouped, &o.csv, &o.ics, &o.html, &o.markdown, &o.prom_textfile, &o.sqlite, &o.stats]
+                .into_iter()
+                .flatten()
+                .cloned(),
+        );
+        files
+    }
+
+    /// [deadline_days]/min_deadline_days/archive/[http]를 각 소스 옵션에 반영
+    pub fn resolve(&mut self) {
+        // 파일의 limit_total = 0은 "제한 없음"(LIMIT_TOTAL=0은 무시, --limit-total 0은 파싱 단계에서 거부)
+        self.limit_total = self.limit_total.filter(|&n| n > 0);
+        self.feed.strip_params = self.url_strip_params.clone();
+        self.wevity.deadline_days = self.deadline_days.get("wevity");
+        self.campuspick.deadline_days = self.deadline_days.get("campuspick");
+        self.dacon.deadline_days = self.deadline_days.get("dacon");
+        self.linkareer.deadline_days = self.deadline_days.get("linkareer");
+        self.wevity.min_deadline_days = self.min_deadline_days;
+        self.campuspick.min_deadline_days = self.min_deadline_days;
+        self.dacon.min_deadline_days = self.min_deadline_days;
+        self.linkareer.min_deadline_days = self.min_deadline_days;
+        self.wevity.archive = self.archive;
+        self.campuspick.archive = self.archive;
+        self.dacon.archive = self.archive;
+        self.linkareer.archive = self.archive;
+        self.wevity.http = self.http.clone();
+        self.campuspick.http = self.http.clone();
+        self.dacon.http = self.http.clone();
+        self.linkareer.http = self.http.clone();
+    }
+}
+
+/// URL 템플릿의 {kind}/{id}를 예시 값으로 채움(형식 검사용)
+fn fill_placeholders(tpl: &str) -> String {
+    tpl.replace("{kind}", "contest").replace("{id}", "1")
+}
+
+/// dir(없으면 가장 가까운 기존 상위)에 임시 파일을 만들었다 지워 쓰기 권한 확인
+fn check_writable(dir: &Path) -> std::result::Result<(), String> {
+    let mut probe_dir = dir;
+    while !probe_dir.as_os_str().is_empty() && !probe_dir.exists() {
+        probe_dir = probe_dir.parent().unwrap_or(Path::new(""));
+    }
+    let probe_dir = if probe_dir.as_os_str().is_empty() { Path::new(".") } else { probe_dir };
+    if !probe_dir.is_dir() {
+        return Err(format!("{} is not a directory", probe_dir.display()));
+    }
+    let probe = probe_dir.join(format!(".check-config-{}", std::process::id()));
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .map_err(|e| format!("not writable ({e})"))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+/// 로그인 세션 헤더: CAMPUSPICK_COOKIE(Cookie 값 그대로), CAMPUSPICK_HEADERS(줄마다 "Name: Value")
+/// 잘못된 줄은 경고 후 건너뜀(값은 로그에 남기지 않음). Cookie/Authorization은 sensitive로 표시
+fn session_headers(list: Option<String>, cookie: Option<String>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(list) = list {
+        for (lineno, line) in list.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let parsed = line.split_once(':').and_then(|(name, value)| {
+                Some((HeaderName::from_bytes(name.trim().as_bytes()).ok()?, HeaderValue::from_str(value.trim()).ok()?))
+            });
+            match parsed {
+                Some((name, mut value)) => {
+                    value.set_sensitive(name == COOKIE || name == AUTHORIZATION);
+                    headers.append(name, value);
+                }
+                None => warn!(line = lineno + 1, "CAMPUSPICK_HEADERS: expected \"Name: Value\", skipped"),
+            }
+        }
+    }
+    if let Some(cookie) = cookie
+        && !cookie.trim().is_empty()
+    {
+        match HeaderValue::from_str(cookie.trim()) {
+            Ok(mut value) => {
+                value.set_sensitive(true);
+                headers.insert(COOKIE, value);
+            }
+            Err(_) => warn!("CAMPUSPICK_COOKIE: invalid header value, ignored"),
+        }
+    }
+    headers
+}
+
+/// apply_env_from에서 쓰는 env 조회 도우미
+struct Env<'a>(&'a dyn Fn(&str) -> Option<String>);
+
+impl Env<'_> {
+    fn var(&self, key: &str) -> Option<String> {
+        (self.0)(key)
+    }
+
+    fn parse<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.var(key).and_then(|s| s.parse().ok())
+    }
+
+    /// env가 있고 파싱되면 덮어씀(잘못된 값은 무시)
+    fn set<T: FromStr>(&self, key: &str, slot: &mut T) {
+        if let Some(v) = self.parse(key) {
+            *slot = v;
+        }
+    }
+
+    /// 쉼표로 구분한 목록 env(빈 항목 제외)
+    fn list(&self, key: &str) -> Option<Vec<String>> {
+        let list = self.var(key)?;
+        Some(list.split(',').map(str::trim).filter(|t| !t.is_empty()).map(str::to_string).collect())
+    }
+
+    /// "1"이면 true, 다른 값이면 false(env가 없으면 그대로)
+    fn set_flag(&self, key: &str, slot: &mut bool) {
+        if let Some(v) = self.var(key) {
+            *slot = v == "1";
+        }
+    }
+
+    fn set_opt(&self, key: &str, slot: &mut Option<String>) {
+        if let Some(v) = self.var(key) {
+            *slot = Some(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// 파일 본문 + 가짜 env로 load와 같은 순서(파싱 → env → resolve)를 밟음
+    fn load_with(toml_body: &str, vars: &[(&str, &str)]) -> Config {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        let mut cfg: Config = toml::from_str(toml_body).unwrap();
+        cfg.apply_env_from(&|k| vars.get(k).cloned());
+        cfg.resolve();
+        cfg
+    }
+
+    #[test]
+    fn global_deadline_env_does_not_override_file_per_source_value() {
+        let cfg = load_with("[deadline_days]\nwevity = 10\n", &[("DEADLINE_DAYS", "60")]);
+        assert_eq!(cfg.wevity.deadline_days, 10);
+        assert_eq!(cfg.dacon.deadline_days, 60);
+        assert_eq!(cfg.campuspick.deadline_days, 60);
+    }
+
+    #[test]
+    fn per_source_deadline_env_wins_over_file() {
+        let cfg = load_with(
+            "[deadline_days]\ndefault = 30\nwevity = 10\n",
+            &[("DEADLINE_DAYS_WEVITY", "5"), ("DEADLINE_DAYS_DACON", "7")],
+        );
+        assert_eq!(cfg.wevity.deadline_days, 5);
+        assert_eq!(cfg.dacon.deadline_days, 7);
+        assert_eq!(cfg.linkareer.deadline_days, 30);
+    }
+
+    #[test]
+    fn defaults_without_file_or_env() {
+        let cfg = load_with("", &[]);
+        assert_eq!(cfg.wevity.deadline_days, 20);
+        assert_eq!(cfg.lock.stale_secs, 3600);
+        assert_eq!(cfg.baseline.runs, 5);
+        assert_eq!(cfg.history.keep_days, 30);
+        assert_eq!(cfg.feed.format, FeedFormat::Rss);
+        assert_eq!(cfg.merge.sort, MergeSort::Latest);
+        assert!(cfg.output.json.is_none());
+        assert_eq!(cfg.output.run_summary_path(), Path::new("etc-rss").join("run_summary.json").to_string_lossy());
+    }
+
+    #[test]
+    fn feed_and_merge_sections_from_file_and_env() {
+        let cfg = load_with(
+            "[feed]\nformat = \"both\"\nlang = \"en\"\ngrace_days = 3\n[merge]\nmax_items = 50\n",
+            &[("MERGE_SORT", "opening_soon"), ("CRAWL_ID", "run-7"), ("MERGED_MAX_ITEMS", "0")],
+        );
+        assert_eq!(cfg.feed.format, FeedFormat::Both);
+        assert_eq!(cfg.feed.lang, Lang::En);
+        assert_eq!(cfg.feed.grace_days, Some(3));
+        assert!(cfg.feed.generator.ends_with("(crawl run-7)"));
+        assert_eq!(cfg.merge.sort, MergeSort::OpeningSoon);
+        // 0은 무제한
+        assert_eq!(cfg.merge.max_items, None);
+    }
+
+    #[test]
+    fn run_level_options_from_env() {
+        let cfg = load_with(
+            "",
+            &[
+                ("MAX_DURATION_DAYS", "180"),
+                ("LOCK_FILE", "/tmp/x.lock"),
+                ("LOCK_STALE_SECS", "60"),
+                ("BASELINE_FILE", "b.json"),
+                ("BASELINE_DROP_RATIO", "0.5"),
+                ("ARCHIVE_DIR", "archive"),
+                ("ARCHIVE_KEEP_DAYS", "7"),
+                ("CSV_OUT", "out.csv"),
+            ],
+        );
+        assert_eq!(cfg.max_duration_days, Some(180));
+        assert_eq!(cfg.lock.file.as_deref(), Some("/tmp/x.lock"));
+        assert_eq!(cfg.lock.stale_secs, 60);
+        assert_eq!(cfg.baseline.file.as_deref(), Some("b.json"));
+        assert_eq!(cfg.baseline.drop_ratio, 0.5);
+        assert_eq!(cfg.history.dir.as_deref(), Some("archive"));
+        assert_eq!(cfg.history.keep_days, 7);
+        assert_eq!(cfg.output.csv.as_deref(), Some("out.csv"));
+    }
+
+    #[test]
+    fn archive_http_and_markdown_come_from_config() {
+        let cfg = load_with(
+            "archive = true\nurl_strip_params = [\"ref\"]\n[http]\npool_max_idle = 8\n[markdown]\ngroup_by = \"kind\"\n",
+            &[("ALL_PROXY", "socks5h://127.0.0.1:1080"), ("NO_PROXY", "localhost"), ("MD_CHECKBOX", "0")],
+        );
+        assert!(cfg.wevity.archive && cfg.campuspick.archive && cfg.dacon.archive && cfg.linkareer.archive);
+        assert_eq!(cfg.feed.strip_params, cfg.url_strip_params);
+        assert_eq!(cfg.url_strip_params, vec!["ref".to_string()]);
+        assert_eq!(cfg.dacon.http.pool_max_idle, 8);
+        assert_eq!(cfg.wevity.http.proxy.as_deref(), Some("socks5h://127.0.0.1:1080"));
+        assert_eq!(cfg.campuspick.http.no_proxy.as_deref(), Some("localhost"));
+        assert!(cfg.http.proxy().unwrap().is_some());
+        assert_eq!(cfg.markdown.group_by, GroupBy::Kind);
+        assert!(!cfg.markdown.checkboxes);
+    }
+
+    #[test]
+    fn invalid_proxy_is_rejected() {
+        let cfg = load_with("", &[("HTTPS_PROXY", "ftp://proxy:21")]);
+        assert!(cfg.http.proxy().is_err());
+        // ARCHIVE=0 env가 파일 값을 끔
+        let cfg = load_with("archive = true\n", &[("ARCHIVE", "0")]);
+        assert!(!cfg.linkareer.archive);
+    }
+
+    #[test]
+    fn campuspick_session_headers_are_parsed_and_redacted() {
+        let cfg = load_with(
+            "",
+            &[
+                ("CAMPUSPICK_HEADERS", "X-Client: web\nAuthorization: Bearer secret-token\nbroken line\n"),
+                ("CAMPUSPICK_COOKIE", "sid=secret-cookie"),
+            ],
+        );
+        let h = &cfg.campuspick.session_headers;
+        assert_eq!(h.get("x-client").unwrap(), "web");
+        assert_eq!(h.get(COOKIE).unwrap(), "sid=secret-cookie");
+        assert!(h.get(COOKIE).unwrap().is_sensitive());
+        assert!(h.get(AUTHORIZATION).unwrap().is_sensitive());
+        assert!(!h.get("x-client").unwrap().is_sensitive());
+        assert_eq!(h.len(), 3);
+        let shown = format!("{cfg:?}");
+        assert!(!shown.contains("secret-cookie") && !shown.contains("secret-token"), "{shown}");
+    }
+
+    #[test]
+    fn per_source_headers_from_file() {
+        let cfg = load_with("[campuspick.headers]\nX-Requested-With = \"XMLHttpRequest\"\n", &[]);
+        assert_eq!(cfg.campuspick.headers.get("X-Requested-With").map(String::as_str), Some("XMLHttpRequest"));
+        assert!(cfg.wevity.headers.is_empty());
+    }
+
+    #[test]
+    fn linkareer_retry_options_from_env() {
+        let cfg = load_with("[linkareer]\nretries = 5\n", &[("LINKAREER_BACKOFF_MS", "50")]);
+        assert_eq!((cfg.linkareer.retries, cfg.linkareer.backoff_ms), (5, 50));
+        let cfg = load_with("[linkareer]\nretries = 5\n", &[("LINKAREER_RETRIES", "1")]);
+        assert_eq!(cfg.linkareer.retries, 1);
+    }
+
+    #[test]
+    fn unparsable_env_keeps_file_value() {
+        let cfg = load_with("preview_n = 12\n", &[("PREVIEW_N", "many")]);
+        assert_eq!(cfg.preview_n, 12);
+    }
+
+    #[test]
+    fn unknown_key_is_an_error_naming_the_key() {
+        let err = toml::from_str::<Config>("[feed]\nfromat = \"atom\"\n").unwrap_err();
+        assert!(err.to_string().contains("fromat"), "{err}");
+    }
+
+    /// 출력 dir만 임시 디렉터리로 돌린 설정(검사가 작업 디렉터리를 건드리지 않게)
+    fn in_tempdir(extra: &str) -> (tempfile::TempDir, Config) {
+        let tmp = tempfile::tempdir().unwrap();
+        let body = format!("[output]\ndir = {:?}\n{extra}", tmp.path().join("out").display().to_string());
+        let cfg = load_with(&body, &[]);
+        (tmp, cfg)
+    }
+
+    #[test]
+    fn default_config_validates_clean() {
+        let (_tmp, cfg) = in_tempdir("");
+        assert_eq!(cfg.validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn invalid_regex_keyword_is_a_validation_error() {
+        let (_tmp, cfg) = in_tempdir("[dacon]\nkeywords = [\"re:(unclosed\", \"AI\"]\n");
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].starts_with("dacon.keywords: invalid regex"), "{problems:?}");
+    }
+
+    #[test]
+    fn bad_urls_and_unwritable_dir_are_reported() {
+        let tmp = tempfile::tempdir().unwrap();
+        let file = tmp.path().join("plain-file");
+        std::fs::write(&file, "x").unwrap();
+        let body = format!(
+            "[output]\ndir = {:?}\n[linkareer]\napi = \"not a url\"\n[[ingest]]\nlabel = \"x\"\nurl = \"ftp://example.com/feed\"\n",
+            file.join("sub").display().to_string()
+        );
+        let problems = load_with(&body, &[]).validate();
+        assert!(problems.iter().any(|p| p.starts_with("linkareer.api: invalid URL")), "{problems:?}");
+        assert!(problems.iter().any(|p| p.starts_with("ingest x: unsupported scheme")), "{problems:?}");
+        assert!(problems.iter().any(|p| p.starts_with("output dir") && p.contains("not a directory")), "{problems:?}");
+    }
+
+    #[test]
+    fn output_files_follow_env_redirects_and_feed_format() {
+        let cfg = load_with(
+            "[output]\ndir = \"out\"\n[[profiles]]\nname = \"ai\"\ntags = [\"AI\"]\n",
+            &[("RSS_MERGED", "/srv/site/merged_rss.xml"), ("JSON_OUT", "/srv/site/all.json"), ("FEED_FORMAT", "both")],
+        );
+        let files = cfg.output_files();
+        for want in [
+            "/srv/site/merged_rss.xml",
+            "/srv/site/merged_atom.xml",
+            "/srv/site/all.json",
+            "out/dacon_rss.xml",
+            "out/dacon_atom.xml",
+            "out/field_ai_rss.xml",
+            "out/run_summary.json",
+        ] {
+            assert!(files.iter().any(|f| f == want), "{want} missing from {files:?}");
+        }
+
+        let atom_only = load_with("[output]\ndir = \"out\"\n", &[("FEED_FORMAT", "atom")]).output_files();
+        assert!(atom_only.iter().any(|f| f == "out/dacon_atom.xml"));
+        assert!(!atom_only.iter().any(|f| f == "out/dacon_rss.xml"));
+    }
+
+    #[test]
+    fn dacon_all_flag_from_env() {
+        assert!(!load_with("", &[]).dacon.all_keywords);
+        assert!(load_with("", &[("DACON_ALL", "1")]).dacon.all_keywords);
+        assert!(!load_with("", &[("DACON_ALL", "0")]).dacon.all_keywords);
+    }
+
+    #[test]
+    fn output_paths_join_dir_with_trailing_slash_and_keep_absolute_overrides() {
+        let cfg = load_with("", &[("RSS_DIR", "etc-rss/"), ("RSS_WEVITY", "/srv/feeds/wevity.xml")]);
+        assert_eq!(cfg.output.path("dacon"), "etc-rss/dacon_rss.xml");
+        assert_eq!(cfg.output.path("campuspick"), "etc-rss/campus_pick_rss.xml");
+        assert_eq!(cfg.output.path("wevity"), "/srv/feeds/wevity.xml");
+        assert_eq!(cfg.output.run_summary_path(), "etc-rss/run_summary.json");
+
+        let plain = load_with("", &[("RSS_DIR", "etc-rss")]);
+        assert_eq!(plain.output.path("merged"), "etc-rss/merged_rss.xml");
+    }
+
+    #[test]
+    fn pool_max_idle_from_file_and_env() {
+        assert_eq!(load_with("", &[]).http.pool_max_idle, 4);
+        assert_eq!(load_with("[http]\npool_max_idle = 8\n", &[]).http.pool_max_idle, 8);
+        assert_eq!(load_with("[http]\npool_max_idle = 8\n", &[("POOL_MAX_IDLE", "1")]).http.pool_max_idle, 1);
+    }
+
+    #[test]
+    fn campuspick_detail_templates_from_env() {
+        let cfg = load_with(
+            "[campuspick]\ndetail_url = \"https://file.example/{kind}/{id}\"\n",
+            &[
+                ("CAMPUS_DETAIL_URL", "https://env.example/{kind}?id={id}"),
+                ("CAMPUS_DETAIL_JSON", "https://a.example/{id}, https://b.example/{kind}/{id}"),
+            ],
+        );
+        assert_eq!(cfg.campuspick.detail_url, "https://env.example/{kind}?id={id}");
+        assert_eq!(cfg.campuspick.detail_json, ["https://a.example/{id}", "https://b.example/{kind}/{id}"]);
+        // env가 없으면 파일 값
+        let cfg = load_with("[campuspick]\ndetail_url = \"https://file.example/{kind}/{id}\"\n", &[]);
+        assert_eq!(cfg.campuspick.detail_url, "https://file.example/{kind}/{id}");
+    }
+
+    #[test]
+    fn feed_ttl_and_generator_from_env() {
+        assert_eq!(load_with("", &[]).feed.ttl_min, None);
+        let cfg = load_with("[feed]\nttl_min = 30\n", &[("FEED_TTL_MIN", "120"), ("FEED_GENERATOR", "my-crawler")]);
+        assert_eq!(cfg.feed.ttl_min, Some(120));
+        assert_eq!(cfg.feed.generator, "my-crawler");
+        // 숫자가 아니면 파일 값 유지
+        assert_eq!(load_with("[feed]\nttl_min = 30\n", &[("FEED_TTL_MIN", "1h")]).feed.ttl_min, Some(30));
+    }
+
+    #[test]
+    fn field_profile_matches_split_tags_case_insensitively() {
+        let profile = FieldProfile { name: "game".into(), tags: vec![" 게임 ".into(), "it".into()] };
+        let with = |field: Option<&str>| crate::notice::Notice {
+            source: crate::notice::Source::Wevity,
+            kind: crate::notice::Kind::Contest,
+            title: "공모전".into(),
+            raw_title: String::new(),
+            url: String::new(),
+            start: None,
+            end: None,
+            organizer: None,
+            field: field.map(str::to_string),
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        };
+        assert!(profile.matches(&with(Some("문학·게임"))));
+        assert!(profile.matches(&with(Some("웹/모바일/IT"))));
+        assert!(!profile.matches(&with(Some("사진/영상"))));
+        assert!(!profile.matches(&with(None)));
+    }
+
+    #[test]
+    fn window_bounds_from_env() {
+        let cfg = load_with("", &[("WINDOW_START", "2026-11-01")]);
+        assert_eq!(cfg.window_start, NaiveDate::from_ymd_opt(2026, 11, 1));
+        assert_eq!(cfg.window_end, None);
+        let dir = tempfile::tempdir().unwrap();
+        let file = format!("[output]\ndir = {:?}\n", dir.path().display().to_string());
+        let cfg = load_with(&file, &[("WINDOW_START", "2026-11-30"), ("WINDOW_END", "2026-11-01")]);
+        assert!(cfg.validate().iter().any(|p| p.contains("is after window_end")), "{:?}", cfg.validate());
+    }
+
+    #[test]
+    fn limit_total_from_env_ignores_zero() {
+        assert_eq!(load_with("", &[("LIMIT_TOTAL", "20")]).limit_total, Some(20));
+        assert_eq!(load_with("limit_total = 5\n", &[("LIMIT_TOTAL", "0")]).limit_total, Some(5));
+        assert_eq!(load_with("limit_total = 0\n", &[]).limit_total, None);
+        assert_eq!(load_with("", &[]).limit_total, None);
+    }
+
+    #[test]
+    fn fake_today_from_env() {
+        assert_eq!(load_with("", &[]).fake_today, None);
+        assert_eq!(load_with("", &[("FAKE_TODAY", "2026-10-14")]).fake_today, NaiveDate::from_ymd_opt(2026, 10, 14));
+        assert_eq!(load_with("fake_today = \"2026-10-01\"\n", &[("FAKE_TODAY", "soon")]).fake_today, NaiveDate::from_ymd_opt(2026, 10, 1));
+    }
+
+    #[test]
+    fn invalid_schedule_is_a_validation_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let with = |top: &str, vars: &[(&str, &str)]| {
+            load_with(&format!("{top}[output]\ndir = {:?}\n", tmp.path().join("out").display().to_string()), vars).validate()
+        };
+        let ok = with("schedule = \"0 8-23/2 * * *\"\nschedule_tz = \"+09:00\"\n", &[]);
+        assert!(ok.is_empty(), "{ok:?}");
+
+        let problems = with("schedule = \"0 25 * * *\"\n", &[("SCHEDULE_TZ", "KST")]);
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(problems[0].starts_with("schedule: invalid cron expression \"0 25 * * *\": hour field: 25 is out of range"), "{problems:?}");
+        assert!(problems[1].starts_with("schedule_tz: invalid UTC offset \"KST\""), "{problems:?}");
+    }
+}
@@ -0,0 +1,26 @@
+// src/csv_write.rs
+use anyhow::Result;
+
+use crate::notice::Notice;
+
+/// 통합 Notice 목록을 CSV로 저장(스프레드시트용, 값이 없으면 빈 문자열)
+pub fn write_csv(notices: &[Notice], output_file: &str) -> Result<()> {
+    let mut w = csv::Writer::from_writer(Vec::new());
+    w.write_record(["source", "kind", "title", "url", "start", "end", "organizer", "field"])?;
+    for n in notices {
+        let source = n.source.name().to_lowercase();
+        let kind = format!("{:?}", n.kind).to_lowercase();
+        w.write_record([
+            source.as_str(),
+            kind.as_str(),
+            n.title.as_str(),
+            n.url.as_str(),
+            n.start.as_deref().unwrap_or(""),
+            n.end.as_deref().unwrap_or(""),
+            n.organizer.as_deref().unwrap_or(""),
+            n.field.as_deref().unwrap_or(""),
+        ])?;
+    }
+    let body = w.into_inner().map_err(|e| anyhow::anyhow!("csv: {e}"))?;
+    crate::atomic_write::write_atomic(output_file, &body)
+}
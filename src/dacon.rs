@@ -1,159 +1,358 @@
-use anyhow::{Context, Result};
-use chrono::{Local, NaiveDate};
-use reqwest::blocking::Client;
-use reqwest::header::{ACCEPT, CONTENT_TYPE};
-use serde::Deserialize;
-use serde_json::Value;
-use std::{thread, time::Duration as StdDuration};
-
-const UA: &str = "dacon-api-filter/2.0 (+you@example.com)";
-const BASE: &str = "https://app.dacon.io/api/v1/competition/list";
-
-// offset은 0부터
-const OFFSET_START: u32 = 0;
-// 마감 20일 이내
-const DEADLINE_DAYS: i64 = 20;
-
-// 키워드
-const KEYWORDS: &[&str] = &[
-    "ai","인공지능","머신러닝","딥러닝",
-    "개발","developer","dev",
-    "보안","security",
-    "sw","소프트웨어","software",
-];
-
-#[derive(Debug, Deserialize, Clone)]
-pub struct Item {
-    #[serde(default)] cpt_id: i64,
-    #[serde(default)] name: String,
-    #[serde(default)] name_eng: String,
-    #[serde(default)] keyword: String,
-    #[serde(default)] keyword_eng: String,
-    #[serde(default)] period_start: String, // "YYYY-MM-DD HH:MM:SS"
-    #[serde(default)] period_end: String,   // "
-}
-
-pub fn collect() -> Result<Vec<Item>> {
-    let client = Client::builder().user_agent(UA).build()?;
-    let mut offset = OFFSET_START;
-    let range = 30u32;
-
-    let mut out: Vec<Item> = Vec::new();
-
-    loop {
-        let url = reqwest::Url::parse_with_params(
-            BASE,
-            &[("offset", offset.to_string()), ("range", range.to_string())],
-        )?;
-
-        let resp = client.get(url.clone()).header(ACCEPT, "application/json").send()?;
-        let status = resp.status();
-        let ctype: String = resp.headers()
-            .get(CONTENT_TYPE)
-            .and_then(|h| h.to_str().ok())
-            .map(str::to_owned)
-            .unwrap_or_default();
-
-        let body = resp.error_for_status()?.text()?;
-
-        if !ctype.to_lowercase().starts_with("application/json") {
-            eprintln!("[warn] non-JSON content-type: {ctype}; status={status}");
-            eprintln!("snippet: {}", &body.chars().take(200).collect::<String>());
-            break;
-        }
-
-        let items = parse_items(&body).with_context(|| format!("JSON parse failed at offset={offset}"))?;
-        if items.is_empty() { break; }
-
-        // 키워드 + 마감일 20일 이내 필터
-        let final_list: Vec<Item> = items
-            .into_iter()
-            .filter(|it| pass_keyword_filter(it) && within_deadline_days(it, DEADLINE_DAYS))
-            .collect();
-
-        out.extend(final_list);
-
-        offset += 1;
-        thread::sleep(StdDuration::from_millis(400));
-        if offset > OFFSET_START + 10 { break; } // 과도 크롤 방지
-    }
-
-    Ok(out)
-}
-
-/// 응답이 배열/객체 래퍼 어떤 형태든 Vec<Item>으로 변환
-fn parse_items(body: &str) -> Result<Vec<Item>> {
-    if let Ok(v) = serde_json::from_str::<Vec<Item>>(body) { return Ok(v); }
-    let val: Value = serde_json::from_str(body)?;
-    for k in ["list","data","content","items","results"] {
-        if let Some(arr) = val.get(k).and_then(|x| x.as_array()) {
-            return Ok(from_value_array(arr));
-        }
-    }
-    if let Some(obj) = val.as_object() {
-        for (_k, v) in obj {
-            if let Some(arr) = v.as_array() {
-                if arr.iter().all(|e| e.is_object()) {
-                    return Ok(from_value_array(arr));
-                }
-            }
-        }
-    }
-    let snippet = body.chars().take(200).collect::<String>();
-    Err(anyhow::anyhow!("unsupported JSON shape; snippet: {}", snippet))
-}
-
-fn from_value_array(arr: &[Value]) -> Vec<Item> {
-    arr.iter().filter_map(|e| serde_json::from_value::<Item>(e.clone()).ok()).collect()
-}
-
-/// 키워드 필터
-fn pass_keyword_filter(it: &Item) -> bool {
-    let hay = normalize(&format!("{} {} {} {}", it.name, it.name_eng, it.keyword, it.keyword_eng));
-    KEYWORDS.iter().any(|kw| hay.contains(&normalize(kw)))
-}
-
-/// 마감일까지 20일 이내면 true
-fn within_deadline_days(it: &Item, n: i64) -> bool {
-    days_until_deadline(&it.period_end).map(|diff| diff >= 0 && diff <= n).unwrap_or(false)
-}
-
-/// D-값(오늘 기준)
-fn days_until_deadline(end_str: &str) -> Option<i64> {
-    let today = Local::now().date_naive();
-    parse_date_ymd(end_str).map(|d| (d - today).num_days())
-}
-
-/// "YYYY-MM-DD HH:MM:SS" → NaiveDate
-fn parse_date_ymd(s: &str) -> Option<NaiveDate> {
-    if s.len() < 10 { return None; }
-    NaiveDate::parse_from_str(&s[..10], "%Y-%m-%d").ok()
-}
-
-/// 소문자화 + 공백 정규화
-fn normalize(s: &str) -> String {
-    s.to_lowercase()
-        .replace('\u{00A0}', " ")
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
-use crate::notice::{Notice, Source, Kind};
-
-pub fn to_notice_from_dacon(it: &Item) -> Notice {
-    // "YYYY-MM-DD HH:MM:SS" -> "YYYY-MM-DD"
-    let start = it.period_start.get(0..10).map(|s| s.to_string());
-    let end   = it.period_end.get(0..10).map(|s| s.to_string());
-
-    Notice {
-        source: Source::Dacon,
-        kind: Kind::Contest, // DACON은 공모전 고정
-        title: it.name.trim().to_string(),
-        url:   format!("https://dacon.io/competitions/official/{}", it.cpt_id),
-        start,
-        end,
-        organizer: None,
-        field: None,
-    }
-}
\ No newline at end of file
+use anyhow::{bail, Context, Result};
+use reqwest::header::ACCEPT;
+use serde::Deserialize;
+use serde_json::Value;
+use std::time::Duration as StdDuration;
+
+use tracing::warn;
+
+use crate::dates;
+use crate::keywords::KeywordMatcher;
+use crate::source::CollectStats;
+
+const BASE: &str = "https://app.dacon.io/api/v1/competition/list";
+
+// offset은 0부터
+const OFFSET_START: u32 = 0;
+// 키워드
+const KEYWORDS: &[&str] = &[
+    "ai","인공지능","머신러닝","딥러닝",
+    "개발","developer","dev",
+    "보안","security",
+    "sw","소프트웨어","software",
+];
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Item {
+    #[serde(default)] cpt_id: i64,
+    #[serde(default)] name: String,
+    #[serde(default)] name_eng: String,
+    #[serde(default)] keyword: String,
+    #[serde(default)] keyword_eng: String,
+    #[serde(default)] period_start: String, // "YYYY-MM-DD HH:MM:SS"
+    #[serde(default)] period_end: String,   // "
+    #[serde(default, alias = "description", alias = "overview")] info: String, // 대회 소개(있으면)
+}
+
+/// DACON 수집 설정(설정 파일 [dacon] 섹션)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DaconOptions {
+    /// 오늘부터 마감까지 남은 일수 상한([deadline_days]에서 결정)
+    #[serde(skip)]
+    pub deadline_days: i64,
+    /// 마감까지 최소 남은 일수(config의 min_deadline_days)
+    #[serde(skip)]
+    pub min_deadline_days: i64,
+    /// 키워드 필터 없이 마감 필터만 적용
+    pub all_keywords: bool,
+    /// 지난 마감도 유지(아카이브 피드용)
+    #[serde(skip)]
+    pub archive: bool,
+    /// 공통 HTTP 설정(config의 [http])
+    #[serde(skip)]
+    pub http: crate::http::HttpOptions,
+    /// 요청마다 덮어쓸 헤더([dacon.headers], 같은 이름의 기본 헤더를 대체)
+    pub headers: std::collections::HashMap<String, String>,
+    /// 대회명/키워드 필터
+    pub keywords: Vec<String>,
+    /// 목록 요청 시도 횟수(네트워크 오류/5xx만 재시도)
+    pub retries: u32,
+    /// 첫 재시도 대기(ms), 이후 2배씩
+    pub backoff_ms: u64,
+    /// 대회 목록 API(테스트/미러용으로 바꿀 수 있음)
+    pub list_api: String,
+    /// 호스트당 초당 최대 요청 수(0이면 제한 없음)
+    pub max_rps: f64,
+}
+
+impl Default for DaconOptions {
+    fn default() -> Self {
+        Self {
+            deadline_days: 20,
+            min_deadline_days: 0,
+            all_keywords: false,
+            archive: false,
+            http: crate::http::HttpOptions::default(),
+            headers: std::collections::HashMap::new(),
+            keywords: KEYWORDS.iter().map(|k| k.to_string()).collect(),
+            retries: 3,
+            backoff_ms: 500,
+            list_api: BASE.into(),
+            max_rps: 2.0,
+        }
+    }
+}
+
+pub async fn collect(opts: &DaconOptions, stats: &CollectStats) -> Result<Vec<Item>> {
+    let client = crate::http::client_builder(&opts.http)?.user_agent(crate::http::USER_AGENT).build()?;
+    let headers = crate::http::header_map(&opts.headers)?;
+    let mut offset = OFFSET_START;
+    let range = 30u32;
+
+    let matcher = KeywordMatcher::new(&opts.keywords)?;
+    let mut out: Vec<Item> = Vec::new();
+
+    loop {
+        let url = reqwest::Url::parse_with_params(
+            &opts.list_api,
+            &[("offset", offset.to_string()), ("range", range.to_string())],
+        )?;
+
+        let list_started = std::time::Instant::now();
+        let body = fetch_with_retry(&client, url.as_str(), &headers, opts, stats).await?;
+        stats.add_list_time(list_started.elapsed());
+
+        // 점검 페이지 등 JSON이 아닌 응답이면 지금까지 모은 것만 사용
+        if !body.trim_start().starts_with(['{', '[']) {
+            warn!(offset, snippet = %body.chars().take(200).collect::<String>(), "non-JSON response");
+            break;
+        }
+
+        let parse_started = std::time::Instant::now();
+        let items = parse_items(&body).with_context(|| format!("JSON parse failed at offset={offset}"))?;
+        if items.is_empty() { break; }
+        stats.add_raw(items.len());
+
+        // 키워드(all_keywords면 생략) + 마감일 deadline_days일 이내 필터
+        let final_list: Vec<Item> = items
+            .into_iter()
+            .filter(|it| (opts.all_keywords || pass_keyword_filter(&matcher, it)) && within_deadline_days(it, opts))
+            .collect();
+        stats.add_parse_time(parse_started.elapsed());
+
+        out.extend(final_list);
+
+        offset += 1;
+        stats.pace(StdDuration::from_millis(400)).await;
+        if offset > OFFSET_START + 10 { break; } // 과도 크롤 방지
+    }
+
+    Ok(out)
+}
+
+/// 응답이 배열/객체 래퍼 어떤 형태든 Vec<Item>으로 변환
+pub fn parse_items(body: &str) -> Result<Vec<Item>> {
+    if let Ok(v) = serde_json::from_str::<Vec<Item>>(body) { return Ok(v); }
+    let val: Value = serde_json::from_str(body)?;
+    for k in ["list","data","content","items","results"] {
+        if let Some(arr) = val.get(k).and_then(|x| x.as_array()) {
+            return Ok(from_value_array(arr));
+        }
+    }
+    if let Some(obj) = val.as_object() {
+        for (_k, v) in obj {
+            if let Some(arr) = v.as_array()
+                && arr.iter().all(|e| e.is_object()) {
+                return Ok(from_value_array(arr));
+            }
+        }
+    }
+    let snippet = body.chars().take(200).collect::<String>();
+    Err(anyhow::anyhow!("unsupported JSON shape; snippet: {}", snippet))
+}
+
+fn from_value_array(arr: &[Value]) -> Vec<Item> {
+    arr.iter().filter_map(|e| serde_json::from_value::<Item>(e.clone()).ok()).collect()
+}
+
+/// 목록 요청(네트워크 오류/5xx는 http::send_with_retry로 재시도, 4xx는 즉시 실패)
+async fn fetch_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &reqwest::header::HeaderMap,
+    opts: &DaconOptions,
+    stats: &CollectStats,
+) -> Result<String> {
+    let req = crate::http::with_headers(client.get(url).header(ACCEPT, "application/json"), headers);
+    let resp = crate::http::send_with_retry(req, opts.retries, StdDuration::from_millis(opts.backoff_ms), opts.max_rps, stats)
+        .await
+        .context("list request")?;
+    let status = resp.status();
+    if !status.is_success() {
+        bail!("HTTP {status} for {url}");
+    }
+    Ok(crate::http::read_text(resp, stats).await?)
+}
+
+/// 키워드 필터
+fn pass_keyword_filter(matcher: &KeywordMatcher, it: &Item) -> bool {
+    let hay = normalize(&format!("{} {} {} {}", it.name, it.name_eng, it.keyword, it.keyword_eng));
+    matcher.is_match(&hay)
+}
+
+/// 마감일까지 min_deadline_days~deadline_days일이면 true(archive면 지난 마감도 true)
+fn within_deadline_days(it: &Item, opts: &DaconOptions) -> bool {
+    dates::days_until(&it.period_end, crate::clock::today())
+        .map(|diff| dates::within_deadline(diff, opts.min_deadline_days, opts.deadline_days, opts.archive))
+        .unwrap_or(false)
+}
+
+/// "YYYY-MM-DD HH:MM:SS" / "YYYY-MM-DDTHH:MM:SS" / "YYYY-MM-DD" / "YYYY.MM.DD" -> "YYYY-MM-DD"(해석 불가면 None)
+fn parse_date_ymd(s: &str) -> Option<String> {
+    dates::parse_ymd(s).map(|d| d.format("%Y-%m-%d").to_string())
+}
+
+/// 소문자화 + 공백 정규화
+fn normalize(s: &str) -> String {
+    s.to_lowercase()
+        .replace('\u{00A0}', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+use crate::notice::{Notice, Source, Kind, clean_title, decode_entities, make_summary};
+
+pub fn to_notice_from_dacon(it: &Item) -> Notice {
+    let start = parse_date_ymd(&it.period_start);
+    let end   = parse_date_ymd(&it.period_end);
+
+    Notice {
+        source: Source::Dacon,
+        kind: Kind::Contest, // DACON은 공모전 고정
+        title: clean_title(&decode_entities(&it.name), &Source::Dacon),
+        raw_title: it.name.clone(),
+        url:   format!("https://dacon.io/competitions/official/{}", it.cpt_id),
+        start,
+        end,
+        organizer: None,
+        // 대회 키워드("정형, 분류")를 분야로
+        field: Some(it.keyword.trim()).filter(|k| !k.is_empty()).map(str::to_string),
+        end_approx: false,
+        // 소개문은 HTML일 수 있어 텍스트만
+        summary: make_summary(&scraper::Html::parse_fragment(&it.info).root_element().text().collect::<Vec<_>>().join(" ")),
+        series_id: None,
+        changed_at: None,
+    }
+}
+
+// === Crawler 구현 ===
+pub struct DaconCrawler {
+    pub opts: DaconOptions,
+    pub timeout: StdDuration,
+}
+
+#[async_trait::async_trait(?Send)]
+impl crate::source::Crawler for DaconCrawler {
+    fn name(&self) -> &str { "dacon" }
+    fn site_url(&self) -> &str { "https://www.dacon.io" }
+    fn timeout(&self) -> StdDuration { self.timeout }
+
+    async fn collect(&self, stats: &CollectStats) -> Result<Vec<Notice>> {
+        let rows = collect(&self.opts, stats).await?;
+        Ok(rows.iter().map(to_notice_from_dacon).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockServer, Reply};
+    use chrono::NaiveDate;
+
+    #[tokio::test]
+    async fn collects_list_fixture_with_keyword_and_deadline_filters() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let server = MockServer::start(vec![(
+            "/api/v1/competition/list",
+            vec![Reply::json(include_str!("../tests/fixtures/dacon_list.json")), Reply::json("[]")],
+        )])
+        .await;
+        let opts = DaconOptions { list_api: server.url("/api/v1/competition/list"), ..DaconOptions::default() };
+
+        let stats = CollectStats::default();
+        let items = collect(&opts, &stats).await.unwrap();
+        // 236402는 키워드 불일치, 236403은 마감 지남
+        assert_eq!(items.iter().map(|it| it.cpt_id).collect::<Vec<_>>(), vec![236401]);
+        assert_eq!(stats.http_requests(), 2);
+        let fixture_len = include_str!("../tests/fixtures/dacon_list.json").len() as u64;
+        assert_eq!(stats.bytes_fetched(), fixture_len + 2);
+        let first = &server.requests_to("/api/v1/competition/list")[0];
+        assert_eq!(first.target, "/api/v1/competition/list?offset=0&range=30");
+
+        let n = to_notice_from_dacon(&items[0]);
+        assert_eq!(n.title, "2026 전력 수요 예측 AI 경진대회");
+        assert_eq!(n.url, "https://dacon.io/competitions/official/236401");
+        assert_eq!(n.start.as_deref(), Some("2026-09-29"));
+        assert_eq!(n.end.as_deref(), Some("2026-10-27"));
+        assert_eq!(n.field.as_deref(), Some("정형, 시계열, 회귀"));
+        assert_eq!(n.summary.as_deref(), Some("전력 수요를 예측하는 AI 모델 을 개발합니다."));
+    }
+
+    #[tokio::test]
+    async fn all_keywords_keeps_non_keyword_competitions_but_still_filters_deadline() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let fixture = include_str!("../tests/fixtures/dacon_list.json");
+        let server = MockServer::start(vec![
+            ("/api/v1/competition/list?offset=0&range=30", vec![Reply::json(fixture)]),
+            ("/api/v1/competition/list", vec![Reply::json("[]")]),
+        ])
+        .await;
+        let url = server.url("/api/v1/competition/list");
+
+        let filtered = DaconOptions { list_api: url.clone(), ..DaconOptions::default() };
+        let ids = |items: Vec<Item>| items.iter().map(|it| it.cpt_id).collect::<Vec<_>>();
+        assert_eq!(ids(collect(&filtered, &CollectStats::default()).await.unwrap()), vec![236401]);
+
+        let all = DaconOptions { list_api: url, all_keywords: true, ..DaconOptions::default() };
+        // 236402(키워드 불일치)는 포함, 236403(마감 지남)은 여전히 제외
+        assert_eq!(ids(collect(&all, &CollectStats::default()).await.unwrap()), vec![236401, 236402]);
+        crate::clock::set_today(None);
+    }
+
+    #[tokio::test]
+    async fn configured_headers_are_sent_and_override_defaults() {
+        let server = MockServer::start(vec![("/list", vec![Reply::json("[]")])]).await;
+        let opts = DaconOptions {
+            list_api: server.url("/list"),
+            headers: [("X-Requested-With", "XMLHttpRequest"), ("Accept", "application/vnd.dacon+json")]
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .into(),
+            ..DaconOptions::default()
+        };
+        collect(&opts, &CollectStats::default()).await.unwrap();
+        let req = &server.requests_to("/list")[0];
+        assert_eq!(req.headers.get("x-requested-with").map(String::as_str), Some("XMLHttpRequest"));
+        assert_eq!(req.headers.get("accept").map(String::as_str), Some("application/vnd.dacon+json"));
+    }
+
+    #[test]
+    fn parses_wrapped_list_shapes() {
+        let body = r#"{"data": [{"cpt_id": 1, "name": "A"}], "total": 1}"#;
+        assert_eq!(parse_items(body).unwrap()[0].cpt_id, 1);
+        assert!(parse_items(r#"{"msg": "maintenance"}"#).is_err());
+    }
+
+    #[test]
+    fn period_dates_accept_dacon_formats() {
+        for raw in ["2026-10-24 23:59:59", "2026-10-24T09:00:00", "2026-10-24", "2026.10.24", "2026.10.24 10:00"] {
+            assert_eq!(parse_date_ymd(raw).as_deref(), Some("2026-10-24"), "{raw}");
+        }
+        for bad in ["", "2026-10", "24/10/2026", "2026-02-30 00:00:00", "미정"] {
+            assert_eq!(parse_date_ymd(bad), None, "{bad}");
+        }
+    }
+
+    #[tokio::test]
+    async fn list_page_is_retried_on_5xx_but_not_on_4xx() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let fixture = include_str!("../tests/fixtures/dacon_list.json");
+        let server = MockServer::start(vec![
+            ("/flaky?offset=0&range=30", vec![Reply::status(502), Reply::status(503), Reply::json(fixture)]),
+            ("/flaky", vec![Reply::json("[]")]),
+            ("/gone", vec![Reply::status(404)]),
+        ])
+        .await;
+        let opts = |path: &str| DaconOptions { list_api: server.url(path), retries: 3, backoff_ms: 1, ..DaconOptions::default() };
+
+        let items = collect(&opts("/flaky"), &CollectStats::default()).await.unwrap();
+        crate::clock::set_today(None);
+        assert_eq!(items.iter().map(|it| it.cpt_id).collect::<Vec<_>>(), vec![236401]);
+        let first_page = server.requests_to("/flaky").into_iter().filter(|r| r.target.contains("offset=0")).count();
+        assert_eq!(first_page, 3);
+
+        assert!(collect(&opts("/gone"), &CollectStats::default()).await.is_err());
+        assert_eq!(server.requests_to("/gone").len(), 1);
+    }
+}
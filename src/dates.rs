@@ -0,0 +1,259 @@
+// src/dates.rs
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// "이번 주 금요일", "다음주까지"
+static RE_REL_WEEK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?P<w>이번|다음)\s*주\s*(?:(?P<d>[월화수목금토일])요일|까지)").unwrap());
+/// "오늘 마감", "내일까지"
+static RE_REL_DAY: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?P<d>오늘|내일|모레)\s*(?:마감|까지)").unwrap());
+/// "D-7", "D-day". 경계는 ASCII 기준(?-u:\b): 유니코드 \b로는 "마감D-7"(감/D), "D-7일"(7/일) 사이에 경계가 없음
+static RE_REL_DDAY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(?-u:\b)D\s*-\s*(?:(?P<n>\d{1,3})|day)(?-u:\b)").unwrap());
+
+/// 상대 표현 마감일("이번 주 금요일 마감", "다음 주까지")을 today 기준 절대 날짜로 변환
+/// 상세 파싱의 마지막 수단이라 오탐을 줄이려고 아래 표현만 처리한다(주는 월요일 시작).
+/// - `이번 주|이번주 <요일>` / `다음 주|다음주 <요일>` : 해당 주의 그 요일
+/// - `이번 주까지` / `다음 주까지`                     : 해당 주 일요일
+/// - `오늘|내일|모레` + `마감|까지`                     : today + 0/1/2일
+/// - `D-7` / `D-day` (대소문자 무관)                   : today + n일 / today
+pub fn resolve_relative_kr(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    if let Some(caps) = RE_REL_WEEK.captures(text) {
+        let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let week_start = if &caps["w"] == "다음" { monday + Duration::days(7) } else { monday };
+        let weekday = match caps.name("d").map(|m| m.as_str()) {
+            Some(d) => weekday_kr(d)?,
+            None => Weekday::Sun,
+        };
+        return Some(week_start + Duration::days(weekday.num_days_from_monday() as i64));
+    }
+
+    if let Some(caps) = RE_REL_DAY.captures(text) {
+        let offset = match &caps["d"] {
+            "오늘" => 0,
+            "내일" => 1,
+            _ => 2,
+        };
+        return Some(today + Duration::days(offset));
+    }
+
+    if let Some(caps) = RE_REL_DDAY.captures(text) {
+        let n: i64 = caps.name("n").map_or(Some(0), |m| m.as_str().parse().ok())?;
+        return Some(today + Duration::days(n));
+    }
+
+    None
+}
+
+fn weekday_kr(d: &str) -> Option<Weekday> {
+    Some(match d {
+        "월" => Weekday::Mon,
+        "화" => Weekday::Tue,
+        "수" => Weekday::Wed,
+        "목" => Weekday::Thu,
+        "금" => Weekday::Fri,
+        "토" => Weekday::Sat,
+        "일" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+/// 남은 일수(D-값)가 [min_days, max_days] 안이면 true
+/// archive면 지난 마감(days < 0)도 true. min_days=0이면 오늘 마감(days == 0)도 포함
+pub fn within_deadline(days: i64, min_days: i64, max_days: i64, archive: bool) -> bool {
+    days <= max_days && (days >= min_days || (archive && days < 0))
+}
+
+/// date가 [start, end] 안이면 true(양끝 포함, None인 쪽은 열린 구간)
+pub fn within_window(date: NaiveDate, start: Option<NaiveDate>, end: Option<NaiveDate>) -> bool {
+    start.is_none_or(|s| date >= s) && end.is_none_or(|e| date <= e)
+}
+
+/// 숫자 날짜("2024.5.3", "2024-05-03 12:00:00", "2024/05/03")
+static RE_YMD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(\d{4})\s*[-./]\s*(\d{1,2})\s*[-./]\s*(\d{1,2})").unwrap()
+});
+
+/// 문자열 안의 첫 숫자 날짜를 NaiveDate로(뒤의 시각 등은 무시)
+pub fn parse_ymd(s: &str) -> Option<NaiveDate> {
+    let caps = RE_YMD.captures(s)?;
+    NaiveDate::from_ymd_opt(caps[1].parse().ok()?, caps[2].parse().ok()?, caps[3].parse().ok()?)
+}
+
+/// 날짜 문자열을 YYYY-MM-DD로 통일(해석할 수 없으면 구분자만 바꾼 앞 10글자)
+pub fn normalize_date(s: &str) -> String {
+    if let Some(d) = parse_ymd(s) {
+        return d.format("%Y-%m-%d").to_string();
+    }
+    let mut t = s.trim().to_string();
+    t = t.replace(['.', '/'], "-");
+    if t.len() >= 10 { t[..10].to_string() } else { t }
+}
+
+/// today → 마감일까지 남은 일수(D-값). 날짜를 해석할 수 없으면 None
+pub fn days_until(end: &str, today: NaiveDate) -> Option<i64> {
+    parse_ymd(end).map(|d| (d - today).num_days())
+}
+
+/// 한국어/숫자 범위 표기에서 (시작, 마감)을 파싱(YYYY-MM-DD). 못 찾으면 (None, None)
+/// 처리 순서: "YYYY.MM.DD ~ YYYY.MM.DD" → "(연) m월 d일 ~ (연) m월 d일" → 단일 "m월 d일 (마감|까지)" → 상대 표현
+/// 연도가 없으면 end_hint(YYYY-…)의 연도 → 올해. 찾으면 마감은 항상 Some
+pub fn parse_range(text: &str, end_hint: Option<&str>) -> (Option<String>, Option<String>) {
+    parse_range_opt(text, end_hint).unwrap_or((None, None))
+}
+
+/// "2026.10.01 ~ 2026.10.24"
+static RE_RANGE_NUM: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(20\d{2}[-./]\d{1,2}[-./]\d{1,2})\s*[~\-–]\s*(20\d{2}[-./]\d{1,2}[-./]\d{1,2})").unwrap()
+});
+/// "(2026년) 10월 1일(수) ~ (2026년) 10월 24일"
+static RE_RANGE_KR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?:(?P<y1>20\d{2})\s*년\s*)?(?P<m1>\d{1,2})\s*월\s*(?P<d1>\d{1,2})\s*일(?:\([^)]*\))?\s*[~\-–]\s*(?:(?P<y2>20\d{2})\s*년\s*)?(?P<m2>\d{1,2})\s*월\s*(?P<d2>\d{1,2})\s*일"
+    ).unwrap()
+});
+/// 단일 "(2026년) 10월 24일 (마감|까지)"
+static RE_SINGLE_KR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:(?P<y>20\d{2})\s*년\s*)?(?P<m>\d{1,2})\s*월\s*(?P<d>\d{1,2})\s*일\s*(?:마감|까지|접수마감)?").unwrap()
+});
+
+fn parse_range_opt(text: &str, end_hint: Option<&str>) -> Option<(Option<String>, Option<String>)> {
+    // 숫자 yyyy-mm-dd ~ yyyy-mm-dd
+    if let Some(caps) = RE_RANGE_NUM.captures(text) {
+        let s = normalize_date(caps.get(1).unwrap().as_str());
+        let e = normalize_date(caps.get(2).unwrap().as_str());
+        return Some((Some(s), Some(e)));
+    }
+
+    // 한국어 "(연) m월 d일 ~ (연) m월 d일"
+    if let Some(caps) = RE_RANGE_KR.captures(text) {
+        let y2 = caps.name("y2").and_then(|m| m.as_str().parse::<i32>().ok())
+            .or_else(|| end_hint.and_then(|e| e.get(0..4)).and_then(|y| y.parse().ok()))
+            .unwrap_or_else(|| crate::clock::today().year());
+        let m2: u32 = caps.name("m2").unwrap().as_str().parse().unwrap_or(1);
+        let d2: u32 = caps.name("d2").unwrap().as_str().parse().unwrap_or(1);
+
+        let mut y1 = caps.name("y1").and_then(|m| m.as_str().parse::<i32>().ok()).unwrap_or(y2);
+        let m1: u32 = caps.name("m1").unwrap().as_str().parse().unwrap_or(1);
+        let d1: u32 = caps.name("d1").unwrap().as_str().parse().unwrap_or(1);
+
+        if caps.name("y1").is_none() && caps.name("y2").is_none() && m1 > m2 { y1 = y2 - 1; }
+
+        let s = format!("{:04}-{:02}-{:02}", y1, m1, d1);
+        let e = format!("{:04}-{:02}-{:02}", y2, m2, d2);
+        return Some((Some(s), Some(e)));
+    }
+
+    if let Some(caps) = RE_SINGLE_KR.captures(text) {
+        let y = caps.name("y").and_then(|m| m.as_str().parse::<i32>().ok())
+            .or_else(|| end_hint.and_then(|e| e.get(0..4)).and_then(|y| y.parse().ok()))
+            .unwrap_or_else(|| crate::clock::today().year());
+        let m: u32 = caps.name("m").unwrap().as_str().parse().unwrap_or(1);
+        let d: u32 = caps.name("d").unwrap().as_str().parse().unwrap_or(1);
+        let e = format!("{:04}-{:02}-{:02}", y, m, d);
+        return Some((None, Some(e)));
+    }
+
+    // 마지막 수단: "이번 주 금요일 마감" 같은 상대 표현
+    if let Some(e) = resolve_relative_kr(text, crate::clock::today()) {
+        return Some((None, Some(e.format("%Y-%m-%d").to_string())));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2026-10-14(수)
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 14).unwrap()
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(y, m, d)
+    }
+
+    #[test]
+    fn dday_badges_resolve_from_today() {
+        assert_eq!(resolve_relative_kr("마감 D-7", today()), ymd(2026, 10, 21));
+        assert_eq!(resolve_relative_kr("마감D-7", today()), ymd(2026, 10, 21));
+        assert_eq!(resolve_relative_kr("D-3일 남음", today()), ymd(2026, 10, 17));
+        assert_eq!(resolve_relative_kr("d - 12", today()), ymd(2026, 10, 26));
+        assert_eq!(resolve_relative_kr("(D-day)", today()), ymd(2026, 10, 14));
+        // 단어 안의 "D-"는 무시
+        assert_eq!(resolve_relative_kr("ID-3 배지", today()), None);
+    }
+
+    #[test]
+    fn today_and_tomorrow_deadlines() {
+        assert_eq!(resolve_relative_kr("오늘 마감", today()), ymd(2026, 10, 14));
+        assert_eq!(resolve_relative_kr("내일 마감!", today()), ymd(2026, 10, 15));
+        assert_eq!(resolve_relative_kr("모레까지 접수", today()), ymd(2026, 10, 16));
+        assert_eq!(resolve_relative_kr("오늘의 추천 공모전", today()), None);
+    }
+
+    #[test]
+    fn relative_deadline_sets_only_end_in_parse_range() {
+        crate::clock::set_today(Some(today()));
+        assert_eq!(parse_range("접수 마감D-7", None), (None, Some("2026-10-21".to_string())));
+        assert_eq!(parse_range("오늘 마감", None), (None, Some("2026-10-14".to_string())));
+        crate::clock::set_today(None);
+    }
+
+    #[test]
+    fn this_and_next_week_weekdays() {
+        // 오늘(수) 기준 이번 주 월~일 = 10-12 ~ 10-18
+        assert_eq!(resolve_relative_kr("이번 주 금요일 마감", today()), ymd(2026, 10, 16));
+        assert_eq!(resolve_relative_kr("이번주 월요일까지였던", today()), ymd(2026, 10, 12));
+        assert_eq!(resolve_relative_kr("다음 주 월요일 마감", today()), ymd(2026, 10, 19));
+        assert_eq!(resolve_relative_kr("다음주까지 접수", today()), ymd(2026, 10, 25));
+        assert_eq!(resolve_relative_kr("이번 주까지", today()), ymd(2026, 10, 18));
+    }
+
+    #[test]
+    fn numeric_and_korean_ranges() {
+        let some = |s: &str| Some(s.to_string());
+        assert_eq!(parse_range("접수기간 2026.10.01 ~ 2026.10.24", None), (some("2026-10-01"), some("2026-10-24")));
+        assert_eq!(parse_range("2026-9-5 – 2026-9-30", None), (some("2026-09-05"), some("2026-09-30")));
+        assert_eq!(
+            parse_range("2026년 10월 1일(수) ~ 2026년 10월 24일(토)", None),
+            (some("2026-10-01"), some("2026-10-24"))
+        );
+        // 연도 없는 범위는 end_hint의 연도, 시작 월이 더 크면 전년도 시작
+        assert_eq!(parse_range("12월 20일 ~ 1월 10일", Some("2027-01-10")), (some("2026-12-20"), some("2027-01-10")));
+        assert_eq!(parse_range("11월 3일 까지", Some("2026-11-03")), (None, some("2026-11-03")));
+        assert_eq!(parse_range("상시 모집", None), (None, None));
+    }
+
+    #[test]
+    fn ranges_without_year_use_the_clock() {
+        crate::clock::set_today(Some(today()));
+        assert_eq!(parse_range("10월 1일 ~ 10월 24일", None), (Some("2026-10-01".into()), Some("2026-10-24".into())));
+        assert_eq!(parse_range("이번 주 금요일 마감", None), (None, Some("2026-10-16".into())));
+        crate::clock::set_today(None);
+    }
+
+    #[test]
+    fn cross_source_date_formats_agree() {
+        // 같은 마감일을 소스마다 다르게 표기: dacon API, campuspick JSON, wevity 상세, 본문 문구
+        for raw in ["2026-10-24 23:59:59", "2026-10-24T00:00:00", "2026.10.24", "2026/10/24", "2026.10.24(토) 18:00"] {
+            assert_eq!(normalize_date(raw), "2026-10-24", "{raw}");
+            assert_eq!(days_until(raw, today()), Some(10), "{raw}");
+        }
+        let expected = (Some("2026-10-01".to_string()), Some("2026-10-24".to_string()));
+        for text in [
+            "2026.10.01 ~ 2026.10.24",
+            "접수기간 : 2026-10-01 - 2026-10-24",
+            "모집 기간 10월 1일(수) ~ 10월 24일(토) 18시까지",
+            "2026년 10월 1일 ~ 2026년 10월 24일",
+        ] {
+            assert_eq!(parse_range(text, Some("2026-10-24")), expected, "{text}");
+        }
+        // 해석할 수 없는 값
+        assert_eq!(days_until("미정", today()), None);
+        assert_eq!(parse_ymd("2026.13.40"), None);
+    }
+}
@@ -0,0 +1,186 @@
+// src/feed_state.rs
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::collections::HashSet;
+use tracing::{info, warn};
+
+use crate::atomic_write::write_atomic;
+use crate::notice::{self, Notice};
+
+/// 유예용 상태 파일 경로: 피드 옆 "<피드 파일>.state.json"(마지막으로 쓴 Notice 목록)
+/// 렌더링된 XML을 다시 파싱하지 않고 마감일/제목 원본을 그대로 보관
+pub fn state_path(output_file: &str) -> String {
+    format!("{output_file}.state.json")
+}
+
+/// 일시적으로 수집에 실패한 항목이 피드에서 사라졌다 다시 나타나지 않도록
+/// 지난번에 쓴 항목 중 새 목록에 없는 것을 유예 기간 동안 이어 붙인다.
+/// - 같은 항목 판정: stable_guid 또는 정규화 URL
+/// - 기준일: 마감일(없으면 시작일)이 today - grace_days 이후면 유지, 날짜가 없으면 버림
+/// - 상태 파일이 없거나 깨졌으면 새 목록 그대로
+/// - 새 항목 → 유지 항목 순서로 합친 뒤 cap(기준일이 늦은 쪽부터 max_items개)
+/// - 돌려준 Notice로 Item을 다시 만들므로 제목/D-day는 오늘 기준으로 새로 계산됨
+pub fn retain_previous(
+    output_file: &str,
+    mut notices: Vec<Notice>,
+    grace_days: i64,
+    max_items: usize,
+    today: NaiveDate,
+    strip: &[String],
+) -> Vec<Notice> {
+    let previous = load(output_file);
+
+    let cutoff = today - chrono::Duration::days(grace_days);
+    let guids: HashSet<String> = notices.iter().map(|n| n.stable_guid(strip)).collect();
+    let urls: HashSet<String> = notices
+        .iter()
+        .filter(|n| !n.url.trim().is_empty())
+        .map(|n| notice::normalize_url(&n.url, strip))
+        .collect();
+    let kept: Vec<Notice> = previous
+        .into_iter()
+        .filter(|n| !guids.contains(&n.stable_guid(strip)))
+        .filter(|n| n.url.trim().is_empty() || !urls.contains(&notice::normalize_url(&n.url, strip)))
+        .filter(|n| reference_date(n).is_some_and(|d| d >= cutoff))
+        .collect();
+
+    if !kept.is_empty() {
+        info!(file = output_file, kept = kept.len(), "kept previous items");
+    }
+    notices.extend(kept);
+    cap_by_date(notices, max_items)
+}
+
+/// 이번에 쓴 목록을 상태 파일로 저장(다음 실행의 retain_previous가 읽음)
+pub fn save(output_file: &str, notices: &[Notice]) -> Result<()> {
+    let body = serde_json::to_vec(notices).context("serialize feed state")?;
+    write_atomic(&state_path(output_file), &body)
+}
+
+/// 상태 파일 읽기: 없으면 빈 목록, 깨졌으면 경고 후 빈 목록
+fn load(output_file: &str) -> Vec<Notice> {
+    let path = state_path(output_file);
+    let body = match std::fs::read(&path) {
+        Ok(body) => body,
+        Err(_) => return Vec::new(),
+    };
+    serde_json::from_slice(&body).unwrap_or_else(|e| {
+        warn!(file = %path, error = %e, "ignoring unreadable feed state");
+        Vec::new()
+    })
+}
+
+/// max_items를 넘으면 기준일이 이른 항목부터 버림(날짜 없는 항목은 가장 늦은 것으로 취급)
+/// 남은 항목의 순서는 그대로
+fn cap_by_date(notices: Vec<Notice>, max_items: usize) -> Vec<Notice> {
+    if notices.len() <= max_items {
+        return notices;
+    }
+    let mut order: Vec<usize> = (0..notices.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(reference_date(&notices[i]).unwrap_or(NaiveDate::MAX)));
+    let keep: HashSet<usize> = order.into_iter().take(max_items).collect();
+    notices
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| keep.contains(i))
+        .map(|(_, n)| n)
+        .collect()
+}
+
+/// 마감일 → 시작일
+fn reference_date(n: &Notice) -> Option<NaiveDate> {
+    n.end
+        .as_deref()
+        .or(n.start.as_deref())
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::{Kind, Source};
+
+    fn notice(id: u32, end: &str) -> Notice {
+        Notice {
+            source: Source::Dacon,
+            kind: Kind::Contest,
+            title: format!("대회 {id}"),
+            raw_title: String::new(),
+            url: format!("https://dacon.io/competitions/official/{id}/overview"),
+            start: None,
+            end: Some(end.into()),
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    fn titles(list: &[Notice]) -> Vec<&str> {
+        list.iter().map(|n| n.title.as_str()).collect()
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 14).unwrap()
+    }
+
+    #[test]
+    fn fresh_file_returns_new_items_unchanged() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("dacon_rss.xml").display().to_string();
+        let got = retain_previous(&out, vec![notice(1, "2026-10-20")], 7, 10, today(), &crate::notice::default_strip_params());
+        assert_eq!(titles(&got), ["대회 1"]);
+    }
+
+    #[test]
+    fn corrupt_state_file_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("dacon_rss.xml").display().to_string();
+        std::fs::write(state_path(&out), "<rss>not json").unwrap();
+        let got = retain_previous(&out, vec![notice(1, "2026-10-20")], 7, 10, today(), &crate::notice::default_strip_params());
+        assert_eq!(titles(&got), ["대회 1"]);
+    }
+
+    #[test]
+    fn keeps_missing_items_within_grace_window() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("dacon_rss.xml").display().to_string();
+        // 2: 아직 진행 중, 3: 마감 후 3일(유예 안), 4: 마감 후 30일(유예 밖), 1: 새 목록에도 있음
+        let previous = [notice(1, "2026-10-20"), notice(2, "2026-10-30"), notice(3, "2026-10-11"), notice(4, "2026-09-14")];
+        save(&out, &previous).unwrap();
+        let got = retain_previous(&out, vec![notice(1, "2026-10-20")], 7, 10, today(), &crate::notice::default_strip_params());
+        assert_eq!(titles(&got), ["대회 1", "대회 2", "대회 3"]);
+    }
+
+    #[test]
+    fn cap_applies_to_combined_set_by_date() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("dacon_rss.xml").display().to_string();
+        save(&out, &[notice(9, "2026-11-30")]).unwrap();
+        let new = vec![notice(1, "2026-10-15"), notice(2, "2026-10-25")];
+        // 유지 항목(11-30)이 새 항목 중 가장 이른 마감(10-15)보다 남음
+        let got = retain_previous(&out, new, 7, 2, today(), &crate::notice::default_strip_params());
+        assert_eq!(titles(&got), ["대회 2", "대회 9"]);
+    }
+
+    #[test]
+    fn previous_item_with_same_url_is_not_duplicated() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("dacon_rss.xml").display().to_string();
+        // 제목/마감이 바뀌어 guid가 달라도 정규화 URL이 같으면 새 항목만 남음
+        let mut old = notice(1, "2026-10-20");
+        old.url.push_str("?utm_source=rss");
+        let undated = Notice { end: None, ..notice(2, "") };
+        save(&out, &[old, undated]).unwrap();
+        let new = Notice { title: "대회 1 (연장)".into(), ..notice(1, "2026-10-27") };
+        let got = retain_previous(&out, vec![new], 7, 10, today(), &crate::notice::default_strip_params());
+        // 날짜 없는 이전 항목은 유예 기준이 없어 버림
+        assert_eq!(titles(&got), ["대회 1 (연장)"]);
+
+        // 다음 실행에서 새 목록이 비면 저장된 항목이 그대로 유지
+        save(&out, &got).unwrap();
+        assert_eq!(titles(&retain_previous(&out, Vec::new(), 7, 10, today(), &crate::notice::default_strip_params())), ["대회 1 (연장)"]);
+    }
+}
@@ -0,0 +1,102 @@
+// src/history.rs
+use anyhow::{Context, Result};
+use chrono::{Duration, NaiveDate};
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::fs;
+use std::path::Path;
+use tracing::warn;
+
+/// 이번 실행의 출력 파일들(Config::output_files)을 archive_dir/YYYY-MM-DD/ 아래로 복사
+/// 같은 날 다시 실행하면 그날 사본을 덮어씀. 복사한 파일 수를 반환
+/// - 출력 디렉터리 밖으로 돌린 파일도 포함, 이번에 쓰지 않아 없는 파일은 건너뜀
+/// - 사본 이름은 파일명. 다른 디렉터리의 같은 이름은 먼저 나온 것만 복사하고 경고
+pub fn snapshot(files: &[String], archive_dir: &str, today: NaiveDate) -> Result<usize> {
+    let dest = Path::new(archive_dir).join(today.format("%Y-%m-%d").to_string());
+    fs::create_dir_all(&dest).with_context(|| format!("create {}", dest.display()))?;
+
+    let mut names: HashSet<OsString> = HashSet::new();
+    let mut copied = 0usize;
+    for file in files {
+        let path = Path::new(file);
+        if !path.is_file() {
+            continue;
+        }
+        let Some(name) = path.file_name() else { continue };
+        if !names.insert(name.to_os_string()) {
+            warn!(file = %file, "skip archive copy: another output has the same file name");
+            continue;
+        }
+        fs::copy(path, dest.join(name)).with_context(|| format!("copy {file}"))?;
+        copied += 1;
+    }
+    Ok(copied)
+}
+
+/// archive_dir 아래 YYYY-MM-DD 디렉터리 중 today - keep_days보다 오래된 것을 삭제
+/// 이름이 날짜 형식이 아닌 항목은 건드리지 않음. 삭제한 디렉터리 수를 반환
+pub fn prune(archive_dir: &str, keep_days: i64, today: NaiveDate) -> Result<usize> {
+    let cutoff = today - Duration::days(keep_days);
+    let mut removed = 0usize;
+    for entry in fs::read_dir(archive_dir).with_context(|| format!("read {archive_dir}"))? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(date) = name.to_str().and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()) else {
+            continue;
+        };
+        if date < cutoff && entry.file_type()?.is_dir() {
+            fs::remove_dir_all(entry.path()).with_context(|| format!("remove {}", entry.path().display()))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 14).unwrap()
+    }
+
+    #[test]
+    fn snapshot_copies_outputs_outside_the_output_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let out = root.path().join("etc-rss");
+        let elsewhere = root.path().join("site");
+        fs::create_dir_all(&out).unwrap();
+        fs::create_dir_all(&elsewhere).unwrap();
+        fs::write(out.join("dacon_rss.xml"), "rss").unwrap();
+        fs::write(out.join("unrelated.txt"), "x").unwrap();
+        fs::write(elsewhere.join("merged_rss.xml"), "merged").unwrap();
+        fs::write(elsewhere.join("dacon_rss.xml"), "dup").unwrap();
+
+        let p = |d: &Path, f: &str| d.join(f).display().to_string();
+        let files = [
+            p(&out, "dacon_rss.xml"),
+            p(&elsewhere, "merged_rss.xml"),
+            p(&out, "missing.json"),
+            p(&elsewhere, "dacon_rss.xml"),
+        ];
+        let archive = root.path().join("archive").display().to_string();
+        assert_eq!(snapshot(&files, &archive, today()).unwrap(), 2);
+
+        let day = Path::new(&archive).join("2026-10-14");
+        assert_eq!(fs::read_to_string(day.join("merged_rss.xml")).unwrap(), "merged");
+        assert_eq!(fs::read_to_string(day.join("dacon_rss.xml")).unwrap(), "rss");
+        assert!(!day.join("unrelated.txt").exists());
+    }
+
+    #[test]
+    fn prune_removes_only_old_date_dirs() {
+        let root = tempfile::tempdir().unwrap();
+        for name in ["2026-09-01", "2026-10-10", "notes"] {
+            fs::create_dir_all(root.path().join(name)).unwrap();
+        }
+        let dir = root.path().display().to_string();
+        assert_eq!(prune(&dir, 7, today()).unwrap(), 1);
+        assert!(!root.path().join("2026-09-01").exists());
+        assert!(root.path().join("2026-10-10").exists() && root.path().join("notes").exists());
+    }
+}
@@ -0,0 +1,197 @@
+// src/html_write.rs
+use anyhow::Result;
+use chrono::Local;
+
+use crate::atomic_write::write_atomic;
+use crate::dates;
+use crate::labels::{self, Lang};
+use crate::notice::{Kind, Notice, is_expired};
+
+/// 페이지 틀: {title} {generated} {rows}와 라벨({lang} {l_*}, labels::table_labels) 치환
+const TEMPLATE: &str = r##"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<style>
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #222; }
+table { border-collapse: collapse; width: 100%; }
+th, td { padding: .4rem .6rem; border-bottom: 1px solid #ddd; text-align: left; vertical-align: top; }
+th { cursor: pointer; user-select: none; background: #f5f5f5; }
+tr.expired td { color: #aaa; }
+tr.expired a { color: #aaa; }
+.badge { display: inline-block; padding: 0 .4rem; border-radius: .3rem; font-size: .8rem; background: #eef; white-space: nowrap; }
+.kind-activity { background: #efe; }
+.kind-education { background: #ffe; }
+.kind-recruit { background: #fee; }
+.meta { color: #777; font-size: .9rem; }
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<p class="meta">{l_generated}: {generated}</p>
+<table id="notices">
+<thead><tr><th>D-day</th><th>{l_title}</th><th>{l_organizer}</th><th>{l_period}</th><th>{l_source}</th><th>{l_kind}</th></tr></thead>
+<tbody>
+{rows}</tbody>
+</table>
+<script>
+// 헤더 클릭 시 data-sort(없으면 텍스트) 기준 정렬, 다시 누르면 역순
+document.querySelectorAll("#notices th").forEach(function (th, col) {
+  var asc = true;
+  th.addEventListener("click", function () {
+    var body = document.querySelector("#notices tbody");
+    var rows = Array.prototype.slice.call(body.rows);
+    var key = function (r) { var c = r.cells[col]; return c.dataset.sort !== undefined ? c.dataset.sort : c.textContent.trim(); };
+    rows.sort(function (a, b) {
+      var x = key(a), y = key(b), nx = parseFloat(x), ny = parseFloat(y);
+      var d = (!isNaN(nx) && !isNaN(ny)) ? nx - ny : x.localeCompare(y);
+      return asc ? d : -d;
+    });
+    asc = !asc;
+    rows.forEach(function (r) { body.appendChild(r); });
+  });
+});
+</script>
+</body>
+</html>
+"##;
+
+/// 통합 목록을 정렬 가능한 표 하나짜리 HTML로 저장(외부 리소스 없음)
+/// - 지난 마감은 지우지 않고 회색(tr.expired)으로
+/// - 제목/주최 등 수집한 텍스트는 모두 이스케이프
+/// - 표 머리글/출처/구분 라벨은 lang(LABEL_LANG)
+pub fn write_html(notices: &[Notice], path: &str, title: &str, lang: Lang) -> Result<()> {
+    let today = crate::clock::today();
+    let mut rows = String::new();
+    for n in notices {
+        let days = n.end.as_deref().and_then(|e| dates::days_until(e, today));
+        let (dday, sort_key) = match days {
+            Some(0) => ("D-Day".to_string(), 0),
+            Some(d) if d > 0 => (format!("D-{d}"), d),
+            Some(d) => (format!("D+{}", -d), d),
+            None => ("-".to_string(), i64::MAX),
+        };
+        let title_cell = if n.url.trim().is_empty() {
+            escape(&n.title)
+        } else {
+            format!(r#"<a href="{}">{}</a>"#, escape(&n.url), escape(&n.title))
+        };
+        let period = format!(
+            "{} ~ {}",
+            n.start.as_deref().unwrap_or("-"),
+            n.end.as_deref().unwrap_or("-"),
+        );
+        let kind_class = match n.kind {
+            Kind::Contest => "kind-contest",
+            Kind::Activity => "kind-activity",
+            Kind::Education => "kind-education",
+            Kind::Recruit => "kind-recruit",
+        };
+
+        rows.push_str(&format!(
+            concat!(
+                "<tr{}>",
+                r#"<td data-sort="{}">{}</td>"#,
+                "<td>{}</td><td>{}</td>",
+                r#"<td data-sort="{}">{}</td>"#,
+                r#"<td><span class="badge">{}</span></td>"#,
+                r#"<td><span class="badge {}">{}</span></td>"#,
+                "</tr>\n",
+            ),
+            if is_expired(n, today) { r#" class="expired""# } else { "" },
+            sort_key, dday,
+            title_cell, escape(n.organizer.as_deref().unwrap_or("-")),
+            // 기간은 마감일 숫자(YYYYMMDD)로 정렬
+            n.end.as_deref().unwrap_or("9999-12-31").replace('-', ""), escape(&period),
+            escape(labels::source_label(&n.source, lang)),
+            kind_class, labels::kind_label(&n.kind, lang),
+        ));
+    }
+
+    let l = labels::table_labels(lang);
+    // 라벨을 먼저 치환(수집한 텍스트가 든 {title}/{rows}에 "{l_…}"가 있어도 건드리지 않도록)
+    let body = TEMPLATE
+        .replace("{lang}", l.html_lang)
+        .replace("{l_generated}", l.generated)
+        .replace("{l_title}", l.title)
+        .replace("{l_organizer}", l.organizer)
+        .replace("{l_period}", l.period)
+        .replace("{l_source}", l.source)
+        .replace("{l_kind}", l.kind)
+        .replace("{title}", &escape(title))
+        .replace("{generated}", &Local::now().format("%Y-%m-%d %H:%M").to_string())
+        .replace("{rows}", &rows);
+    write_atomic(path, body.as_bytes())
+}
+
+/// HTML 텍스트/속성 값 이스케이프
+pub(crate) fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::Source;
+    use chrono::NaiveDate;
+
+    fn notice(end: &str) -> Notice {
+        Notice {
+            source: Source::Dacon,
+            kind: Kind::Contest,
+            title: "<b>AI</b> 대회 {l_title}".into(),
+            raw_title: String::new(),
+            url: "https://dacon.io/competitions/official/1/overview".into(),
+            start: Some("2026-10-01".into()),
+            end: Some(end.into()),
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    fn render(list: &[Notice], lang: Lang) -> String {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("index.html").display().to_string();
+        write_html(list, &path, "통합", lang).unwrap();
+        crate::clock::set_today(None);
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn korean_headers_and_escaped_rows() {
+        let html = render(&[notice("2026-10-20"), notice("2026-10-10")], Lang::Ko);
+        assert!(html.contains(r#"<html lang="ko">"#));
+        assert!(html.contains("<th>제목</th><th>주최</th><th>기간</th><th>출처</th><th>구분</th>"));
+        assert!(html.contains("&lt;b&gt;AI&lt;/b&gt; 대회 {l_title}"));
+        assert!(html.contains(r#"<td data-sort="6">D-6</td>"#));
+        assert_eq!(html.matches(r#"<tr class="expired">"#).count(), 1);
+        assert!(html.contains("데이콘"));
+    }
+
+    #[test]
+    fn english_headers() {
+        let html = render(&[notice("2026-10-14")], Lang::En);
+        assert!(html.contains(r#"<html lang="en">"#));
+        assert!(html.contains("<th>Title</th><th>Organizer</th><th>Period</th><th>Source</th><th>Type</th>"));
+        assert!(html.contains("Generated: "));
+        assert!(html.contains(">D-Day</td>") && html.contains(">DACON<") && html.contains(">Contest<"));
+    }
+}
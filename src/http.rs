@@ -0,0 +1,238 @@
+// src/http.rs
+use anyhow::{anyhow, bail, Context, Result};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, COOKIE};
+use reqwest::{ClientBuilder, NoProxy, Proxy, RequestBuilder, Response};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::warn;
+
+use crate::source::CollectStats;
+
+/// API 소스(dacon/campuspick/linkareer) 공통 요청 UA. robots.txt 그룹도 이 UA 기준
+/// wevity는 봇 검사 때문에 브라우저 UA를 따로 씀
+pub const USER_AGENT: &str =
+    concat!("etc-crawler/", env!("CARGO_PKG_VERSION"), " (+https://github.com/wuisp-rust-dev/contest-crawler)");
+
+/// 호스트당 유휴 커넥션 상한 기본값(wevity 상세 동시성 기본값과 맞춤)
+const DEFAULT_POOL_MAX_IDLE: usize = 4;
+
+/// 모든 소스 공통 HTTP 클라이언트 설정([http], env는 Config::apply_env에서)
+/// - pool_max_idle: POOL_MAX_IDLE
+/// - proxy: HTTPS_PROXY → https_proxy → ALL_PROXY → all_proxy. http://, https://, socks5://, socks5h:// 지원
+/// - no_proxy: NO_PROXY / no_proxy(프록시를 우회할 호스트, 쉼표 구분)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpOptions {
+    pub pool_max_idle: usize,
+    pub proxy: Option<String>,
+    pub no_proxy: Option<String>,
+}
+
+impl Default for HttpOptions {
+    fn default() -> Self {
+        Self { pool_max_idle: DEFAULT_POOL_MAX_IDLE, proxy: None, no_proxy: None }
+    }
+}
+
+impl HttpOptions {
+    /// 프록시 설정(없으면 None, URL이 잘못되면 Err)
+    /// Config::load에서 한 번 검사하므로 잘못된 값은 시작할 때 에러로 끝남
+    pub fn proxy(&self) -> Result<Option<Proxy>> {
+        let Some(url) = self.proxy.as_deref().map(str::trim).filter(|v| !v.is_empty()) else {
+            return Ok(None);
+        };
+        // reqwest는 모르는 스킴도 받아 두고 요청할 때야 실패하므로 먼저 확인
+        let parsed = url::Url::parse(url).with_context(|| format!("invalid proxy URL: {url}"))?;
+        if !matches!(parsed.scheme(), "http" | "https" | "socks5" | "socks5h") || parsed.host_str().is_none() {
+            bail!("invalid proxy URL: {url} (expected http://, https://, socks5:// or socks5h://host:port)");
+        }
+        let proxy = Proxy::all(url).with_context(|| format!("invalid proxy URL: {url}"))?;
+        Ok(Some(proxy.no_proxy(self.no_proxy.as_deref().and_then(NoProxy::from_string))))
+    }
+}
+
+/// 모든 소스가 공통으로 쓰는 async 클라이언트 빌더(커넥션 풀/프록시 적용)
+/// 프록시 URL이 잘못되면 Err(직결로 바꿔 조용히 진행하지 않음)
+pub fn client_builder(opts: &HttpOptions) -> Result<ClientBuilder> {
+    // 시스템 프록시 env를 reqwest가 따로 읽지 않도록 끄고 opts의 값만 씀
+    let builder = reqwest::Client::builder().pool_max_idle_per_host(opts.pool_max_idle).no_proxy();
+    Ok(match opts.proxy()? {
+        Some(proxy) => builder.proxy(proxy),
+        None => builder,
+    })
+}
+
+/// 설정의 소스별 헤더([<source>.headers], 이름 → 값) → HeaderMap. 잘못된 이름/값은 그 이름을 담아 Err
+/// Cookie/Authorization 값은 sensitive로 표시해 Debug 출력에서 가려짐
+pub fn header_map(headers: &HashMap<String, String>) -> Result<HeaderMap> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let name = HeaderName::from_bytes(name.trim().as_bytes()).with_context(|| format!("invalid header name {name:?}"))?;
+        let mut value = HeaderValue::from_str(value.trim()).with_context(|| format!("invalid value for header {name}"))?;
+        value.set_sensitive(name == COOKIE || name == AUTHORIZATION);
+        map.insert(name, value);
+    }
+    Ok(map)
+}
+
+/// 설정 헤더를 요청에 덮어씀(같은 이름의 코드 기본 헤더를 대체). 기본 헤더를 모두 붙인 뒤 호출
+pub fn with_headers(req: RequestBuilder, headers: &HeaderMap) -> RequestBuilder {
+    if headers.is_empty() { req } else { req.headers(headers.clone()) }
+}
+
+/// 응답 본문을 문자열로 읽고 stats에 요청 1건/본문 바이트 수를 기록(읽기 실패도 요청 1건)
+pub async fn read_text(resp: Response, stats: &CollectStats) -> reqwest::Result<String> {
+    let text = resp.text().await;
+    stats.add_http(text.as_ref().map_or(0, String::len));
+    text
+}
+
+/// 요청을 보내고, 연결 오류/타임아웃/5xx면 지수 백오프(+지터)로 재시도
+/// - attempts: 총 시도 횟수(0이면 1로 취급)
+/// - base_backoff: 첫 재시도 전 대기. 이후 2배씩, 최대 8배. 매번 0~절반만큼 무작위로 더함
+///
+/// 시도마다 rate_limit::throttle(호스트당 초당 max_rps회)을 거치고 기다린 시간은 stats에 기록
+/// 4xx 등 5xx가 아닌 응답은 바로 돌려줌(상태 확인은 호출자 몫)
+/// 본문을 복제할 수 없는 요청(스트림 본문)은 한 번만 보냄
+pub async fn send_with_retry(
+    req: RequestBuilder,
+    attempts: u32,
+    base_backoff: Duration,
+    max_rps: f64,
+    stats: &CollectStats,
+) -> Result<Response> {
+    let (client, req) = req.build_split();
+    let mut next = Some(req?);
+    let attempts = attempts.max(1);
+    let mut backoff = base_backoff;
+    let mut last_err: Option<anyhow::Error> = None;
+
+    for attempt in 1..=attempts {
+        let Some(req) = next.take() else { break };
+        next = req.try_clone();
+        let url = req.url().to_string();
+        stats.add_rate_limit_wait(crate::rate_limit::throttle(&url, max_rps).await);
+
+        match client.execute(req).await {
+            Ok(resp) if resp.status().is_server_error() => {
+                last_err = Some(anyhow!("HTTP {} for {url}", resp.status()));
+            }
+            Ok(resp) => return Ok(resp),
+            // 연결 실패/타임아웃/전송 중 끊김만 재시도
+            Err(e) if e.is_connect() || e.is_timeout() || e.is_request() => last_err = Some(e.into()),
+            Err(e) => return Err(e.into()),
+        }
+
+        if attempt < attempts && next.is_some() {
+            let jitter = Duration::from_millis(fastrand::u64(0..=backoff.as_millis() as u64 / 2));
+            let wait = backoff + jitter;
+            warn!(attempt, attempts, url = %url, backoff_ms = wait.as_millis() as u64, "retrying request");
+            tokio::time::sleep(wait).await;
+            backoff = (backoff * 2).min(base_backoff * 8);
+        }
+    }
+    Err(last_err.unwrap_or_else(|| anyhow!("no attempts")).context(format!("failed after {attempts} attempt(s)")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockServer, Reply};
+
+    fn with_proxy(url: &str) -> HttpOptions {
+        HttpOptions { proxy: Some(url.to_string()), ..HttpOptions::default() }
+    }
+
+    #[test]
+    fn invalid_proxy_fails_the_builder() {
+        for bad in ["ftp://proxy:21", "not a url", "socks5://"] {
+            assert!(client_builder(&with_proxy(bad)).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn valid_or_missing_proxy_builds() {
+        for ok in ["http://127.0.0.1:3128", "socks5h://127.0.0.1:1080"] {
+            assert!(client_builder(&with_proxy(ok)).unwrap().build().is_ok(), "{ok}");
+        }
+        assert!(client_builder(&HttpOptions::default()).unwrap().build().is_ok());
+        assert!(HttpOptions { proxy: Some("  ".into()), ..HttpOptions::default() }.proxy().unwrap().is_none());
+    }
+
+    #[test]
+    fn header_map_validates_and_redacts() {
+        let ok: HashMap<String, String> =
+            [("X-Requested-With", "XMLHttpRequest"), ("Cookie", "sid=1")].map(|(k, v)| (k.into(), v.into())).into();
+        let map = header_map(&ok).unwrap();
+        assert_eq!(map.get("x-requested-with").unwrap(), "XMLHttpRequest");
+        assert!(map.get(COOKIE).unwrap().is_sensitive());
+        let bad: HashMap<String, String> = [("Bad Header".to_string(), "x".to_string())].into();
+        assert!(header_map(&bad).unwrap_err().to_string().contains("Bad Header"));
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_then_returns_success() {
+        let server = MockServer::start(vec![("/list", vec![Reply::status(503), Reply::status(502), Reply::ok("done")])]).await;
+        let client = client_builder(&HttpOptions::default()).unwrap().build().unwrap();
+        let resp = send_with_retry(client.get(server.url("/list")), 3, Duration::from_millis(1), 0.0, &CollectStats::default()).await.unwrap();
+        assert_eq!(resp.text().await.unwrap(), "done");
+        assert_eq!(server.requests_to("/list").len(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_attempts_and_does_not_retry_4xx() {
+        let server = MockServer::start(vec![("/down", vec![Reply::status(500)]), ("/gone", vec![Reply::status(404)])]).await;
+        let client = reqwest::Client::new();
+        assert!(send_with_retry(client.get(server.url("/down")), 2, Duration::from_millis(1), 0.0, &CollectStats::default()).await.is_err());
+        assert_eq!(server.requests_to("/down").len(), 2);
+        let resp = send_with_retry(client.get(server.url("/gone")), 3, Duration::from_millis(1), 0.0, &CollectStats::default()).await.unwrap();
+        assert_eq!(resp.status().as_u16(), 404);
+        assert_eq!(server.requests_to("/gone").len(), 1);
+    }
+
+    /// keep-alive 서버에 요청을 연달아 보내고 서버가 받은 커넥션 수를 셈
+    async fn connections_for(opts: &HttpOptions, requests: usize) -> usize {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let counter = accepted.clone();
+        tokio::spawn(async move {
+            while let Ok((mut sock, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 4096];
+                    while let Ok(n) = sock.read(&mut buf).await {
+                        if n == 0 {
+                            break;
+                        }
+                        let reply = "HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\nok";
+                        if sock.write_all(reply.as_bytes()).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        let client = client_builder(opts).unwrap().build().unwrap();
+        for _ in 0..requests {
+            let body = client.get(&url).send().await.unwrap().text().await.unwrap();
+            assert_eq!(body, "ok");
+        }
+        accepted.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn pool_max_idle_controls_connection_reuse() {
+        // 유휴 커넥션을 남기면 재사용(1개), 0이면 요청마다 새 커넥션
+        let pooled = HttpOptions { pool_max_idle: 1, ..HttpOptions::default() };
+        assert_eq!(connections_for(&pooled, 3).await, 1);
+        let unpooled = HttpOptions { pool_max_idle: 0, ..HttpOptions::default() };
+        assert_eq!(connections_for(&unpooled, 3).await, 3);
+    }
+}
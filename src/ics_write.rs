@@ -0,0 +1,81 @@
+// src/ics_write.rs
+use anyhow::Result;
+use chrono::{Duration, NaiveDate, Utc};
+use tracing::info;
+
+use crate::atomic_write::write_atomic;
+use crate::notice::{Notice, normalize_url, stable_hash};
+
+/// 마감일(end)을 종일 일정으로 하는 iCalendar 파일 저장
+/// - end가 없는 항목은 건너뛰고 건수만 기록
+/// - UID는 정규화 URL 해시라 다시 실행해도 같은 일정이 갱신됨
+pub fn write_ics(notices: &[Notice], output_file: &str, strip: &[String]) -> Result<()> {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines: Vec<String> = vec![
+        "BEGIN:VCALENDAR".into(),
+        "VERSION:2.0".into(),
+        format!("PRODID:-//{}//{}//KO", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+        "CALSCALE:GREGORIAN".into(),
+        "METHOD:PUBLISH".into(),
+    ];
+
+    let mut skipped = 0usize;
+    for n in notices {
+        let Some(end) = n.end.as_deref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()) else {
+            skipped += 1;
+            continue;
+        };
+        let uid_key = if n.url.is_empty() { n.title.as_str() } else { &normalize_url(&n.url, strip) };
+
+        lines.push("BEGIN:VEVENT".into());
+        lines.push(format!("UID:{:016x}@etc-crawler", stable_hash(uid_key)));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        // 종일 일정: DTEND는 다음 날(배타적)
+        lines.push(format!("DTSTART;VALUE=DATE:{}", end.format("%Y%m%d")));
+        lines.push(format!("DTEND;VALUE=DATE:{}", (end + Duration::days(1)).format("%Y%m%d")));
+        lines.push(format!("SUMMARY:{}", escape_text(&n.title)));
+        if !n.url.is_empty() {
+            lines.push(format!("URL:{}", n.url));
+        }
+        if let Some(org) = n.organizer.as_deref() {
+            lines.push(format!("DESCRIPTION:{}", escape_text(org)));
+        }
+        lines.push("END:VEVENT".into());
+    }
+    lines.push("END:VCALENDAR".into());
+
+    if skipped > 0 {
+        info!(file = output_file, skipped, "items without end skipped");
+    }
+
+    let mut body = String::new();
+    for l in &lines {
+        body.push_str(&fold_line(l));
+        body.push_str("\r\n");
+    }
+    write_atomic(output_file, body.as_bytes())
+}
+
+/// TEXT 값 이스케이프(RFC 5545 3.3.11)
+fn escape_text(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace(['\r', '\n'], "\\n")
+}
+
+/// 75옥텟마다 줄 접기(UTF-8 문자 중간에서 자르지 않음)
+fn fold_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 8);
+    let mut len = 0usize;
+    for c in line.chars() {
+        let w = c.len_utf8();
+        if len + w > 75 {
+            out.push_str("\r\n ");
+            len = 1;
+        }
+        out.push(c);
+        len += w;
+    }
+    out
+}
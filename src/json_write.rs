@@ -0,0 +1,118 @@
+// src/json_write.rs
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use crate::atomic_write::write_atomic;
+use crate::labels::{self, Lang};
+use crate::notice::{Notice, Source};
+
+/// Notice → JSON 객체(빈 값은 "-" 대신 null)
+/// source/kind는 serde 값("wevity", "contest", write_json과 같음), 표시용 한국어는 source_label/kind_label
+pub fn to_json_value(n: &Notice) -> Value {
+    json!({
+        "source":       n.source,
+        "source_label": labels::source_label(&n.source, Lang::Ko),
+        "kind":         n.kind,
+        "kind_label":   labels::kind_label(&n.kind, Lang::Ko),
+        "title":        n.title,
+        "raw_title":    n.raw_title,
+        "url":          n.url,
+        "start":        n.start,
+        "end":          n.end,
+        "end_approx":   n.end_approx,
+        "organizer":    n.organizer,
+        "field":        n.field,
+        "series_id":    n.series_id,
+    })
+}
+
+/// 통합 Notice 목록을 JSON 배열로 저장
+pub fn write_json_feed(notices: &[Notice], output_file: &str) -> Result<()> {
+    let arr = Value::Array(notices.iter().map(to_json_value).collect());
+    write_atomic(output_file, serde_json::to_string_pretty(&arr)?.as_bytes())
+}
+
+/// 소스별로 묶은 통합 JSON: {"meta": {version, generator, generated}, "groups": [{source, source_label, count, items}]}
+/// source는 to_json_value와 같은 serde 값. generator는 FeedOptions::generator(CRAWL_ID가 있으면 포함), 그룹 순서는 입력에 처음 나온 순서
+pub fn write_grouped_json(notices: &[Notice], output_file: &str, generator: &str) -> Result<()> {
+    write_atomic(output_file, serde_json::to_string_pretty(&grouped_value(notices, generator))?.as_bytes())
+}
+
+fn grouped_value(notices: &[Notice], generator: &str) -> Value {
+    let mut groups: Vec<(&Source, Vec<Value>)> = Vec::new();
+    for n in notices {
+        match groups.iter_mut().find(|(s, _)| s.name() == n.source.name()) {
+            Some((_, items)) => items.push(to_json_value(n)),
+            None => groups.push((&n.source, vec![to_json_value(n)])),
+        }
+    }
+    json!({
+        "meta": {
+            "version":   env!("CARGO_PKG_VERSION"),
+            "generator": generator,
+            "generated": crate::clock::today().format("%Y-%m-%d").to_string(),
+        },
+        "groups": groups
+            .into_iter()
+            .map(|(source, items)| {
+                json!({
+                    "source":       source,
+                    "source_label": labels::source_label(source, Lang::Ko),
+                    "count":        items.len(),
+                    "items":        items,
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Notice 목록을 필드 그대로(source/kind는 소문자 영문) pretty JSON으로 저장
+pub fn write_json(notices: &[Notice], output_file: &str) -> Result<()> {
+    write_atomic(output_file, serde_json::to_string_pretty(notices)?.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::Kind;
+
+    fn notice(source: Source, title: &str) -> Notice {
+        Notice {
+            source,
+            kind: Kind::Contest,
+            title: title.into(),
+            raw_title: String::new(),
+            url: format!("https://example.com/{title}"),
+            start: None,
+            end: Some("2026-10-24".into()),
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    #[test]
+    fn grouped_json_carries_version_and_groups_by_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("grouped.json").display().to_string();
+        let list = [notice(Source::Dacon, "a"), notice(Source::Wevity, "b"), notice(Source::Dacon, "c")];
+        write_grouped_json(&list, &out, "etc_crawler/test (crawl run-7)").unwrap();
+
+        let v: Value = serde_json::from_str(&std::fs::read_to_string(&out).unwrap()).unwrap();
+        assert_eq!(v["meta"]["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(v["meta"]["generator"], "etc_crawler/test (crawl run-7)");
+        let groups = v["groups"].as_array().unwrap();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0]["source"], "dacon");
+        assert_eq!(groups[0]["source_label"], labels::source_label(&Source::Dacon, Lang::Ko));
+        assert_eq!(groups[0]["count"], 2);
+        assert_eq!(groups[0]["items"][0]["source"], "dacon");
+        assert_eq!(groups[0]["items"][0]["kind"], "contest");
+        assert_eq!(groups[0]["items"][0]["kind_label"], labels::kind_label(&Kind::Contest, Lang::Ko));
+        assert_eq!(groups[0]["items"][1]["title"], "c");
+        assert_eq!(groups[1]["items"][0]["title"], "b");
+    }
+}
@@ -0,0 +1,104 @@
+// src/keywords.rs
+use regex::Regex;
+
+/// 소스 공통 키워드 매처
+///
+/// 키워드 문법:
+/// - `re:<pattern>` : 정규식 그대로(대소문자 무시)
+/// - `*<word>*`     : 강제 부분 문자열 일치
+/// - ASCII 단어     : 단어 경계 일치("ai"는 "AI 공모전"/"AI챌린지"엔 맞고 "Chair"엔 안 맞음)
+/// - 그 외(한글 등) : 부분 문자열 일치
+#[derive(Debug)]
+pub struct KeywordMatcher {
+    patterns: Vec<Pattern>,
+}
+
+#[derive(Debug)]
+enum Pattern {
+    Regex(Regex),
+    Substring(String),
+}
+
+impl KeywordMatcher {
+    /// 잘못된 `re:` 정규식은 Err
+    pub fn new<S: AsRef<str>>(keywords: &[S]) -> Result<Self, regex::Error> {
+        let patterns = keywords
+            .iter()
+            .map(|kw| compile(kw.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { patterns })
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        let lower = text.to_lowercase();
+        self.patterns.iter().any(|p| match p {
+            Pattern::Regex(re) => re.is_match(text),
+            Pattern::Substring(s) => lower.contains(s.as_str()),
+        })
+    }
+}
+
+fn compile(kw: &str) -> Result<Pattern, regex::Error> {
+    if let Some(re) = kw.strip_prefix("re:") {
+        return Ok(Pattern::Regex(Regex::new(&format!("(?i){re}"))?));
+    }
+    if let Some(inner) = kw.strip_prefix('*').and_then(|k| k.strip_suffix('*')) {
+        return Ok(Pattern::Substring(inner.to_lowercase()));
+    }
+    if kw.chars().all(|c| c.is_ascii_alphanumeric()) {
+        // ASCII 경계: 한글이 바로 붙은 "AI챌린지"도 경계로 인정
+        let re = format!(r"(?i)(?-u:\b){}(?-u:\b)", regex::escape(kw));
+        return Ok(Pattern::Regex(Regex::new(&re)?));
+    }
+    Ok(Pattern::Substring(kw.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyword_table() {
+        // (키워드, 제목, 기대값)
+        let table: &[(&str, &str, bool)] = &[
+            // ASCII 단어: 단어 경계
+            ("ai", "AI 공모전", true),
+            ("ai", "2026 AI챌린지", true),
+            ("ai", "생성형AI 아이디어", true),
+            ("ai", "Chair design award", false),
+            ("ai", "He said yes", false),
+            ("sw", "SW 개발 캠프", true),
+            ("sw", "swimming festival", false),
+            ("it", "IT 서포터즈", true),
+            ("it", "Write it down", true),
+            ("it", "Digital edition", false),
+            ("ML", "ml engineer bootcamp", true),
+            ("ML", "HTML 교육", false),
+            // 한글: 부분 문자열
+            ("개발", "앱개발자 모집", true),
+            ("데이터", "빅데이터 분석 대회", true),
+            ("데이터", "디자인 공모전", false),
+            // 강제 부분 문자열
+            ("*ai*", "Chair design award", true),
+            ("*개발*", "게임개발", true),
+            // 명시적 정규식(대소문자 무시)
+            (r"re:\bML\b", "ml 경진대회", true),
+            (r"re:\bML\b", "HTML5", false),
+            (r"re:^\[서울\]", "[서울] 청년 해커톤", true),
+            (r"re:^\[서울\]", "부산 [서울] 해커톤", false),
+        ];
+        for &(kw, title, want) in table {
+            let m = KeywordMatcher::new(&[kw]).unwrap();
+            assert_eq!(m.is_match(title), want, "keyword {kw:?} vs {title:?}");
+        }
+    }
+
+    #[test]
+    fn any_keyword_matches_and_bad_regex_is_an_error() {
+        let m = KeywordMatcher::new(&["sw", "데이터"]).unwrap();
+        assert!(m.is_match("빅데이터 캠프"));
+        assert!(!m.is_match("swimming"));
+        assert!(!KeywordMatcher::new::<&str>(&[]).unwrap().is_match("AI"));
+        assert!(KeywordMatcher::new(&["re:(unclosed"]).is_err());
+    }
+}
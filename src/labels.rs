@@ -0,0 +1,172 @@
+// src/labels.rs
+use serde::Deserialize;
+
+use crate::notice::{Kind, Source};
+
+/// 출력 라벨 언어(LABEL_LANG=en이면 영어, 그 외 한국어)
+/// 수집한 원문(제목/주최 등)은 번역하지 않고 고정 라벨만 바꾼다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Lang {
+    #[default]
+    Ko,
+    En,
+}
+
+/// 영어 모드에서 kr_mark(LABEL_KR_MARK=1)면 한글이 포함된 제목 뒤에 "(KR)"을 붙임
+pub fn mark_korean(title: &str, lang: Lang, kr_mark: bool) -> String {
+    let mark = lang == Lang::En && kr_mark;
+    if mark && title.chars().any(|c| ('\u{AC00}'..='\u{D7A3}').contains(&c)) {
+        format!("{title} (KR)")
+    } else {
+        title.to_string()
+    }
+}
+
+pub fn kind_label(kind: &Kind, lang: Lang) -> &'static str {
+    match (kind, lang) {
+        (Kind::Contest,   Lang::Ko) => "공모전",
+        (Kind::Activity,  Lang::Ko) => "대외활동",
+        (Kind::Education, Lang::Ko) => "교육",
+        (Kind::Recruit,   Lang::Ko) => "채용",
+        (Kind::Contest,   Lang::En) => "Contest",
+        (Kind::Activity,  Lang::En) => "Activity",
+        (Kind::Education, Lang::En) => "Education",
+        (Kind::Recruit,   Lang::En) => "Recruit",
+    }
+}
+
+pub fn source_label(source: &Source, lang: Lang) -> &str {
+    match (source, lang) {
+        (Source::Wevity,     Lang::Ko) => "위비티",
+        (Source::Campuspick, Lang::Ko) => "캠퍼스픽",
+        (Source::Dacon,      Lang::Ko) => "데이콘",
+        (Source::Linkareer,  Lang::Ko) => "링커리어",
+        (Source::Wevity,     Lang::En) => "Wevity",
+        (Source::Campuspick, Lang::En) => "Campuspick",
+        (Source::Dacon,      Lang::En) => "DACON",
+        (Source::Linkareer,  Lang::En) => "Linkareer",
+        (Source::Custom(label), _)     => label,
+    }
+}
+
+/// RSS description 항목명
+pub struct DescLabels {
+    pub organizer: &'static str,
+    pub period: &'static str,
+    pub field: &'static str,
+}
+
+pub fn desc_labels(lang: Lang) -> DescLabels {
+    match lang {
+        Lang::Ko => DescLabels { organizer: "주최", period: "기간", field: "분야" },
+        Lang::En => DescLabels { organizer: "Organizer", period: "Period", field: "Field" },
+    }
+}
+
+/// 추정된 마감일 표시
+pub fn approx_mark(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Ko => " (추정)",
+        Lang::En => " (approx.)",
+    }
+}
+
+/// Markdown 요약의 마감 주 그룹 이름(이번 주 / 다음 주 / 그 이후 / 미정 / 지난 마감 순)
+pub fn week_groups(lang: Lang) -> [&'static str; 5] {
+    match lang {
+        Lang::Ko => ["이번 주 마감", "다음 주 마감", "그 이후 마감", "마감일 미정", "지난 마감"],
+        Lang::En => ["Due this week", "Due next week", "Due later", "No deadline", "Past deadline"],
+    }
+}
+
+/// 그룹 헤더 뒤 건수 표시: "(3건)" / "(3)"
+pub fn count(n: usize, lang: Lang) -> String {
+    match lang {
+        Lang::Ko => format!("({n}건)"),
+        Lang::En => format!("({n})"),
+    }
+}
+
+/// HTML 대시보드 표 머리글과 생성 시각 라벨
+pub struct TableLabels {
+    pub html_lang: &'static str,
+    pub generated: &'static str,
+    pub title: &'static str,
+    pub organizer: &'static str,
+    pub period: &'static str,
+    pub source: &'static str,
+    pub kind: &'static str,
+    pub count: &'static str,
+}
+
+pub fn table_labels(lang: Lang) -> TableLabels {
+    let desc = desc_labels(lang);
+    match lang {
+        Lang::Ko => TableLabels {
+            html_lang: "ko",
+            generated: "생성",
+            title: "제목",
+            organizer: desc.organizer,
+            period: desc.period,
+            source: "출처",
+            kind: "구분",
+            count: "건수",
+        },
+        Lang::En => TableLabels {
+            html_lang: "en",
+            generated: "Generated",
+            title: "Title",
+            organizer: desc.organizer,
+            period: desc.period,
+            source: "Source",
+            kind: "Type",
+            count: "Count",
+        },
+    }
+}
+
+/// 채널 메타데이터(title, description)
+pub fn channel_meta(feed: &str, lang: Lang) -> (&'static str, &'static str) {
+    match (feed, lang) {
+        ("wevity",     Lang::Ko) => ("Wevity RSS", "위비티 공모전/대외활동"),
+        ("campuspick", Lang::Ko) => ("Campuspick RSS", "캠퍼스픽 대외활동"),
+        ("dacon",      Lang::Ko) => ("DACON RSS", "데이콘 대회"),
+        ("linkareer",  Lang::Ko) => ("Linkareer RSS", "링커리어 공모전/대외활동"),
+        ("wevity",     Lang::En) => ("Wevity RSS", "Wevity contests and activities"),
+        ("campuspick", Lang::En) => ("Campuspick RSS", "Campuspick activities"),
+        ("dacon",      Lang::En) => ("DACON RSS", "DACON competitions"),
+        ("linkareer",  Lang::En) => ("Linkareer RSS", "Linkareer contests and activities"),
+        ("archive",    Lang::Ko) => ("Archive RSS", "지난 마감 포함 전체 기록"),
+        ("archive",    Lang::En) => ("Archive RSS", "Full history including closed deadlines"),
+        ("archive_index", Lang::Ko) => ("지난 공고 아카이브", "월별 마감 공고 기록"),
+        ("archive_index", Lang::En) => ("Notice Archive", "Closed notices by month"),
+        (_,            Lang::Ko) => ("통합 공모전·대외활동 RSS", "모든 소식 통합"),
+        (_,            Lang::En) => ("Merged Contests & Activities RSS", "All sources merged"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_labels_replace_fixed_korean_text() {
+        assert_eq!(kind_label(&Kind::Education, Lang::En), "Education");
+        assert_eq!(source_label(&Source::Dacon, Lang::Ko), "데이콘");
+        assert_eq!(week_groups(Lang::En)[0], "Due this week");
+        assert_eq!(count(3, Lang::Ko), "(3건)");
+        assert_eq!(table_labels(Lang::En).organizer, "Organizer");
+        assert_eq!(channel_meta("dacon", Lang::En).1, "DACON competitions");
+        assert_eq!(channel_meta("archive", Lang::En).1, "Full history including closed deadlines");
+        assert_eq!(channel_meta("archive", Lang::Ko).1, "지난 마감 포함 전체 기록");
+    }
+
+    #[test]
+    fn kr_mark_only_in_english_mode_for_hangul_titles() {
+        assert_eq!(mark_korean("AI 공모전", Lang::En, true), "AI 공모전 (KR)");
+        assert_eq!(mark_korean("AI Contest", Lang::En, true), "AI Contest");
+        assert_eq!(mark_korean("AI 공모전", Lang::Ko, true), "AI 공모전");
+        assert_eq!(mark_korean("AI 공모전", Lang::En, false), "AI 공모전");
+    }
+}
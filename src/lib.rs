@@ -0,0 +1,42 @@
+// src/lib.rs
+//! 공모전/대외활동 수집 라이브러리
+//! 수집기(wevity/campuspick/dacon)와 Notice 정규화/통합/피드 쓰기를 제공한다.
+//! 공개 함수는 env를 직접 읽지 않고 옵션 구조체를 받는다(env는 `config::Config::apply_env`에서만 읽음).
+
+pub mod config;
+pub mod notice;
+pub mod clock;
+pub mod dates;
+pub mod source;
+pub mod http;
+pub mod rate_limit;
+pub mod robots;
+pub mod labels;
+mod keywords;
+pub mod wevity;
+pub mod campuspick;
+pub mod dacon;
+pub mod linkareer;
+pub mod rss_ingest;
+pub mod bench;
+
+pub mod atomic_write;
+pub mod feed_state;
+pub mod rss_write;
+pub mod rss_merged;
+pub mod json_write;
+pub mod ics_write;
+pub mod html_write;
+pub mod md_write;
+pub mod csv_write;
+pub mod metrics;
+pub mod summary;
+pub mod baseline;
+pub mod history;
+pub mod store;
+pub mod archive;
+pub mod lock;
+pub mod schedule;
+
+#[cfg(test)]
+mod test_util;
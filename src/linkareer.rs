@@ -0,0 +1,324 @@
+// src/linkareer.rs
+use anyhow::{Context, Result};
+use chrono::{Local, NaiveDate, TimeZone};
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashSet, time::Duration};
+
+use tracing::warn;
+
+use crate::dates;
+use crate::keywords::KeywordMatcher;
+use crate::source::CollectStats;
+use crate::notice::{Notice, Source, Kind, clean_title, decode_entities, sort_join};
+
+/// 링커리어 웹 사이트 URL
+const WEB_BASE: &str = "https://linkareer.com";
+
+/// 목록 GraphQL 쿼리(활동 유형별로 같은 쿼리를 씀)
+const LIST_QUERY: &str = "query gqlActivityList($filterBy: ActivityFilter, $pageSize: Int, $page: Int) { activities(filterBy: $filterBy, pageSize: $pageSize, page: $page) { nodes { id title organizationName recruitStartAt recruitCloseAt categories { name } } } }";
+
+/// 링커리어 수집 설정(설정 파일 [linkareer] 섹션)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LinkareerOptions {
+    /// 목록 GraphQL 엔드포인트
+    pub api: String,
+    /// 대외활동/공모전 activityTypeID
+    pub activity_type: String,
+    pub contest_type: String,
+    /// 페이지당 개수
+    pub page_size: usize,
+    /// 페이지 수
+    pub pages: usize,
+    /// 오늘부터 마감까지 남은 일수 상한([deadline_days]에서 결정)
+    #[serde(skip)]
+    pub deadline_days: i64,
+    /// 마감까지 최소 남은 일수(config의 min_deadline_days)
+    #[serde(skip)]
+    pub min_deadline_days: i64,
+    pub delay_ms: u64,
+    /// 목록 요청 시도 횟수(네트워크 오류/5xx만 재시도)
+    pub retries: u32,
+    /// 첫 재시도 대기(ms), 이후 2배씩
+    pub backoff_ms: u64,
+    /// 지난 마감도 유지(아카이브 피드용)
+    #[serde(skip)]
+    pub archive: bool,
+    /// 공통 HTTP 설정(config의 [http])
+    #[serde(skip)]
+    pub http: crate::http::HttpOptions,
+    /// 요청마다 덮어쓸 헤더([linkareer.headers], 같은 이름의 기본 헤더를 대체)
+    pub headers: std::collections::HashMap<String, String>,
+    /// 제목/카테고리 키워드(공모전/대외활동 공통, 문법은 keywords 모듈 참고)
+    pub keywords: Vec<String>,
+    /// 목록 배열 위치(JSON pointer)
+    pub list_path: String,
+    /// 상세 페이지 URL 템플릿({id} 치환)
+    pub detail_url: String,
+    /// 호스트당 초당 최대 요청 수(0이면 제한 없음)
+    pub max_rps: f64,
+}
+
+impl Default for LinkareerOptions {
+    fn default() -> Self {
+        Self {
+            api: "https://api.linkareer.com/graphql".into(),
+            activity_type: "1".into(),
+            contest_type: "3".into(),
+            page_size: 30,
+            pages: 3,
+            deadline_days: 20,
+            min_deadline_days: 0,
+            delay_ms: 300,
+            retries: 3,
+            backoff_ms: 500,
+            archive: false,
+            http: crate::http::HttpOptions::default(),
+            headers: std::collections::HashMap::new(),
+            keywords: KEYWORDS.iter().map(|k| k.to_string()).collect(),
+            list_path: "/data/activities/nodes".into(),
+            detail_url: "https://linkareer.com/activity/{id}".into(),
+            max_rps: 2.0,
+        }
+    }
+}
+
+/// 제목/카테고리 키워드 기본값(캠퍼스픽 대외활동 키워드 + 대회/해커톤)
+const KEYWORDS: &[&str] = &[
+    "IT","SW","코딩","소프트웨어","컴퓨터","보안","정보보호","KISIA","개인정보","개발자","AI","엔지니어","부트캠프",
+    "프로그래밍","데이터","해커톤",
+];
+
+#[derive(Clone, Debug)]
+pub struct Row {
+    pub kind: String,              // activity / contest
+    pub title: String,
+    pub url: String,               // 상세 URL
+    pub start: Option<String>,     // 모집 시작일(YYYY-MM-DD)
+    pub end: Option<String>,       // 모집 마감일(YYYY-MM-DD)
+    pub organizer: Option<String>, // 주최 기관명
+    pub field: Option<String>,     // 카테고리명(" / "로 결합)
+}
+
+pub async fn collect(opts: &LinkareerOptions, stats: &CollectStats) -> Result<Vec<Row>> {
+    let client = crate::http::client_builder(&opts.http)?
+        .user_agent(crate::http::USER_AGENT)
+        .build()?;
+    let headers = crate::http::header_map(&opts.headers)?;
+
+    let mut out = Vec::<Row>::new();
+    out.extend(fetch_one_kind(&client, opts, &headers, "activity", &opts.activity_type, stats).await?);
+    out.extend(fetch_one_kind(&client, opts, &headers, "contest", &opts.contest_type, stats).await?);
+
+    out.sort_by(|a,b| a.start.is_none().cmp(&b.start.is_none())
+        .then(a.start.cmp(&b.start))
+        .then(a.end.cmp(&b.end))
+        .then(a.title.cmp(&b.title)));
+    Ok(out)
+}
+
+async fn fetch_one_kind(
+    client: &reqwest::Client,
+    opts: &LinkareerOptions,
+    headers: &reqwest::header::HeaderMap,
+    kind: &str,
+    type_id: &str,
+    stats: &CollectStats,
+) -> Result<Vec<Row>> {
+    let mut out = Vec::<Row>::new();
+    let mut seen = HashSet::<String>::new();
+    let matcher = KeywordMatcher::new(&opts.keywords)?;
+    let today = crate::clock::today();
+
+    for page in 1..=opts.pages {
+        let body = serde_json::json!({
+            "operationName": "gqlActivityList",
+            "variables": {
+                "filterBy": { "activityTypeID": type_id, "status": "OPEN" },
+                "pageSize": opts.page_size,
+                "page": page,
+            },
+            "query": LIST_QUERY,
+        });
+
+        let req = client.post(&opts.api)
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/json")
+            .header("Origin", WEB_BASE)
+            .header("Referer", format!("{WEB_BASE}/list/{kind}"))
+            .body(body.to_string());
+        // 네트워크 오류/5xx는 재시도(호스트별 max_rps도 send_with_retry에서)
+        let req = crate::http::with_headers(req, headers);
+        let list_started = std::time::Instant::now();
+        let resp = crate::http::send_with_retry(req, opts.retries, Duration::from_millis(opts.backoff_ms), opts.max_rps, stats)
+            .await
+            .context("list request")?;
+        let status = resp.status();
+        let text = crate::http::read_text(resp, stats).await?;
+        stats.add_list_time(list_started.elapsed());
+        if !status.is_success() {
+            warn!(kind, page, %status, "list request failed");
+            break;
+        }
+
+        let parse_started = std::time::Instant::now();
+        let v: Value = serde_json::from_str(&text).with_context(|| "invalid JSON")?;
+        let Some(arr) = v.pointer(&opts.list_path).and_then(|x| x.as_array()) else {
+            warn!(path = %opts.list_path, "list path not found");
+            break;
+        };
+        if arr.is_empty() { break; }
+
+        for it in arr {
+            let Some(id) = get_id(it) else { continue };
+            if !seen.insert(id.clone()) { continue; }
+            stats.add_raw(1);
+
+            let title = it.get("title").and_then(|x| x.as_str()).unwrap_or_default().trim().to_string();
+            if title.is_empty() { continue; }
+
+            // IT 키워드 필터(제목 + 카테고리)
+            let field = categories(it);
+            let hay = format!("{title} {}", field.as_deref().unwrap_or_default());
+            if !matcher.is_match(&hay) { continue; }
+
+            // 마감 필터(마감일을 모르면 제외)
+            let Some(end) = it.get("recruitCloseAt").and_then(to_date) else { continue };
+            if !dates::within_deadline((end - today).num_days(), opts.min_deadline_days, opts.deadline_days, opts.archive) {
+                continue;
+            }
+
+            out.push(Row {
+                kind: kind.to_string(),
+                title,
+                url: opts.detail_url.replace("{id}", &id),
+                start: it.get("recruitStartAt").and_then(to_date).map(|d| d.format("%Y-%m-%d").to_string()),
+                end: Some(end.format("%Y-%m-%d").to_string()),
+                organizer: it.get("organizationName").and_then(|x| x.as_str())
+                    .map(str::trim).filter(|s| !s.is_empty()).map(str::to_string),
+                field,
+            });
+        }
+        stats.add_parse_time(parse_started.elapsed());
+        stats.pace(Duration::from_millis(opts.delay_ms)).await;
+    }
+    Ok(out)
+}
+
+fn get_id(v: &Value) -> Option<String> {
+    let x = v.get("id")?;
+    if let Some(s) = x.as_str() && !s.is_empty() { return Some(s.to_string()); }
+    x.as_i64().map(|n| n.to_string())
+}
+
+/// categories[].name → "A / B"
+fn categories(v: &Value) -> Option<String> {
+    let names: Vec<&str> = v.get("categories")?.as_array()?
+        .iter()
+        .filter_map(|c| c.get("name").and_then(|n| n.as_str()))
+        .collect();
+    if names.is_empty() { None } else { Some(names.join(" / ")) }
+}
+
+/// 날짜 값: epoch 밀리초(숫자) 또는 "YYYY-MM-DD..." 문자열 → 로컬 날짜
+fn to_date(v: &Value) -> Option<NaiveDate> {
+    if let Some(ms) = v.as_i64() {
+        return Local.timestamp_millis_opt(ms).single().map(|t| t.date_naive());
+    }
+    dates::parse_ymd(v.as_str()?)
+}
+
+// === Notice 어댑터 ===
+pub fn to_notice_from_linkareer(r: &Row) -> Notice {
+    let kind = if r.kind == "activity" { Kind::Activity } else { Kind::Contest };
+    Notice {
+        source: Source::Linkareer,
+        kind,
+        title: clean_title(&decode_entities(&r.title), &Source::Linkareer),
+        raw_title: r.title.clone(),
+        url:   r.url.clone(),
+        start: r.start.clone(),
+        end:   r.end.clone(),
+        organizer: r.organizer.as_deref().map(|c| sort_join(c, '/', " / ")),
+        field: r.field.clone(),
+        end_approx: false,
+        summary: None,
+        series_id: None,
+        changed_at: None,
+    }
+}
+
+// === Crawler 구현 ===
+pub struct LinkareerCrawler {
+    pub opts: LinkareerOptions,
+    pub timeout: Duration,
+}
+
+#[async_trait::async_trait(?Send)]
+impl crate::source::Crawler for LinkareerCrawler {
+    fn name(&self) -> &str { "linkareer" }
+    fn site_url(&self) -> &str { WEB_BASE }
+    fn list_url(&self) -> Option<&str> { Some("https://linkareer.com/list/contest") }
+    fn timeout(&self) -> Duration { self.timeout }
+
+    async fn collect(&self, stats: &CollectStats) -> Result<Vec<Notice>> {
+        let rows = collect(&self.opts, stats).await?;
+        Ok(rows.iter().map(to_notice_from_linkareer).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockServer, Reply};
+    use serde_json::json;
+
+    #[test]
+    fn to_date_accepts_epoch_millis_and_date_strings() {
+        // 2026-10-20 12:00 UTC(정오라 어느 시간대에서도 같은 날짜)
+        assert_eq!(to_date(&json!(1792497600000i64)), NaiveDate::from_ymd_opt(2026, 10, 20));
+        assert_eq!(to_date(&json!("2026-10-20T23:59:59+09:00")), NaiveDate::from_ymd_opt(2026, 10, 20));
+        assert_eq!(to_date(&json!("2026.10.20")), NaiveDate::from_ymd_opt(2026, 10, 20));
+        assert_eq!(to_date(&json!(null)), None);
+        assert_eq!(to_date(&json!("상시")), None);
+    }
+
+    #[test]
+    fn categories_join_names_and_skip_empty() {
+        assert_eq!(
+            categories(&json!({"categories": [{"name": "IT/SW"}, {"id": 3}, {"name": "데이터"}]})).as_deref(),
+            Some("IT/SW / 데이터")
+        );
+        assert_eq!(categories(&json!({"categories": []})), None);
+        assert_eq!(categories(&json!({"title": "x"})), None);
+    }
+
+    #[test]
+    fn get_id_accepts_strings_and_numbers() {
+        assert_eq!(get_id(&json!({"id": "A12"})).as_deref(), Some("A12"));
+        assert_eq!(get_id(&json!({"id": 345})).as_deref(), Some("345"));
+        assert_eq!(get_id(&json!({"id": ""})), None);
+        assert_eq!(get_id(&json!({"title": "no id"})), None);
+    }
+
+    #[tokio::test]
+    async fn list_request_is_retried_on_server_error() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let page = json!({"data": {"activities": {"nodes": [
+            {"id": "77", "title": "AI 해커톤", "organizationName": "링커리어", "recruitCloseAt": "2026-10-20"}
+        ]}}});
+        let server = MockServer::start(vec![("/graphql", vec![Reply::status(503), Reply::json(&page.to_string())])]).await;
+        let opts = LinkareerOptions { api: server.url("/graphql"), pages: 1, delay_ms: 0, backoff_ms: 1, ..LinkareerOptions::default() };
+
+        let rows = fetch_one_kind(&reqwest::Client::new(), &opts, &Default::default(), "contest", "3", &CollectStats::default())
+            .await
+            .unwrap();
+        assert_eq!(rows.iter().map(|r| r.url.as_str()).collect::<Vec<_>>(), ["https://linkareer.com/activity/77"]);
+        let reqs = server.requests_to("/graphql");
+        assert_eq!(reqs.len(), 2);
+        assert_eq!(reqs[1].method, "POST");
+        assert!(reqs[1].body.contains("\"activityTypeID\":\"3\""), "{}", reqs[1].body);
+    }
+}
@@ -0,0 +1,103 @@
+// src/lock.rs
+use anyhow::{bail, Context, Result};
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// 실행 잠금 파일(생성 시 배타적으로 만들고, drop 시 삭제)
+/// 내용: "<pid> <unix 시각>"
+#[derive(Debug)]
+pub struct RunLock {
+    path: PathBuf,
+}
+
+impl RunLock {
+    /// 잠금 획득. 다른 실행이 잡고 있으면 Err("already running")
+    /// 기록된 PID가 없거나(/proc 기준) stale_secs보다 오래된 잠금은 지우고 다시 시도
+    pub fn acquire(path: impl AsRef<Path>, stale_secs: i64) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        for _ in 0..2 {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut f) => {
+                    let now = chrono::Utc::now().timestamp();
+                    write!(f, "{} {now}", std::process::id())?;
+                    return Ok(Self { path });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    let body = std::fs::read_to_string(&path).unwrap_or_default();
+                    if !is_stale(&body, stale_secs) {
+                        bail!("already running (lock {} held: {})", path.display(), body.trim());
+                    }
+                    warn!(path = %path.display(), holder = body.trim(), "removing stale lock");
+                    std::fs::remove_file(&path).ok();
+                }
+                Err(e) => return Err(e).with_context(|| format!("create lock {}", path.display())),
+            }
+        }
+        bail!("could not acquire lock {}", path.display())
+    }
+}
+
+impl Drop for RunLock {
+    fn drop(&mut self) {
+        std::fs::remove_file(&self.path).ok();
+    }
+}
+
+/// 형식이 깨졌거나, 프로세스가 없거나, 너무 오래된 잠금이면 stale
+fn is_stale(body: &str, stale_secs: i64) -> bool {
+    let mut it = body.split_whitespace();
+    let (Some(pid), Some(ts)) = (
+        it.next().and_then(|s| s.parse::<u32>().ok()),
+        it.next().and_then(|s| s.parse::<i64>().ok()),
+    ) else {
+        return true;
+    };
+    let proc_dir = Path::new("/proc");
+    if proc_dir.is_dir() && !proc_dir.join(pid.to_string()).exists() {
+        return true;
+    }
+    chrono::Utc::now().timestamp() - ts > stale_secs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_run_is_refused_until_the_first_releases() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("crawl.lock");
+        let first = RunLock::acquire(&path, 3600).unwrap();
+        let err = RunLock::acquire(&path, 3600).unwrap_err();
+        assert!(format!("{err:#}").contains("already running"), "{err:#}");
+        let body = std::fs::read_to_string(&path).unwrap();
+        assert!(body.starts_with(&format!("{} ", std::process::id())), "{body}");
+
+        drop(first);
+        assert!(!path.exists());
+        let _again = RunLock::acquire(&path, 3600).unwrap();
+    }
+
+    #[test]
+    fn stale_locks_are_replaced() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("crawl.lock");
+        let now = chrono::Utc::now().timestamp();
+        // 깨진 내용
+        std::fs::write(&path, "garbage").unwrap();
+        drop(RunLock::acquire(&path, 3600).unwrap());
+        // 없는 PID(/proc 기준)
+        std::fs::write(&path, format!("4000000000 {now}")).unwrap();
+        drop(RunLock::acquire(&path, 3600).unwrap());
+        // 살아 있는 PID(자기 자신)라도 stale_secs보다 오래됐으면 stale
+        std::fs::write(&path, format!("{} {}", std::process::id(), now - 7200)).unwrap();
+        drop(RunLock::acquire(&path, 3600).unwrap());
+        // 살아 있고 최근이면 거부
+        std::fs::write(&path, format!("{} {now}", std::process::id())).unwrap();
+        assert!(RunLock::acquire(&path, 3600).is_err());
+        assert!(!is_stale(&format!("{} {now}", std::process::id()), 3600));
+        assert!(is_stale("", 3600));
+    }
+}
@@ -1,167 +1,360 @@
 // src/main.rs
-use anyhow::{Context, Result};
+use anyhow::{anyhow, bail, Result};
+use clap::{Parser, Subcommand};
+use std::cell::RefCell;
 use std::collections::HashSet;
-use std::time::Duration;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use futures_util::future::join_all;
+use tracing::{error, info, warn};
 
-mod notice;
-mod wevity;
-mod campuspick;
-mod dacon;
+use etc_crawler::notice::{self, Notice};
+use etc_crawler::source::{self, CollectStats, Crawler};
+use etc_crawler::{
+    archive, baseline, bench, campuspick, clock, config, csv_write, dacon, history, html_write, ics_write, json_write, labels, linkareer, lock, md_write,
+    metrics, rss_ingest, rss_merged, rss_write, schedule, store, summary, wevity,
+};
 
-mod rss_write;
-mod rss_merged;
+/// 통합 피드 채널 링크
+const MERGED_LINK: &str = "https://wuisp-rust-dev.github.io/etc-crawler";
 
-use notice::Notice;
-use tokio::time::timeout;
+#[derive(Parser, Debug)]
+#[command(name = "etc_crawler", version, about = "공모전/대외활동 RSS 크롤러")]
+struct Cli {
+    /// 설정 파일 경로(없으면 CONFIG_PATH → contest-crawler.toml)
+    #[arg(long, global = true)]
+    config: Option<String>,
+
+    /// 검증(notice::validate_notices)에 걸린 항목을 경고만 하지 않고 제외
+    #[arg(long, global = true)]
+    strict: bool,
+
+    /// 마감일이 이 날짜 이후인 항목만(YYYY-MM-DD, WINDOW_START보다 우선)
+    #[arg(long, global = true)]
+    since: Option<chrono::NaiveDate>,
+
+    /// 마감일이 이 날짜 이전인 항목만(YYYY-MM-DD, WINDOW_END보다 우선)
+    #[arg(long, global = true)]
+    until: Option<chrono::NaiveDate>,
+
+    /// DACON 키워드 필터 없이 마감 기간 안의 대회 전체 수집(DACON_ALL=1과 같음)
+    #[arg(long, global = true)]
+    dacon_all: bool,
+
+    /// robots.txt를 무시하고 수집(IGNORE_ROBOTS=1과 같음, 기본은 따름)
+    #[arg(long, global = true)]
+    ignore_robots: bool,
+
+    /// 통합 RSS/프리뷰를 마감 임박순 상위 N개로 제한(LIMIT_TOTAL과 같음, 소스별 RSS는 그대로). 1 이상
+    #[arg(long, global = true, value_parser = clap::value_parser!(u64).range(1..))]
+    limit_total: Option<u64>,
+
+    /// 로그 형식(text: 사람이 읽는 한 줄, json: 이벤트당 JSON 한 줄). 수준은 RUST_LOG(기본 info)
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+
+    /// 생략하면 crawl(전체 소스)
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum LogFormat {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// 수집 후 피드/출력 파일 생성
+    Crawl {
+        /// 수집할 소스(쉼표 구분, 생략 시 전체)
+        #[arg(long, value_delimiter = ',')]
+        source: Vec<String>,
+    },
+    /// 이미 생성된 RSS 파일들을 다시 합쳐 통합 피드 생성(수집 없음)
+    Merge {
+        #[arg(required = true)]
+        files: Vec<String>,
+        #[arg(short, long)]
+        output: String,
+    },
+    /// 저장된 페이지/응답(fixture)으로 파서 처리량 측정(네트워크 없음)
+    Bench {
+        /// fixture 디렉터리(파일 이름 규칙은 bench 모듈 참고)
+        #[arg(long, default_value = "tests/fixtures")]
+        dir: PathBuf,
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+    },
+    /// 크롤링 없이 설정만 점검(키워드 정규식/출력 디렉터리/URL/SCHEDULE). 문제가 있으면 종료 코드 1
+    CheckConfig,
+    /// SCHEDULE(cron 식)에 맞춰 crawl을 반복(프로세스를 끝낼 때까지). 시간대는 SCHEDULE_TZ
+    Daemon {
+        #[arg(long, value_delimiter = ',')]
+        source: Vec<String>,
+    },
+    /// 수집 후 콘솔에만 출력(파일 쓰기 없음)
+    Preview {
+        /// 출력 건수(생략 시 설정의 preview_n)
+        #[arg(long)]
+        limit: Option<usize>,
+        #[arg(long, value_delimiter = ',')]
+        source: Vec<String>,
+    },
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    eprintln!("[start] main");
+    let cli = Cli::parse();
+    init_logging(cli.log_format);
+    info!("start");
 
-    // ── ENV로 조절 가능한 타임아웃/프리뷰/경로
-    let to_wevity: u64     = std::env::var("TO_WEVITY").ok().and_then(|s| s.parse().ok()).unwrap_or(25);
-    let to_campuspick: u64 = std::env::var("TO_CAMPUS").ok().and_then(|s| s.parse().ok()).unwrap_or(25);
-    let to_dacon: u64      = std::env::var("TO_DACON").ok().and_then(|s| s.parse().ok()).unwrap_or(25);
-    let preview_n: usize   = std::env::var("PREVIEW_N").ok().and_then(|s| s.parse().ok()).unwrap_or(30);
+    // ── 설정: --config / CONFIG_PATH / contest-crawler.toml (없으면 기본값, env가 파일보다 우선)
+    let config_path = cli.config.clone()
+        .or_else(|| std::env::var("CONFIG_PATH").ok())
+        .unwrap_or_else(|| config::DEFAULT_CONFIG_PATH.to_string());
+    if matches!(cli.command, Some(Command::CheckConfig)) {
+        std::process::exit(check_config(&config_path));
+    }
+    let mut cfg = config::Config::load(&config_path)?;
+    if let Some(d) = cfg.fake_today {
+        clock::fix_today(d);
+    }
+    if cli.dacon_all {
+        cfg.dacon.all_keywords = true;
+    }
+    if cli.ignore_robots {
+        cfg.ignore_robots();
+    }
+    if let Some(n) = cli.limit_total {
+        cfg.limit_total = Some(n as usize);
+    }
+    if cli.since.is_some() {
+        cfg.window_start = cli.since;
+    }
+    if cli.until.is_some() {
+        cfg.window_end = cli.until;
+    }
 
-    // RSS 출력 경로(없으면 etc-rss 밑으로)
-    let out_dir = std::env::var("RSS_DIR").unwrap_or_else(|_| "etc-rss".into());
-    let p_wevity   = std::env::var("RSS_WEVITY").unwrap_or_else(|_| format!("{out_dir}/wevity_rss.xml"));
-    let p_campus   = std::env::var("RSS_CAMPUS").unwrap_or_else(|_| format!("{out_dir}/campus_pick_rss.xml"));
-    let p_dacon    = std::env::var("RSS_DACON").unwrap_or_else(|_| format!("{out_dir}/dacon_rss.xml"));
-    let p_merged   = std::env::var("RSS_MERGED").unwrap_or_else(|_| format!("{out_dir}/merged_rss.xml"));
-
-    // ── 1) wevity: 공모전/대외활동 동시에 + 개별 타임아웃
-    let wevity_fut = async {
-        eprintln!("[wevity] fetching…");
-        let (c_res, a_res) = tokio::join!(
-            timeout(Duration::from_secs(to_wevity), wevity::scrape_wevity_contests()),
-            timeout(Duration::from_secs(to_wevity), wevity::scrape_wevity_activities()),
-        );
-        let contests   = c_res.context("wevity contests timeout")??;
-        let activities = a_res.context("wevity activities timeout")??;
-
-        let mut out: Vec<Notice> = Vec::with_capacity(contests.len() + activities.len());
-        out.extend(contests.iter().map(wevity::to_notice_from_wevity));
-        out.extend(activities.iter().map(wevity::to_notice_from_wevity));
-        Ok::<Vec<Notice>, anyhow::Error>(out)
-    };
+    let mut code = 0;
+    match cli.command.unwrap_or(Command::Crawl { source: Vec::new() }) {
+        Command::Crawl { source } => {
+            let _lock = acquire_lock(&cfg.lock)?;
+            code = crawl(&cfg, &source, true, cfg.preview_n, cli.strict).await?;
+        }
+        Command::Daemon { source } => {
+            let Some(expr) = cfg.schedule.as_deref() else {
+                bail!("daemon needs SCHEDULE (cron expression, e.g. \"0 8-23/2 * * *\")");
+            };
+            let plan = schedule::Schedule::parse(expr).map_err(|e| anyhow!("SCHEDULE {expr:?}: {e:#}"))?;
+            let tz = schedule::parse_offset(cfg.schedule_tz.as_deref())?;
+            info!(schedule = expr, tz = %tz, "daemon started");
+            let (cfg, source, strict) = (&cfg, &source, cli.strict);
+            // 종료는 SIGINT/SIGTERM 기본 동작(남은 잠금 파일은 lock.stale_secs가 지나면 다시 잡힘)
+            schedule::run_loop(&plan, tz, std::future::pending(), move || async move {
+                let _lock = acquire_lock(&cfg.lock)?;
+                let code = crawl(cfg, source, true, cfg.preview_n, strict).await?;
+                info!(code, "scheduled crawl finished");
+                Ok(())
+            })
+            .await?;
+        }
+        Command::Preview { limit, source } => {
+            code = crawl(&cfg, &source, false, limit.unwrap_or(cfg.preview_n), cli.strict).await?;
+        }
+        Command::Bench { dir, iterations } => {
+            let r = bench::run(&dir, iterations)?;
+            println!(
+                "{} files x {} iterations: {} items in {:.3}s ({:.0} items/sec)",
+                r.files, r.iterations, r.items, r.elapsed.as_secs_f64(), r.items_per_sec()
+            );
+        }
+        Command::CheckConfig => unreachable!("handled before loading the config"),
+        Command::Merge { files, output } => {
+            let _lock = acquire_lock(&cfg.lock)?;
+            let (title, desc) = labels::channel_meta("merged", cfg.feed.lang);
+            let n = rss_merged::merge_feed_files(&files, title, MERGED_LINK, desc, &output, &cfg.feed)?;
+            info!(items = n, files = files.len(), output = %output, "merged feed files");
+        }
+    }
 
-    // ── 2) campuspick: async → timeout
-    let campuspick_fut = async {
-        eprintln!("[campuspick] fetching…");
-        let rows = timeout(Duration::from_secs(to_campuspick), campuspick::collect())
-            .await
-            .context("campuspick timeout")??;
-        let notices = rows
-            .iter()
-            .map(campuspick::to_notice_from_campuspick)
-            .collect::<Vec<_>>();
-        Ok::<Vec<Notice>, anyhow::Error>(notices)
-    };
+    info!(code, "done");
+    if code != 0 {
+        std::process::exit(code);
+    }
+    Ok(())
+}
 
-    // ── 3) dacon: blocking → spawn_blocking + timeout
-    let dacon_fut = async {
-        use tokio::task::JoinHandle;
-        eprintln!("[dacon] fetching…");
-
-        let join: JoinHandle<anyhow::Result<Vec<dacon::Item>>> =
-            tokio::task::spawn_blocking(dacon::collect);
-
-        let join_out = timeout(Duration::from_secs(to_dacon), join)
-            .await
-            .map_err(|_| anyhow::anyhow!("dacon timeout"))?;
-
-        let rows: Vec<dacon::Item> = match join_out {
-            Ok(Ok(v))  => v,
-            Ok(Err(e)) => return Err(e),
-            Err(e)     => return Err(anyhow::Error::new(e)),
-        };
-
-        let notices = rows
-            .iter()
-            .map(dacon::to_notice_from_dacon)
-            .collect::<Vec<_>>();
-        Ok::<Vec<Notice>, anyhow::Error>(notices)
+/// check-config: 설정을 읽고 Config::validate 결과를 출력. 문제가 없으면 0, 있으면 1
+/// 파일 형식/프록시/헤더 오류(load 실패)도 크롤링 때처럼 멈추지 않고 보고에 포함
+fn check_config(path: &str) -> i32 {
+    let problems = match config::Config::load(path) {
+        Ok(cfg) => cfg.validate(),
+        Err(e) => vec![format!("{e:#}")],
     };
+    if problems.is_empty() {
+        println!("config OK: {path}");
+        return 0;
+    }
+    println!("config {path}: {} problem(s)", problems.len());
+    for p in &problems {
+        println!("  - {p}");
+    }
+    1
+}
+
+/// tracing 구독자 설치: stderr로, RUST_LOG(없으면 info) 수준 필터
+/// 콘솔 프리뷰(println!)는 stdout 그대로라 목록만 파이프로 넘길 수 있음
+fn init_logging(format: LogFormat) {
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    let builder = tracing_subscriber::fmt().with_env_filter(filter).with_writer(std::io::stderr);
+    match format {
+        LogFormat::Text => builder.init(),
+        LogFormat::Json => builder.json().init(),
+    }
+}
+
+/// (옵션) 실행 잠금: 겹친 실행이 출력 파일을 덮어쓰지 않도록(반환값 drop 시 해제)
+fn acquire_lock(cfg: &config::LockConfig) -> Result<Option<lock::RunLock>> {
+    match &cfg.file {
+        Some(path) => Ok(Some(lock::RunLock::acquire(path, cfg.stale_secs)?)),
+        None => Ok(None),
+    }
+}
 
-    // ── 4) 병렬 수집(부분 성공 허용)
-    let (wevity_v, campuspick_v, dacon_v) = tokio::join!(wevity_fut, campuspick_fut, dacon_fut);
+/// 수집 → 정리 → (write면) 파일 생성 → 콘솔 프리뷰
+/// selected가 비어 있으면 전체 소스, 아니면 이름이 일치하는 소스만 실행
+/// strict면 검증에 걸린 항목을 제외(아니면 경고만)하고, 일부 소스 실패도 종료 코드 1로
+/// 반환값은 종료 코드(metrics::exit_code)
+async fn crawl(cfg: &config::Config, selected: &[String], write: bool, preview_n: usize, strict: bool) -> Result<i32> {
+    let started = Instant::now();
+    // RSS 출력 경로(없으면 etc-rss 밑으로)
+    let out_dir = PathBuf::from(&cfg.output.dir);
+    let p_merged   = cfg.output.path("merged");
+    let p_archive  = cfg.output.path("archive");
 
-    let wevity_v     = wevity_v.unwrap_or_else(|e| { eprintln!("[wevity] skipped: {e:#}"); Vec::new() });
-    let campuspick_v = campuspick_v.unwrap_or_else(|e| { eprintln!("[campuspick] skipped: {e:#}"); Vec::new() });
-    let dacon_v      = dacon_v.unwrap_or_else(|e| { eprintln!("[dacon] skipped: {e:#}"); Vec::new() });
+    // 피드/통합 옵션
+    let feed_opts = &cfg.feed;
+    let merge_opts = &cfg.merge;
 
-    // ── 5) (옵션) 개별 RSS 파일 생성
-    std::fs::create_dir_all(&out_dir).ok();
+    // ── 1) 수집 소스 목록
+    let mut crawlers: Vec<Box<dyn Crawler>> = vec![
+        Box::new(wevity::WevityCrawler::new(cfg.wevity.clone(), cfg.timeouts.get("wevity"))),
+        Box::new(campuspick::CampuspickCrawler { config: cfg.campuspick.clone(), timeout: cfg.timeouts.get("campuspick") }),
+        Box::new(dacon::DaconCrawler { opts: cfg.dacon.clone(), timeout: cfg.timeouts.get("dacon") }),
+        Box::new(linkareer::LinkareerCrawler { opts: cfg.linkareer.clone(), timeout: cfg.timeouts.get("linkareer") }),
+    ];
+    for feed in &cfg.ingest {
+        let timeout = cfg.timeouts.get(&feed.label);
+        crawlers.push(Box::new(rss_ingest::RssIngestCrawler { feed: feed.clone(), timeout, http: cfg.http.clone() }));
+    }
+    if let Some(unknown) = selected.iter().find(|s| !crawlers.iter().any(|c| c.name() == s.as_str())) {
+        bail!("unknown source: {unknown}");
+    }
+    let crawlers: Vec<Box<dyn Crawler>> = crawlers
+        .into_iter()
+        .filter(|c| selected.is_empty() || selected.iter().any(|s| s == c.name()))
+        .collect();
 
-    if !wevity_v.is_empty() {
-        if let Err(e) = rss_write::write_rss_feed(
-            &wevity_v,
-            "Wevity RSS",
-            "https://www.wevity.com",
-            "위비티 공모전/대외활동",
-            &p_wevity,
-        ) {
-            eprintln!("[rss_write] wevity failed: {e:?}");
+    // ── 2) 병렬 수집(소스별 타임아웃 + 전체 예산, 부분 성공 허용)
+    // 전체 예산이 끝나도 그때까지 끝난 소스 결과는 slots에 남아 있음
+    let budget = cfg.timeouts.total_budget();
+    let slots: RefCell<Vec<Option<SourceResult>>> = RefCell::new((0..crawlers.len()).map(|_| None).collect());
+    // 소스별 필터 전 건수/상세 실패 카운터(타임아웃으로 끊겨도 그때까지 값은 남음)
+    let stats: Vec<CollectStats> = crawlers.iter().map(|_| CollectStats::default()).collect();
+    let all_done = join_all(crawlers.iter().zip(&stats).enumerate().map(|(i, (c, st))| {
+        let slots = &slots;
+        async move {
+            let out = timed(source::run(c.as_ref(), st)).await;
+            slots.borrow_mut()[i] = Some(out);
         }
+    }));
+    if tokio::time::timeout(budget, all_done).await.is_err() {
+        warn!(budget_secs = budget.as_secs(), "total budget exceeded; keeping finished sources");
     }
-    if !campuspick_v.is_empty() {
-        if let Err(e) = rss_write::write_rss_feed(
-            &campuspick_v,
-            "Campuspick RSS",
-            "https://www.campuspick.com",
-            "캠퍼스픽 대외활동",
-            &p_campus,
-        ) {
-            eprintln!("[rss_write] campuspick failed: {e:?}");
+    // (결과, 소요 시간, 타임아웃 여부): 소스 타임아웃(Elapsed) 또는 전체 예산 초과
+    let results = slots.into_inner().into_iter().map(|slot| match slot {
+        Some((res, t)) => {
+            let timed_out = res.as_ref().err().is_some_and(|e| e.is::<tokio::time::error::Elapsed>());
+            (res, t, timed_out)
         }
+        None => (Err(anyhow!("total budget exceeded")), budget, true),
+    });
+
+    let mut per_source: Vec<Vec<Notice>> = Vec::with_capacity(crawlers.len());
+    let mut source_err: Vec<Option<String>> = Vec::with_capacity(crawlers.len());
+    let mut durations: Vec<(Duration, bool)> = Vec::with_capacity(crawlers.len());
+    for (c, (res, t, timed_out)) in crawlers.iter().zip(results) {
+        source_err.push(res.as_ref().err().map(|e| format!("{e:#}")));
+        durations.push((t, timed_out));
+        per_source.push(res.unwrap_or_else(|e| { warn!(source = c.name(), error = %format!("{e:#}"), "source skipped"); Vec::new() }));
     }
-    if !dacon_v.is_empty() {
-        if let Err(e) = rss_write::write_rss_feed(
-            &dacon_v,
-            "DACON RSS",
-            "https://www.dacon.io",
-            "데이콘 대회",
-            &p_dacon,
-        ) {
-            eprintln!("[rss_write] dacon failed: {e:?}");
-        }
-    }
-
-    // ── URL 정규화 함수
-    fn normalize_url(url: &str) -> String {
-        if let Some((base, _)) = url.split_once("&gp=") {
-            base.to_string()
-        } else if let Some((base, _)) = url.split_once("?gp=") {
-            base.to_string()
-        } else {
-            url.to_string()
-        }
-    }
-
-    // ── 6) 통합용 벡터 만들기 + 중복 제거 + 정렬
-    let mut all: Vec<Notice> =
-        Vec::with_capacity(wevity_v.len() + campuspick_v.len() + dacon_v.len());
-    all.extend(wevity_v.clone());
-    all.extend(campuspick_v.clone());
-    all.extend(dacon_v.clone());
-
-    // 1차: URL 기준 중복 제거 (같은 플랫폼 내부 중복 제거)
-    let mut seen_url = HashSet::new();
-    all.retain(|n| seen_url.insert(normalize_url(&n.url)));
-
-    // 2차: 플랫폼 간 중복 제거 (title + 기간 기준)
-    let mut seen_cross = HashSet::new();
-    all.retain(|n| {
-        let key = format!(
-            "{}|{}-{}",
-            n.title.trim().to_lowercase(),
-            n.start.as_ref().map(|d| d.to_string()).unwrap_or_default(),
-            n.end.as_ref().map(|d| d.to_string()).unwrap_or_default()
-        );
-    seen_cross.insert(key)
-});
+
+    // 수상한 항목(빈 제목, http가 아닌 URL, 잘못된/역전된 날짜) 경고, --strict면 제외
+    for (c, v) in crawlers.iter().zip(&mut per_source) {
+        let issues = notice::validate_notices(v);
+        for (i, reason) in &issues {
+            warn!(source = c.name(), index = i, title = %v[*i].title, reason = %reason, "suspicious notice");
+        }
+        if strict && !issues.is_empty() {
+            let bad: HashSet<usize> = issues.iter().map(|(i, _)| *i).collect();
+            let mut idx = 0usize;
+            v.retain(|_| { let keep = !bad.contains(&idx); idx += 1; keep });
+            warn!(source = c.name(), dropped = bad.len(), "dropped suspicious notices (--strict)");
+        }
+    }
+
+    // ── (옵션) ARCHIVE=1: 지난 마감까지 포함한 원본은 아카이브용으로 두고, 일반 피드에서는 제외
+    let today = clock::today();
+    let archive_src = cfg.archive.then(|| per_source.clone());
+    if archive_src.is_some() {
+        for v in &mut per_source {
+            v.retain(|n| !notice::is_expired(n, today));
+        }
+    }
+
+    // 소스 내부 중복(같은 URL)은 개별 피드에서도 빠지도록 먼저 제거
+    for v in &mut per_source {
+        notice::dedup_by_url(v, &cfg.url_strip_params);
+    }
+
+    // (옵션) 상시 모집처럼 기간이 너무 긴 항목 제외(MAX_DURATION_DAYS)
+    if let Some(max_days) = cfg.max_duration_days {
+        for v in &mut per_source {
+            notice::drop_long_running(v, max_days);
+        }
+    }
+
+    // (옵션) 마감일 창 --since/--until(WINDOW_START/WINDOW_END). 창을 지정하면 마감일 없는 항목은 제외
+    if cfg.window_start.is_some() || cfg.window_end.is_some() {
+        for v in &mut per_source {
+            notice::retain_in_window(v, cfg.window_start, cfg.window_end);
+        }
+    }
+
+    // (옵션) SQLite 변경 이력: 내용이 바뀐 항목만 changed_at(pubDate/Atom updated)을 새로 찍음. preview는 DB를 건드리지 않음
+    if write && let Some(db_path) = &cfg.output.sqlite {
+        let now = chrono::Utc::now();
+        let tracked = store::open_db(db_path).and_then(|mut conn| {
+            per_source.iter_mut().try_fold(0, |acc, v| Ok(acc + store::track_changes(&mut conn, v, &cfg.url_strip_params, now)?))
+        });
+        match tracked {
+            Ok(n) => info!(changed = n, "tracked item changes"),
+            Err(e) => warn!(error = %format!("{e:#}"), "change tracking skipped"),
+        }
+    }
+
+    // ── 2-1) 통합용 벡터 만들기 + 중복 제거 + 정렬
+    let mut all: Vec<Notice> = per_source.iter().flatten().cloned().collect();
+
+    // 1차: URL 기준(같은 플랫폼 내부) → 2차: 플랫폼 간(title + 기간)
+    let dedup = summary::DedupCounts::dedup(&mut all, &cfg.url_strip_params);
+    for ((a, b), cnt) in &dedup.pairs {
+        info!(pair = %format!("{a}↔{b}"), duplicates = cnt, "cross-source duplicates");
+    }
+
     // 정렬
     all.sort_by(|a, b| {
         a.start.is_none().cmp(&b.start.is_none())
@@ -170,23 +363,250 @@ async fn main() -> Result<()> {
             .then(a.title.cmp(&b.title))
     });
 
-    // ── 7) 통합 RSS 파일 생성
-    if let Err(e) = rss_merged::write_merged_rss(
-        vec![wevity_v, campuspick_v, dacon_v],
-        "통합 공모전·대외활동 RSS",
-        "https://wuisp-rust-dev.github.io/etc-crawler", 
-        "모든 소식 통합",
-        &p_merged,
-    ) {
-        eprintln!("[rss_merged] failed: {e:?}");
+    // (옵션) SQLite 이력이 있으면 회차만 다른 반복 공모전을 series_id로 묶음
+    if let Some(db_path) = &cfg.output.sqlite
+        && std::path::Path::new(db_path).exists() {
+        match store::open_db(db_path).and_then(|conn| store::assign_series(&conn, &mut all, &cfg.url_strip_params)) {
+            Ok(n) => info!(recurring = n, "matched contest series"),
+            Err(e) => warn!(error = %format!("{e:#}"), "series detection skipped"),
+        }
+    }
+
+    let sources: Vec<metrics::SourceMetrics> = crawlers
+        .iter()
+        .zip(&per_source)
+        .zip(&stats)
+        .zip(source_err.into_iter().zip(durations))
+        .map(|(((c, v), st), (error, (duration, timed_out)))| metrics::SourceMetrics {
+            name: c.name().to_string(),
+            raw_items: st.raw_items(),
+            items: v.len(),
+            detail_failures: st.detail_failures(),
+            robots_blocked: st.robots_blocked(),
+            http_requests: st.http_requests(),
+            bytes_fetched: st.bytes_fetched(),
+            budget: st.budget(),
+            duration,
+            timed_out,
+            error,
+        })
+        .collect();
+
+    // 통합 RSS/프리뷰용 상위 N개(limit_total). 나머지 출력은 전체 목록 그대로
+    let top: Option<Vec<Notice>> = cfg.limit_total.map(|n| rss_merged::nearest_deadline(&all, n));
+    if let Some(top) = &top
+        && top.len() < all.len() {
+        info!(kept = top.len(), total = all.len(), "limited merged output by nearest deadline");
     }
 
-    // ── 8) 콘솔 프리뷰
-    println!("[Merged Notices: {} items]\n", all.len());
-    for n in all.iter().take(preview_n) {
+    // ── 3) 파일 출력(preview에서는 생략)
+    let mut merged_ok = true;
+    if write {
+        // ── (옵션) 수집 원본 JSON(소스 간 중복 제거 전, 다른 도구 입력용)
+        if let Some(json_path) = &cfg.output.json {
+            let collected: Vec<Notice> = per_source.iter().flatten().cloned().collect();
+            if let Err(e) = json_write::write_json(&collected, json_path) {
+                error!(output = "json", error = %format!("{e:#}"), "write failed");
+            }
+        }
+
+        // ── 3-1) (옵션) 개별 RSS 파일 생성
+        let lang = feed_opts.lang;
+        std::fs::create_dir_all(&out_dir).ok();
+
+        for (c, v) in crawlers.iter().zip(&per_source) {
+            if v.is_empty() || !c.own_feed() {
+                continue;
+            }
+            let (title, desc) = labels::channel_meta(c.name(), lang);
+            if let Err(e) = rss_write::write_feeds(v, title, c.channel_link(cfg.output.list_link), desc, &cfg.output.path(c.name()), feed_opts) {
+                error!(output = "rss", source = c.name(), error = %format!("{e:#}"), "write failed");
+            }
+        }
+
+        // ── 3-2) 통합 RSS 파일 생성
+        if let Err(e) = rss_merged::write_merged_rss(
+            top.clone().map(|t| vec![t]).unwrap_or(per_source),
+            labels::channel_meta("merged", lang).0,
+            MERGED_LINK,
+            labels::channel_meta("merged", lang).1,
+            &p_merged,
+            merge_opts,
+            feed_opts,
+        ) {
+            error!(output = "merged", error = %format!("{e:#}"), "write failed");
+            merged_ok = false;
+        }
+
+        // ── (옵션) 분야 태그 프로필 피드
+        for profile in &cfg.profiles {
+            let picked: Vec<Notice> = all.iter().filter(|n| profile.matches(n)).cloned().collect();
+            let title = format!("{} - {}", labels::channel_meta("merged", lang).0, profile.name);
+            let path = cfg.output.path(&format!("field_{}", profile.name));
+            if let Err(e) = rss_write::write_feeds(&picked, &title, MERGED_LINK, labels::channel_meta("merged", lang).1, &path, feed_opts) {
+                error!(output = "rss", profile = %profile.name, error = %format!("{e:#}"), "write failed");
+            }
+        }
+
+        // ── (옵션) 통합 JSON
+        if let Some(json_path) = &cfg.output.json_merged
+            && let Err(e) = json_write::write_json_feed(&all, json_path) {
+            error!(output = "json", error = %format!("{e:#}"), "write failed");
+        }
+        if let Some(json_path) = &cfg.output.json_grouped
+            && let Err(e) = json_write::write_grouped_json(&all, json_path, &feed_opts.generator) {
+            error!(output = "json", error = %format!("{e:#}"), "write failed");
+        }
+
+        // ── (옵션) 통합 CSV
+        if let Some(csv_path) = &cfg.output.csv
+            && let Err(e) = csv_write::write_csv(&all, csv_path) {
+            error!(output = "csv", error = %format!("{e:#}"), "write failed");
+        }
+
+        // ── (옵션) 통합 iCalendar(마감일 일정)
+        if let Some(ics_path) = &cfg.output.ics
+            && let Err(e) = ics_write::write_ics(&all, ics_path, &cfg.url_strip_params) {
+            error!(output = "ics", error = %format!("{e:#}"), "write failed");
+        }
+
+        // ── (옵션) HTML 대시보드(GitHub Pages용 index.html)
+        if let Some(html_path) = &cfg.output.html
+            && let Err(e) = html_write::write_html(&all, html_path, labels::channel_meta("merged", feed_opts.lang).0, feed_opts.lang) {
+            error!(output = "html", error = %format!("{e:#}"), "write failed");
+        }
+
+        // ── (옵션) Markdown 주간 요약(노션/README 붙여 넣기용)
+        if let Some(md_path) = &cfg.output.markdown
+            && let Err(e) = md_write::write_markdown(&all, md_path, &cfg.markdown, feed_opts.lang, today) {
+            error!(output = "markdown", error = %format!("{e:#}"), "write failed");
+        }
+
+        // ── (옵션) 아카이브 RSS(지난 마감은 expired 카테고리)
+        if let Some(src) = archive_src {
+            let archived = rss_merged::merge_notices(src, merge_opts.sort, &cfg.url_strip_params);
+            let (title, desc) = labels::channel_meta("archive", feed_opts.lang);
+            if let Err(e) = rss_write::write_archive_feed(
+                &archived,
+                title,
+                MERGED_LINK,
+                desc,
+                &p_archive,
+                feed_opts,
+            ) {
+                error!(output = "archive", error = %format!("{e:#}"), "write failed");
+            }
+        }
+
+        // ── (옵션) 마감이 지난 공고 월별 아카이브(JSONL + archive.html)
+        if let Some(dir) = &cfg.output.archive_dir
+            && let Err(e) = archive::update(dir, &all, today, &cfg.url_strip_params, feed_opts.lang) {
+            error!(output = "archive_dir", error = %format!("{e:#}"), "write failed");
+        }
+
+        // ── (옵션) Prometheus textfile 메트릭
+        if let Some(prom_path) = &cfg.output.prom_textfile {
+            // _total 카운터는 지난번 파일 값에 누적(파일이 없으면 0부터)
+            let previous = std::fs::read_to_string(prom_path).map(|b| metrics::previous_counters(&b)).unwrap_or_default();
+            let body = metrics::render_textfile(&sources, all.len(), chrono::Utc::now().timestamp(), &previous);
+            if let Err(e) = metrics::write_textfile(prom_path, &body) {
+                error!(output = "metrics", error = %format!("{e:#}"), "write failed");
+            }
+        }
+
+        // ── (옵션) 소스별 기준 건수 대비 급감 경고
+        let mut health_warnings: Vec<String> = Vec::new();
+        if let Some(baseline_path) = &cfg.baseline.file {
+            let mut base = baseline::Baseline::load(baseline_path);
+            for s in &sources {
+                if let Some(w) = base.check(&s.name, s.items, cfg.baseline.drop_ratio) {
+                    warn!(warning = %w, "item count dropped below baseline");
+                    health_warnings.push(w);
+                }
+                base.record(&s.name, s.items, cfg.baseline.runs);
+            }
+            if let Err(e) = base.save(baseline_path) {
+                error!(output = "baseline", error = %format!("{e:#}"), "write failed");
+            }
+        }
+
+        // ── (옵션) SQLite 누적 저장(정규화 URL 기준 upsert)
+        if let Some(db_path) = &cfg.output.sqlite {
+            match store::open_db(db_path).and_then(|mut conn| store::upsert(&mut conn, &all, &cfg.url_strip_params)) {
+                Ok(n) => info!(upserted = n, db = %db_path, "stored notices"),
+                Err(e) => error!(output = "store", error = %format!("{e:#}"), "write failed"),
+            }
+        }
+
+        // ── 실행 요약 JSON(RUN_SUMMARY_PATH, 기본 출력 디렉터리/run_summary.json)
+        let summary_path = cfg.output.run_summary_path();
+        let run_summary = metrics::RunSummary::new(&sources, &dedup, all.len(), started.elapsed());
+        if let Err(e) = run_summary.write(&summary_path) {
+            error!(output = "run_summary", error = %format!("{e:#}"), "write failed");
+        }
+
+        // ── (옵션) 날짜별 사본(ARCHIVE_DIR/YYYY-MM-DD/), ARCHIVE_KEEP_DAYS보다 오래된 날짜는 삭제
+        if let Some(archive_dir) = &cfg.history.dir {
+            let keep_days = cfg.history.keep_days;
+            match history::snapshot(&cfg.output_files(), archive_dir, today) {
+                Ok(n) => info!(files = n, dir = %archive_dir, date = %today, "archived outputs"),
+                Err(e) => error!(output = "history", error = %format!("{e:#}"), "snapshot failed"),
+            }
+            match history::prune(archive_dir, keep_days, today) {
+                Ok(0) => {}
+                Ok(n) => info!(pruned = n, keep_days, "pruned old snapshots"),
+                Err(e) => error!(output = "history", error = %format!("{e:#}"), "prune failed"),
+            }
+        }
+
+        // ── (옵션) 실행 통계 JSON(대시보드 수집용)
+        if let Some(stats_path) = &cfg.output.stats {
+            let stats = metrics::RunStats::new(&sources, all.len(), started.elapsed());
+            if let Err(e) = stats.write(stats_path) {
+                error!(output = "stats", error = %format!("{e:#}"), "write failed");
+            }
+        }
+
+        // ── (옵션) GitHub Actions job summary
+        if let Some(summary_path) = &cfg.output.step_summary {
+            let body = summary::render_step_summary(&sources, &dedup, &health_warnings, &all, today);
+            if let Err(e) = summary::append_step_summary(summary_path, &body) {
+                error!(output = "step_summary", error = %format!("{e:#}"), "write failed");
+            }
+        }
+    }
+
+    // ── 4) 콘솔 프리뷰
+    let shown = top.as_deref().unwrap_or(&all);
+    println!("[Merged Notices: {} items]\n", shown.len());
+    for n in shown.iter().take(preview_n) {
         println!("- {}", n);
     }
 
-    eprintln!("[done]");
-    Ok(())
+    info!(result = %metrics::outcome_line(&sources), "run finished");
+    Ok(metrics::exit_code(&sources, all.len(), merged_ok, strict))
+}
+
+/// 소스 한 개의 수집 결과 + 소요 시간
+type SourceResult = (Result<Vec<Notice>>, Duration);
+
+/// future 실행 시간을 함께 반환
+async fn timed<T>(fut: impl std::future::Future<Output = T>) -> (T, Duration) {
+    let started = Instant::now();
+    let out = fut.await;
+    (out, started.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_total_must_be_positive() {
+        let cli = Cli::try_parse_from(["etc_crawler", "--limit-total", "20"]).unwrap();
+        assert_eq!(cli.limit_total, Some(20));
+        // LIMIT_TOTAL=0이 무시되는 것과 맞추어 0은 거부(통합 피드가 비지 않게)
+        assert!(Cli::try_parse_from(["etc_crawler", "--limit-total", "0"]).is_err());
+        assert_eq!(Cli::try_parse_from(["etc_crawler"]).unwrap().limit_total, None);
+    }
 }
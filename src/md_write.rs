@@ -0,0 +1,181 @@
+// src/md_write.rs
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Deserialize;
+use std::fmt::Write as _;
+
+use crate::atomic_write::write_atomic;
+use crate::dates;
+use crate::labels::{self, Lang};
+use crate::notice::{Kind, Notice};
+
+/// 묶는 기준(MD_GROUP_BY=kind|week, 기본 week)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    /// 공모전 / 대외활동 / 교육 / 채용
+    Kind,
+    /// 마감 주(이번 주 / 다음 주 / 그 이후 / 마감일 미정)
+    Week,
+}
+
+/// Markdown 요약 옵션([markdown], env는 Config::apply_env에서 MD_GROUP_BY / MD_CHECKBOX)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MarkdownOptions {
+    pub group_by: GroupBy,
+    /// 항목 앞에 "- [ ]" 체크박스(끄면 "- ")
+    pub checkboxes: bool,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self { group_by: GroupBy::Week, checkboxes: true }
+    }
+}
+
+/// 노션/README에 붙여 넣을 주간 요약 Markdown(순수 함수, today는 호출자가 지정)
+/// 항목: "- [ ] [제목](URL) — 주최 · ~YYYY-MM-DD (D-n) · 소스"
+/// 그룹 헤더: "## 이번 주 마감 (3건)"(lang에 따라 labels::week_groups/kind_label). 빈 그룹은 생략, 그룹 안은 입력 순서 유지
+pub fn render_markdown(notices: &[Notice], opts: &MarkdownOptions, lang: Lang, today: NaiveDate) -> String {
+    let groups: Vec<(&str, Vec<&Notice>)> = match opts.group_by {
+        GroupBy::Kind => [Kind::Contest, Kind::Activity, Kind::Education, Kind::Recruit]
+            .iter()
+            .map(|k| (labels::kind_label(k, lang), notices.iter().filter(|n| &n.kind == k).collect()))
+            .collect(),
+        GroupBy::Week => {
+            let mut buckets: [(&str, Vec<&Notice>); 5] = labels::week_groups(lang).map(|name| (name, Vec::new()));
+            for n in notices {
+                buckets[week_bucket(n, today)].1.push(n);
+            }
+            buckets.into_iter().collect()
+        }
+    };
+
+    let mut out = String::new();
+    for (header, items) in groups.iter().filter(|(_, v)| !v.is_empty()) {
+        let _ = writeln!(out, "## {header} {}\n", labels::count(items.len(), lang));
+        for n in items {
+            let _ = writeln!(out, "{}", item_line(n, opts, today));
+        }
+        let _ = writeln!(out);
+    }
+    out
+}
+
+/// render_markdown 결과를 파일로 저장
+pub fn write_markdown(notices: &[Notice], path: &str, opts: &MarkdownOptions, lang: Lang, today: NaiveDate) -> Result<()> {
+    write_atomic(path, render_markdown(notices, opts, lang, today).as_bytes())
+}
+
+/// 0: 이번 주, 1: 다음 주, 2: 그 이후, 3: 마감일 미정, 4: 지난 마감 (주는 월요일 시작)
+fn week_bucket(n: &Notice, today: NaiveDate) -> usize {
+    let Some(end) = n.end.as_deref().and_then(dates::parse_ymd) else { return 3 };
+    if end < today {
+        return 4;
+    }
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let weeks = (end - monday).num_days() / 7;
+    (weeks as usize).min(2)
+}
+
+fn item_line(n: &Notice, opts: &MarkdownOptions, today: NaiveDate) -> String {
+    let title = escape(&n.title);
+    let head = if n.url.trim().is_empty() { title } else { format!("[{title}]({})", n.url.replace(' ', "%20").replace(')', "%29")) };
+
+    let mut meta: Vec<String> = Vec::new();
+    if let Some(org) = n.organizer.as_deref() {
+        meta.push(escape(org));
+    }
+    if let Some(end) = n.end.as_deref() {
+        match dates::days_until(end, today) {
+            Some(d) if d >= 0 => meta.push(format!("~{end} (D-{d})")),
+            Some(d) => meta.push(format!("~{end} (D+{})", -d)),
+            None => meta.push(format!("~{end}")),
+        }
+    }
+    meta.push(n.source.name().to_string());
+
+    let bullet = if opts.checkboxes { "- [ ] " } else { "- " };
+    format!("{bullet}{head} — {}", meta.join(" · "))
+}
+
+/// 링크 텍스트가 깨지지 않도록 Markdown 특수문자 이스케이프
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '[' | ']' | '\\' | '*' | '_' | '`') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::Source;
+
+    fn notice(title: &str, kind: Kind, end: Option<&str>) -> Notice {
+        Notice {
+            source: Source::Wevity,
+            kind,
+            title: title.into(),
+            raw_title: title.into(),
+            url: "https://www.wevity.com/?c=find&ix=1".into(),
+            start: None,
+            end: end.map(str::to_string),
+            organizer: Some("과기부".into()),
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 14).unwrap()
+    }
+
+    #[test]
+    fn groups_by_deadline_week() {
+        let list = [
+            notice("이번 주", Kind::Contest, Some("2026-10-18")),
+            notice("다음 주", Kind::Contest, Some("2026-10-19")),
+            notice("미정", Kind::Activity, None),
+            notice("지남", Kind::Contest, Some("2026-10-13")),
+        ];
+        let md = render_markdown(&list, &MarkdownOptions::default(), Lang::Ko, today());
+        let headers: Vec<&str> = md.lines().filter(|l| l.starts_with("## ")).collect();
+        assert_eq!(headers, ["## 이번 주 마감 (1건)", "## 다음 주 마감 (1건)", "## 마감일 미정 (1건)", "## 지난 마감 (1건)"]);
+        assert!(md.contains("- [ ] [이번 주](https://www.wevity.com/?c=find&ix=1) — 과기부 · ~2026-10-18 (D-4) · Wevity"));
+        assert!(md.contains("~2026-10-13 (D+1)"));
+    }
+
+    #[test]
+    fn english_labels_and_kind_grouping() {
+        let list = [notice("Hack [AI]", Kind::Education, Some("2026-10-30"))];
+        let opts = MarkdownOptions { group_by: GroupBy::Kind, checkboxes: false };
+        let md = render_markdown(&list, &opts, Lang::En, today());
+        assert!(md.starts_with("## Education (1)\n"), "{md}");
+        assert!(md.contains("- [Hack \\[AI\\]]("));
+
+        let week = render_markdown(&list, &MarkdownOptions::default(), Lang::En, today());
+        assert!(week.starts_with("## Due later (1)\n"), "{week}");
+    }
+
+    #[test]
+    fn digest_snapshot_with_fixed_today() {
+        let list = [
+            Notice { organizer: Some("과기부 / NIA".into()), ..notice("AI 아이디어 공모전", Kind::Contest, Some("2026-10-16")) },
+            Notice { source: Source::Dacon, organizer: None, ..notice("수요 예측 경진대회", Kind::Contest, Some("2026-10-14")) },
+            Notice { end_approx: true, ..notice("IT 서포터즈 모집", Kind::Activity, Some("2026-10-21")) },
+            notice("부트캠프 *3기*", Kind::Education, Some("2026-11-20")),
+            notice("상시 멘토링", Kind::Activity, None),
+        ];
+        let got = render_markdown(&list, &MarkdownOptions::default(), Lang::Ko, today());
+        assert_eq!(got, include_str!("../tests/fixtures/snapshots/digest.md"), "actual:\n{got}");
+    }
+}
@@ -0,0 +1,349 @@
+// src/metrics.rs
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use crate::source::BudgetUsage;
+
+/// 소스별 1회 실행 결과(메트릭용)
+#[derive(Clone, Debug)]
+pub struct SourceMetrics {
+    pub name: String,
+    pub raw_items: usize,       // 필터 전(목록에서 본) 건수
+    pub items: usize,
+    pub detail_failures: usize, // 상세 페이지 조회/파싱 실패 건수
+    pub robots_blocked: usize,  // robots.txt 때문에 건너뛴 URL 수
+    pub http_requests: u64,     // 응답을 받은 HTTP 요청 수
+    pub bytes_fetched: u64,     // 받은 본문 바이트 수
+    pub budget: BudgetUsage,    // 구간별 소요 시간/예산 소진 여부
+    pub duration: Duration,
+    pub timed_out: bool,       // 소스 타임아웃/전체 예산 초과로 끝났으면 true
+    pub error: Option<String>, // 실패 시 사유
+}
+
+/// 1회 실행 요약(STATS_FILE JSON)
+#[derive(Clone, Debug, Serialize)]
+pub struct RunStats {
+    /// 소스별 수집 건수(실패한 소스는 0)
+    pub per_source: HashMap<String, usize>,
+    /// 실패 사유("소스: 사유")
+    pub failures: Vec<String>,
+    /// 타임아웃으로 끝난 소스 이름
+    pub timed_out: Vec<String>,
+    /// 수집 시작부터 출력까지 걸린 시간
+    pub elapsed_ms: u128,
+    /// 통합 목록 건수(중복 제거 후)
+    pub merged_count: usize,
+}
+
+impl RunStats {
+    pub fn new(sources: &[SourceMetrics], merged_count: usize, elapsed: Duration) -> Self {
+        Self {
+            per_source: sources.iter().map(|s| (s.name.clone(), s.items)).collect(),
+            failures: sources
+                .iter()
+                .filter_map(|s| s.error.as_ref().map(|e| format!("{}: {e}", s.name)))
+                .collect(),
+            timed_out: sources.iter().filter(|s| s.timed_out).map(|s| s.name.clone()).collect(),
+            elapsed_ms: elapsed.as_millis(),
+            merged_count,
+        }
+    }
+
+    /// pretty JSON으로 저장(임시 파일 + rename)
+    pub fn write(&self, path: &str) -> Result<()> {
+        crate::atomic_write::write_atomic(path, serde_json::to_string_pretty(self)?.as_bytes())
+    }
+}
+
+/// run_summary.json의 소스별 항목
+#[derive(Clone, Debug, Serialize)]
+pub struct SourceSummary {
+    pub name: String,
+    /// 필터 전 건수(소스가 목록에서 본 항목)
+    pub raw_items: usize,
+    /// 필터/검증/소스 내부 중복 제거 후 건수
+    pub items: usize,
+    pub detail_failures: usize,
+    /// robots.txt가 막아 요청하지 않은 URL 수
+    pub robots_blocked: usize,
+    pub http_requests: u64,
+    pub bytes_fetched: u64,
+    /// 목록/상세/제한기 대기/파싱 시간과 예산 소진 여부
+    pub budget: BudgetUsage,
+    pub elapsed_ms: u128,
+    pub timed_out: bool,
+    pub error: Option<String>,
+}
+
+/// 1회 실행 상세 요약(RUN_SUMMARY_PATH, 기본 출력 디렉터리/run_summary.json)
+/// 실행마다 덮어씀. 시계열은 수집하는 쪽에서 쌓음
+#[derive(Clone, Debug, Serialize)]
+pub struct RunSummary {
+    /// 실행 종료 시각(RFC 3339)
+    pub finished_at: String,
+    pub sources: Vec<SourceSummary>,
+    /// 1차 중복 제거(URL)로 빠진 건수
+    pub dedup_url_dropped: usize,
+    /// 2차 중복 제거(플랫폼 간 title + 기간)로 빠진 건수
+    pub dedup_cross_dropped: usize,
+    pub merged_count: usize,
+    pub total_ms: u128,
+}
+
+impl RunSummary {
+    pub fn new(
+        sources: &[SourceMetrics],
+        dedup: &crate::summary::DedupCounts,
+        merged_count: usize,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            finished_at: chrono::Local::now().to_rfc3339(),
+            sources: sources
+                .iter()
+                .map(|s| SourceSummary {
+                    name: s.name.clone(),
+                    raw_items: s.raw_items,
+                    items: s.items,
+                    detail_failures: s.detail_failures,
+                    robots_blocked: s.robots_blocked,
+                    http_requests: s.http_requests,
+                    bytes_fetched: s.bytes_fetched,
+                    budget: s.budget.clone(),
+                    elapsed_ms: s.duration.as_millis(),
+                    timed_out: s.timed_out,
+                    error: s.error.clone(),
+                })
+                .collect(),
+            dedup_url_dropped: dedup.url_dropped,
+            dedup_cross_dropped: dedup.cross_dropped,
+            merged_count,
+            total_ms: elapsed.as_millis(),
+        }
+    }
+
+    /// pretty JSON으로 저장(임시 파일 + rename)
+    pub fn write(&self, path: &str) -> Result<()> {
+        crate::atomic_write::write_atomic(path, serde_json::to_string_pretty(self)?.as_bytes())
+    }
+}
+
+impl SourceMetrics {
+    pub fn ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// 종료 코드(cron/Actions 모니터링용)
+/// - 1: 통합 결과가 0건이거나 통합 피드 쓰기 실패
+/// - 2: 일부 소스 실패, 출력은 있음(strict면 1)
+/// - 0: 모든 소스 성공
+pub fn exit_code(sources: &[SourceMetrics], merged_items: usize, merged_write_ok: bool, strict: bool) -> i32 {
+    if merged_items == 0 || !merged_write_ok {
+        return 1;
+    }
+    if sources.iter().any(|s| !s.ok()) {
+        return if strict { 1 } else { 2 };
+    }
+    0
+}
+
+/// 한 줄 요약: "3/4 sources ok; failed: dacon (dacon timeout)"
+pub fn outcome_line(sources: &[SourceMetrics]) -> String {
+    let ok = sources.iter().filter(|s| s.ok()).count();
+    let failed: Vec<String> = sources
+        .iter()
+        .filter_map(|s| s.error.as_ref().map(|e| format!("{} ({e})", s.name)))
+        .collect();
+    if failed.is_empty() {
+        format!("{ok}/{} sources ok", sources.len())
+    } else {
+        format!("{ok}/{} sources ok; failed: {}", sources.len(), failed.join(", "))
+    }
+}
+
+/// Prometheus textfile collector 형식으로 렌더링
+///
+/// 노출 메트릭(이름/라벨은 고정):
+/// - `etc_crawler_source_items{source}`            마지막 실행의 소스별 수집 건수
+/// - `etc_crawler_source_duration_seconds{source}` 마지막 실행의 소스별 소요 시간
+/// - `etc_crawler_source_up{source}`               마지막 실행 성공 여부(1/0)
+/// - `etc_crawler_merged_items`                    통합 피드 건수
+/// - `etc_crawler_last_run_timestamp_seconds`      마지막 실행 종료 시각(unix)
+/// - `etc_crawler_last_success_timestamp_seconds`  한 소스라도 성공한 마지막 실행 시각(unix)
+///
+/// 카운터(`_total`, previous = 지난번 파일의 값에 이번 실행분을 더함):
+/// - `etc_crawler_runs_total`                      실행 횟수
+/// - `etc_crawler_source_failures_total{source}`   소스 실패 횟수
+/// - `etc_crawler_http_requests_total{source}`     응답을 받은 HTTP 요청 수
+/// - `etc_crawler_bytes_fetched_total{source}`     받은 본문 바이트 수(압축 해제 후)
+pub fn render_textfile(
+    sources: &[SourceMetrics],
+    merged_items: usize,
+    finished_at: i64,
+    previous: &HashMap<String, u64>,
+) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# HELP etc_crawler_source_items Items collected per source in the last run.");
+    let _ = writeln!(out, "# TYPE etc_crawler_source_items gauge");
+    for s in sources {
+        let _ = writeln!(out, "etc_crawler_source_items{{source=\"{}\"}} {}", s.name, s.items);
+    }
+
+    let _ = writeln!(out, "# HELP etc_crawler_source_duration_seconds Duration of the last run per source.");
+    let _ = writeln!(out, "# TYPE etc_crawler_source_duration_seconds gauge");
+    for s in sources {
+        let _ = writeln!(out, "etc_crawler_source_duration_seconds{{source=\"{}\"}} {:.3}", s.name, s.duration.as_secs_f64());
+    }
+
+    let _ = writeln!(out, "# HELP etc_crawler_source_up Whether the source succeeded in the last run.");
+    let _ = writeln!(out, "# TYPE etc_crawler_source_up gauge");
+    for s in sources {
+        let _ = writeln!(out, "etc_crawler_source_up{{source=\"{}\"}} {}", s.name, u8::from(s.ok()));
+    }
+
+    let _ = writeln!(out, "# HELP etc_crawler_merged_items Items in the merged feed.");
+    let _ = writeln!(out, "# TYPE etc_crawler_merged_items gauge");
+    let _ = writeln!(out, "etc_crawler_merged_items {merged_items}");
+
+    let _ = writeln!(out, "# HELP etc_crawler_last_run_timestamp_seconds Unix time the last run finished.");
+    let _ = writeln!(out, "# TYPE etc_crawler_last_run_timestamp_seconds gauge");
+    let _ = writeln!(out, "etc_crawler_last_run_timestamp_seconds {finished_at}");
+
+    if sources.iter().any(SourceMetrics::ok) {
+        let _ = writeln!(out, "# HELP etc_crawler_last_success_timestamp_seconds Unix time of the last run with at least one successful source.");
+        let _ = writeln!(out, "# TYPE etc_crawler_last_success_timestamp_seconds gauge");
+        let _ = writeln!(out, "etc_crawler_last_success_timestamp_seconds {finished_at}");
+    } else if let Some(last) = previous.get("etc_crawler_last_success_timestamp_seconds") {
+        // 이번 실행이 전부 실패해도 마지막 성공 시각은 유지
+        let _ = writeln!(out, "# HELP etc_crawler_last_success_timestamp_seconds Unix time of the last run with at least one successful source.");
+        let _ = writeln!(out, "# TYPE etc_crawler_last_success_timestamp_seconds gauge");
+        let _ = writeln!(out, "etc_crawler_last_success_timestamp_seconds {last}");
+    }
+
+    let counter = |out: &mut String, key: String, add: u64| {
+        let _ = writeln!(out, "{key} {}", previous.get(&key).copied().unwrap_or(0) + add);
+    };
+
+    let _ = writeln!(out, "# HELP etc_crawler_runs_total Crawl runs.");
+    let _ = writeln!(out, "# TYPE etc_crawler_runs_total counter");
+    counter(&mut out, "etc_crawler_runs_total".into(), 1);
+
+    let _ = writeln!(out, "# HELP etc_crawler_source_failures_total Failed runs per source.");
+    let _ = writeln!(out, "# TYPE etc_crawler_source_failures_total counter");
+    for s in sources {
+        counter(&mut out, format!("etc_crawler_source_failures_total{{source=\"{}\"}}", s.name), u64::from(!s.ok()));
+    }
+
+    let _ = writeln!(out, "# HELP etc_crawler_http_requests_total HTTP requests that got a response, per source.");
+    let _ = writeln!(out, "# TYPE etc_crawler_http_requests_total counter");
+    for s in sources {
+        counter(&mut out, format!("etc_crawler_http_requests_total{{source=\"{}\"}}", s.name), s.http_requests);
+    }
+
+    let _ = writeln!(out, "# HELP etc_crawler_bytes_fetched_total Response body bytes fetched per source.");
+    let _ = writeln!(out, "# TYPE etc_crawler_bytes_fetched_total counter");
+    for s in sources {
+        counter(&mut out, format!("etc_crawler_bytes_fetched_total{{source=\"{}\"}}", s.name), s.bytes_fetched);
+    }
+
+    out
+}
+
+/// 지난번 textfile에서 카운터/마지막 성공 시각 읽기(키: 라벨까지 포함한 시리즈 이름)
+/// 파일이 없거나 값이 깨진 줄은 0부터 다시 셈
+pub fn previous_counters(body: &str) -> HashMap<String, u64> {
+    body.lines()
+        .filter(|l| !l.starts_with('#'))
+        .filter_map(|l| l.rsplit_once(' '))
+        .filter(|(key, _)| {
+            key.starts_with("etc_crawler_last_success_timestamp_seconds")
+                || key.split('{').next().is_some_and(|name| name.ends_with("_total"))
+        })
+        .filter_map(|(key, v)| Some((key.to_string(), v.trim().parse().ok()?)))
+        .collect()
+}
+
+/// textfile collector가 반쯤 쓰인 파일을 읽지 않도록 임시 파일에 쓰고 rename
+pub fn write_textfile(path: &str, body: &str) -> Result<()> {
+    crate::atomic_write::write_atomic(path, body.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(name: &str, error: Option<&str>, http_requests: u64, bytes_fetched: u64) -> SourceMetrics {
+        SourceMetrics {
+            name: name.into(),
+            raw_items: 10,
+            items: if error.is_some() { 0 } else { 4 },
+            detail_failures: 0,
+            robots_blocked: 0,
+            http_requests,
+            bytes_fetched,
+            budget: BudgetUsage::default(),
+            duration: Duration::from_millis(1500),
+            timed_out: false,
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn textfile_has_gauges_and_counters() {
+        let sources = [source("dacon", None, 3, 2048), source("wevity", Some("timeout"), 5, 100)];
+        let body = render_textfile(&sources, 4, 1_792_000_000, &HashMap::new());
+        for line in [
+            "etc_crawler_source_items{source=\"dacon\"} 4",
+            "etc_crawler_source_duration_seconds{source=\"dacon\"} 1.500",
+            "etc_crawler_source_up{source=\"wevity\"} 0",
+            "etc_crawler_merged_items 4",
+            "etc_crawler_last_success_timestamp_seconds 1792000000",
+            "# TYPE etc_crawler_runs_total counter",
+            "etc_crawler_runs_total 1",
+            "etc_crawler_source_failures_total{source=\"dacon\"} 0",
+            "etc_crawler_source_failures_total{source=\"wevity\"} 1",
+            "etc_crawler_http_requests_total{source=\"wevity\"} 5",
+            "etc_crawler_bytes_fetched_total{source=\"dacon\"} 2048",
+        ] {
+            assert!(body.lines().any(|l| l == line), "missing {line:?} in\n{body}");
+        }
+    }
+
+    #[test]
+    fn counters_accumulate_across_runs() {
+        let first = render_textfile(&[source("dacon", None, 3, 2048)], 4, 100, &HashMap::new());
+        // 두 번째 실행은 전부 실패: 카운터는 더하고, 마지막 성공 시각은 첫 실행 값 유지
+        let second =
+            render_textfile(&[source("dacon", Some("HTTP 500"), 2, 10)], 0, 200, &previous_counters(&first));
+        let counters = previous_counters(&second);
+        assert_eq!(counters["etc_crawler_runs_total"], 2);
+        assert_eq!(counters["etc_crawler_source_failures_total{source=\"dacon\"}"], 1);
+        assert_eq!(counters["etc_crawler_http_requests_total{source=\"dacon\"}"], 5);
+        assert_eq!(counters["etc_crawler_bytes_fetched_total{source=\"dacon\"}"], 2058);
+        assert_eq!(counters["etc_crawler_last_success_timestamp_seconds"], 100);
+        assert!(!counters.contains_key("etc_crawler_merged_items"));
+    }
+
+    #[test]
+    fn exit_code_reflects_total_partial_and_full_success() {
+        let all_ok = [source("dacon", None, 1, 1), source("wevity", None, 1, 1)];
+        let partial = [source("dacon", None, 1, 1), source("wevity", Some("bot check"), 1, 1)];
+        let all_failed = [source("dacon", Some("timeout"), 0, 0), source("wevity", Some("HTTP 503"), 0, 0)];
+
+        assert_eq!(exit_code(&all_ok, 8, true, false), 0);
+        assert_eq!(exit_code(&partial, 4, true, false), 2);
+        assert_eq!(exit_code(&partial, 4, true, true), 1);
+        assert_eq!(exit_code(&all_failed, 0, true, false), 1);
+        // 모두 성공했어도 0건이거나 통합 피드 쓰기에 실패하면 1
+        assert_eq!(exit_code(&all_ok, 0, true, false), 1);
+        assert_eq!(exit_code(&all_ok, 8, false, false), 1);
+
+        assert_eq!(outcome_line(&all_ok), "2/2 sources ok");
+        assert_eq!(outcome_line(&partial), "1/2 sources ok; failed: wevity (bot check)");
+    }
+}
@@ -1,58 +1,553 @@
-// src/notice.rs
-use std::fmt;
-
-#[derive(Clone, Debug)]
-pub enum Source {
-    Wevity,
-    Dacon,
-    Campuspick,
-}
-
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum Kind {
-    Contest,
-    Activity,
-}
-
-#[derive(Clone, Debug)]
-pub struct Notice {
-    pub source: Source,
-    pub kind: Kind,                 // 공모전 / 대외활동
-    pub title: String,
-    pub url: String,
-    pub start: Option<String>,      // YYYY-MM-DD
-    pub end:   Option<String>,      // YYYY-MM-DD
-    pub organizer: Option<String>,  // 주최/주관
-    pub field: Option<String>,      // 분야(있으면)
-}
-
-pub fn infer_kind_from_label(label: &str, default: Kind) -> Kind {
-    let s = label.trim().to_lowercase();
-    if s.contains("활동") || s.contains("activity") {
-        Kind::Activity
-    } else if s.contains("공모") || s.contains("contest") || s.contains("competition") {
-        Kind::Contest
-    } else {
-        default
-    }
-}
-
-impl fmt::Display for Notice {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let start = self.start.clone().unwrap_or_else(|| "-".into());
-        let end   = self.end.clone().unwrap_or_else(|| "-".into());
-        let org   = self.organizer.clone().unwrap_or_else(|| "-".into());
-        let field = self.field.clone().unwrap_or_else(|| "-".into());
-
-        write!(
-            f,
-            "[{:?}/{:?}] {} | {} | {} ~ {} | {}",
-            self.source, self.kind, self.title, org, start, end, self.url
-        )?;
-
-        if !field.is_empty() && field != "-" {
-            write!(f, " | {}", field)?;
-        }
-        Ok(())
-    }
-}
\ No newline at end of file
+// src/notice.rs
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Wevity,
+    Dacon,
+    Campuspick,
+    Linkareer,
+    /// 외부 RSS/Atom 재수집(rss_ingest) 소스. 값은 설정의 라벨, JSON에는 라벨 문자열 그대로
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl Source {
+    /// 카테고리/CSV/로그용 이름("Wevity" … , Custom은 라벨)
+    pub fn name(&self) -> &str {
+        match self {
+            Source::Wevity     => "Wevity",
+            Source::Dacon      => "Dacon",
+            Source::Campuspick => "Campuspick",
+            Source::Linkareer  => "Linkareer",
+            Source::Custom(label) => label,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Contest,
+    Activity,
+    /// 교육/부트캠프/아카데미
+    Education,
+    /// 채용/인턴
+    Recruit,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Notice {
+    pub source: Source,
+    pub kind: Kind,                 // 공모전 / 대외활동 / 교육 / 채용
+    pub title: String,              // 표시용(clean_title)
+    #[serde(default)]
+    pub raw_title: String,          // 소스가 준 제목 원문(정리/디코딩 전)
+    pub url: String,
+    pub start: Option<String>,      // YYYY-MM-DD
+    pub end:   Option<String>,      // YYYY-MM-DD
+    pub organizer: Option<String>,  // 주최/주관
+    pub field: Option<String>,      // 분야(있으면)
+    pub end_approx: bool,           // end가 D-day 배지 등에서 추정된 값이면 true
+    pub summary: Option<String>,    // 본문 소개문(SUMMARY_MAX_CHARS자까지, 없으면 None)
+    pub series_id: Option<String>,  // 회차만 다른 지난 공고가 있으면 그 묶음 키(series_key, store::assign_series)
+    pub changed_at: Option<String>, // 내용(content_hash)이 마지막으로 바뀐 시각(RFC 3339, store::track_changes)
+}
+
+/// 소개문 최대 글자 수
+pub const SUMMARY_MAX_CHARS: usize = 300;
+
+/// 본문 텍스트 → 소개문: 공백 정리 후 SUMMARY_MAX_CHARS자에서 자르고 "…". 비면 None
+pub fn make_summary(text: &str) -> Option<String> {
+    let t = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if t.is_empty() {
+        return None;
+    }
+    if t.chars().count() <= SUMMARY_MAX_CHARS {
+        return Some(t);
+    }
+    let head: String = t.chars().take(SUMMARY_MAX_CHARS).collect();
+    Some(format!("{}…", head.trim_end()))
+}
+
+/// 여러 값이 이어진 문자열을 정렬해 다시 결합("B / A" → "A / B")
+/// 같은 항목이 실행마다 같은 문자열이 되도록(dedup/diff 안정성)
+pub fn sort_join(s: &str, sep: char, joiner: &str) -> String {
+    let mut parts: Vec<&str> = s.split(sep).map(str::trim).filter(|p| !p.is_empty()).collect();
+    parts.sort_unstable();
+    parts.dedup();
+    parts.join(joiner)
+}
+
+/// normalize_url에서 지울 쿼리 파라미터 기본값("접두*"는 접두 일치)
+const DEFAULT_STRIP_PARAMS: &[&str] = &["gp", "page", "utm_*", "fbclid"];
+
+/// 기본 제거 파라미터 목록(config의 url_strip_params 기본값)
+pub fn default_strip_params() -> Vec<String> {
+    DEFAULT_STRIP_PARAMS.iter().map(|p| p.to_string()).collect()
+}
+
+fn is_stripped_param(key: &str, strip: &[String]) -> bool {
+    strip.iter().any(|p| match p.strip_suffix('*') {
+        Some(prefix) => key.starts_with(prefix),
+        None => key == p,
+    })
+}
+
+/// URL 정규화: 페이지/추적 파라미터(strip, config의 url_strip_params)를 지우고 나머지 쿼리를 키 순으로 정렬
+/// 추적 파라미터만 다른 URL이 실행 간에도 같은 키가 되도록. 파싱할 수 없으면 그대로
+pub fn normalize_url(url: &str, strip: &[String]) -> String {
+    let Ok(mut parsed) = url::Url::parse(url.trim()) else {
+        return url.to_string();
+    };
+    let mut pairs: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(k, _)| !is_stripped_param(k, strip))
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    pairs.sort();
+    if pairs.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    parsed.to_string()
+}
+
+/// 파싱 버그로 보이는 항목 검사: (인덱스, 사유) 목록(문제가 없으면 빈 Vec)
+/// - 제목이 비었거나 공백뿐
+/// - URL이 http(s)가 아님(빈 URL 포함)
+/// - start/end가 YYYY-MM-DD가 아님
+/// - end가 start보다 앞섬
+pub fn validate_notices(notices: &[Notice]) -> Vec<(usize, String)> {
+    let parse = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+    let mut out = Vec::new();
+    for (i, n) in notices.iter().enumerate() {
+        if n.title.trim().is_empty() {
+            out.push((i, "empty title".to_string()));
+        }
+        let url = n.url.trim();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            out.push((i, format!("non-http url: {url:?}")));
+        }
+        for (label, d) in [("start", &n.start), ("end", &n.end)] {
+            if let Some(d) = d && parse(d).is_none() {
+                out.push((i, format!("invalid {label} date: {d}")));
+            }
+        }
+        if let (Some(s), Some(e)) = (n.start.as_deref().and_then(parse), n.end.as_deref().and_then(parse))
+            && e < s {
+            out.push((i, format!("end {e} before start {s}")));
+        }
+    }
+    out
+}
+
+/// 정규화 URL 기준 중복 제거(처음 나온 항목 유지)
+pub fn dedup_by_url(notices: &mut Vec<Notice>, strip: &[String]) {
+    let mut seen = std::collections::HashSet::new();
+    notices.retain(|n| seen.insert(n.url_key(strip)));
+}
+
+/// 모집 기간이 max_days를 넘는 항목(상시 모집성) 제외. 기간을 알 수 없는 항목(상시 "마감일 없음" 포함)은 유지
+pub fn drop_long_running(notices: &mut Vec<Notice>, max_days: i64) {
+    notices.retain(|n| n.duration_days().is_none_or(|d| d <= max_days));
+}
+
+/// 마감일이 [start, end] 창 안(양끝 포함, None인 쪽은 열림)인 항목만 유지. 마감일이 없거나 해석할 수 없으면 제외
+pub fn retain_in_window(notices: &mut Vec<Notice>, start: Option<chrono::NaiveDate>, end: Option<chrono::NaiveDate>) {
+    notices.retain(|n| {
+        n.end.as_deref()
+            .and_then(crate::dates::parse_ymd)
+            .is_some_and(|d| crate::dates::within_window(d, start, end))
+    });
+}
+
+/// 마감일(end)이 오늘보다 앞이면 true
+pub fn is_expired(n: &Notice, today: chrono::NaiveDate) -> bool {
+    n.end
+        .as_deref()
+        .and_then(|e| chrono::NaiveDate::parse_from_str(e, "%Y-%m-%d").ok())
+        .is_some_and(|e| e < today)
+}
+
+/// 라벨/제목 문자열로 Kind 추정(대외활동 → 공모전 → 교육 → 채용 순서, 못 찾으면 default)
+/// "교육 콘텐츠 공모전"처럼 겹치면 기존 두 종류가 우선. "채용연계 부트캠프"는 교육
+pub fn infer_kind_from_label(label: &str, default: Kind) -> Kind {
+    let s = label.trim().to_lowercase();
+    let has = |keys: &[&str]| keys.iter().any(|k| s.contains(k));
+    if has(&["활동", "activity"]) {
+        Kind::Activity
+    } else if has(&["공모", "contest", "competition"]) {
+        Kind::Contest
+    } else if has(&["교육", "부트캠프", "아카데미", "education", "bootcamp", "academy"]) {
+        Kind::Education
+    } else if has(&["채용", "인턴", "recruit", "internship", "hiring"]) {
+        Kind::Recruit
+    } else {
+        default
+    }
+}
+
+/// 분야 분류표: (표준 분야, 원문/제목에서 찾을 소문자 키워드)
+/// 위비티 sub-tit("IT·프로그래밍", "디자인·브랜드" …)와 제목 키워드를 함께 본다.
+const FIELD_TAXONOMY: &[(&str, &[&str])] = &[
+    ("IT", &["it", "sw", "소프트웨어", "프로그래밍", "코딩", "개발", "보안", "정보보호", "해커톤", "인공지능", "ai", "앱", "게임"]),
+    ("Data", &["데이터", "data", "빅데이터", "머신러닝", "딥러닝", "분석", "통계"]),
+    ("Design", &["디자인", "design", "브랜드", "ui", "ux", "일러스트", "영상", "사진", "캐릭터"]),
+    ("Marketing", &["마케팅", "marketing", "광고", "홍보", "서포터즈", "기자단", "sns"]),
+    ("Startup", &["창업", "스타트업", "startup", "아이디어", "사업계획", "비즈니스"]),
+];
+
+/// 제목 + 분야 원문을 표준 분야(IT, Data, Design, Marketing, Startup)로 분류(순서 고정, 중복 없음)
+/// ASCII 키워드는 단어 단위("ai"가 "mail"에 맞지 않도록), 한글은 부분 문자열로 비교
+pub fn classify_field(title: &str, field_raw: Option<&str>) -> Vec<String> {
+    let hay = format!("{} {}", title, field_raw.unwrap_or_default()).to_lowercase();
+    let words: Vec<&str> = hay
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    FIELD_TAXONOMY
+        .iter()
+        .filter(|(_, kws)| {
+            kws.iter().any(|kw| {
+                if kw.is_ascii() { words.contains(kw) } else { hay.contains(kw) }
+            })
+        })
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// 분야 태그 안의 괄호 설명("게임(모바일)" → "게임")
+static RE_PAREN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\([^)]*\)").unwrap());
+
+/// "제 1 회" / "제1 회" 등 회차 표기
+static RE_ROUND: Lazy<Regex> = Lazy::new(|| Regex::new(r"제\s*(\d+)\s*회").unwrap());
+
+/// 중복 제거 키 전용 제목 정규화(표시용 아님)
+/// - 회차 표기를 "제N회"로 통일(회차 번호가 다르면 다른 키)
+/// - 소문자화 + 공백 축약
+pub fn normalize_title_key(title: &str) -> String {
+    let t = RE_ROUND.replace_all(title.trim(), "제${1}회");
+    t.to_lowercase().split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 반복 개최 표기: 회차("제5회") 또는 단독 연도("2026", "2026년", "2026년도")
+static RE_SERIES_MARK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"제\s*\d+\s*회|(?:^|\s)(?:19|20)\d{2}(?:년도?)?(?:\s|$)").unwrap());
+
+/// 같은 공모전의 다른 회차/연도를 묶는 키: 회차/연도 표기를 지운 뒤 normalize_title_key
+/// "제1회 X 대회"와 "제2회 X 대회", "2025 X 대회"와 "2026년 X 대회"는 같은 키
+pub fn series_key(title: &str) -> String {
+    normalize_title_key(&RE_SERIES_MARK.replace_all(title, " "))
+}
+
+/// 제목 앞의 분류 태그: "(공모전)", "[대외활동]" 등
+static RE_LEADING_TAG: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*[\[(](공모전|공모|대외활동|활동|대회|모집)[\])]\s*").unwrap()
+});
+/// 연속된 문장부호("!!!", "??", "~~")
+static RE_REPEAT_PUNCT: Lazy<Regex> = Lazy::new(|| Regex::new(r"([!?~.])[!?~.]+").unwrap());
+
+/// 소스별로 제목 끝에 붙는 사이트명
+fn site_suffixes(source: &Source) -> &'static [&'static str] {
+    match source {
+        Source::Wevity     => &["위비티", "wevity"],
+        Source::Campuspick => &["캠퍼스픽", "campuspick"],
+        Source::Dacon      => &["데이콘", "dacon"],
+        Source::Linkareer  => &["링커리어", "linkareer"],
+        Source::Custom(_)  => &[],
+    }
+}
+
+/// HTML 엔티티 디코딩("&amp;", "&#39;", "&nbsp;" …). 피드에는 디코딩한 값을 넣고
+/// 이스케이프는 쓰는 쪽(rss/atom 크레이트, html_write)이 한 번만 한다
+pub fn decode_entities(s: &str) -> String {
+    html_escape::decode_html_entities(s).into_owned()
+}
+
+/// 표시용 제목 정리(원문은 Notice::raw_title에 그대로 남음)
+/// 엔티티는 디코딩하지 않음: HTML 파서를 거친 wevity 제목은 이미 디코딩됐고, JSON/피드 소스는
+/// 어댑터에서 decode_entities를 한 번만 부름("&amp;lt;"가 "<"까지 풀리지 않게)
+/// 1) NFKC로 전각 문장부호/괄호 정규화, 【】→[]
+/// 2) 끝의 사이트명 접미사 제거("- 위비티", "| 캠퍼스픽")
+/// 3) 앞의 분류 태그 제거("(공모전)")
+/// 4) 연속 문장부호 축약 + 공백 정리
+pub fn clean_title(title: &str, source: &Source) -> String {
+    let mut t: String = title.nfkc().collect();
+    t = t.replace('【', "[").replace('】', "]");
+
+    let lower = t.to_ascii_lowercase();
+    for suffix in site_suffixes(source) {
+        if let Some(pos) = lower.rfind(suffix)
+            && lower[pos + suffix.len()..].trim().is_empty() {
+            let head = t[..pos].trim_end();
+            if let Some(stripped) = head.strip_suffix(['-', '|', ':', '·']) {
+                t = stripped.trim_end().to_string();
+                break;
+            }
+        }
+    }
+
+    t = RE_LEADING_TAG.replace(&t, "").into_owned();
+    t = RE_REPEAT_PUNCT.replace_all(&t, "$1").into_owned();
+    t.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl Notice {
+    /// URL 기준 중복 키: 정규화 URL(URL이 비어 있으면 제목+소스+종류)
+    pub fn url_key(&self, strip: &[String]) -> String {
+        if self.url.trim().is_empty() {
+            format!("{}-{}-{:?}", self.title, self.source.name(), self.kind)
+        } else {
+            normalize_url(&self.url, strip)
+        }
+    }
+
+    /// 플랫폼 간 중복 키: 정규화 제목(trim/소문자/공백 축약/회차 표기) + 기간
+    pub fn dedup_key(&self) -> String {
+        format!(
+            "{}|{}-{}",
+            normalize_title_key(&self.title),
+            self.start.as_deref().unwrap_or_default(),
+            self.end.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// 모집 기간(end - start) 일수. 둘 중 하나라도 없거나 형식이 다르면 None
+    pub fn duration_days(&self) -> Option<i64> {
+        let parse = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+        let start = parse(self.start.as_deref()?)?;
+        let end = parse(self.end.as_deref()?)?;
+        Some((end - start).num_days())
+    }
+
+    /// 오늘(로컬)부터 마감까지 남은 일수(지났으면 음수). 마감일이 없거나 형식이 다르면 None
+    pub fn days_until_end(&self) -> Option<i64> {
+        crate::dates::days_until(self.end.as_deref()?, crate::clock::today())
+    }
+
+    /// 남은 기간 표시(콘솔 Display 제목 뒤, RSS 본문 기간 줄 끝): "(D-5)", "(D-day)", 지난 마감은 "(마감)"
+    /// 표시용일 뿐 필터링에는 쓰지 않음
+    pub fn dday_tag(&self) -> Option<String> {
+        Some(match self.days_until_end()? {
+            0 => "(D-day)".to_string(),
+            d if d > 0 => format!("(D-{d})"),
+            _ => "(마감)".to_string(),
+        })
+    }
+
+    /// 분야 원문을 개별 태그로 분리("IT·소프트웨어, 게임" → ["IT", "소프트웨어", "게임"])
+    /// 구분자: · ・ / , | 그리고 " > ". 괄호 안 설명과 양끝 공백은 제거, 빈 값/중복 태그(대소문자 무시)는 제외
+    pub fn field_tags(&self) -> Vec<String> {
+        let Some(raw) = self.field.as_deref() else { return Vec::new(); };
+        // 괄호 안에도 구분자가 올 수 있어("공학(기계·전자)") 괄호를 먼저 지우고 분리
+        let raw = RE_PAREN.replace_all(raw, "").replace(" > ", ",");
+        let mut tags: Vec<String> = Vec::new();
+        for part in raw.split(['·', '・', '/', ',', '|']) {
+            let tag = part.split_whitespace().collect::<Vec<_>>().join(" ");
+            if !tag.is_empty() && !tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+
+    /// 피드 항목 GUID: 정규화 URL + 제목 키 + 마감일의 stable_hash
+    pub fn stable_guid(&self, strip: &[String]) -> String {
+        let key = format!(
+            "{}|{}|{}",
+            normalize_url(&self.url, strip),
+            normalize_title_key(&self.title),
+            self.end.as_deref().unwrap_or("")
+        );
+        format!("etc-crawler:{:016x}", stable_hash(&key))
+    }
+
+    /// 내용 해시: 제목 + 기간 + 주최 + 분야의 stable_hash. 마감 연장/주최 정정처럼 리더에 다시 띄울 변경만 반영
+    pub fn content_hash(&self) -> String {
+        let key = format!(
+            "{}|{}|{}|{}|{}",
+            self.title,
+            self.start.as_deref().unwrap_or(""),
+            self.end.as_deref().unwrap_or(""),
+            self.organizer.as_deref().unwrap_or(""),
+            self.field.as_deref().unwrap_or("")
+        );
+        format!("{:016x}", stable_hash(&key))
+    }
+}
+
+/// FNV-1a(64bit) 해시
+/// std Hasher는 버전마다 달라질 수 있어 직접 계산(실행 간 같은 값 보장)
+pub fn stable_hash(s: &str) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in s.bytes() {
+        h ^= u64::from(b);
+        h = h.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    h
+}
+
+impl fmt::Display for Notice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = self.start.clone().unwrap_or_else(|| "-".into());
+        let end   = self.end.clone().unwrap_or_else(|| "-".into());
+        let org   = self.organizer.clone().unwrap_or_else(|| "-".into());
+        let field = self.field.clone().unwrap_or_else(|| "-".into());
+
+        write!(f, "[{}/{:?}] {}", self.source.name(), self.kind, self.title)?;
+        if let Some(tag) = self.dday_tag() {
+            write!(f, " {tag}")?;
+        }
+        write!(f, " | {} | {} ~ {} | {}", org, start, end, self.url)?;
+
+        if !field.is_empty() && field != "-" {
+            write!(f, " | {}", field)?;
+        }
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notice(title: &str) -> Notice {
+        Notice {
+            source: Source::Wevity,
+            kind: Kind::Contest,
+            title: title.into(),
+            raw_title: title.into(),
+            url: "https://www.wevity.com/?c=find&ix=1".into(),
+            start: Some("2026-10-01".into()),
+            end: Some("2026-10-24".into()),
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    #[test]
+    fn round_markers_collapse_spacing_but_keep_round_numbers() {
+        assert_eq!(normalize_title_key("제 1 회  AI 공모전"), "제1회 ai 공모전");
+        assert_eq!(normalize_title_key("제1 회 AI 공모전"), normalize_title_key("제1회 AI  공모전"));
+        assert_ne!(normalize_title_key("제1회 AI 공모전"), normalize_title_key("제2회 AI 공모전"));
+        // 키만 정규화하고 표시용 title은 그대로
+        let spaced = notice("제 3 회 SW 해커톤");
+        assert_eq!(spaced.dedup_key(), notice("제3회 SW 해커톤").dedup_key());
+        assert_ne!(spaced.dedup_key(), notice("제4회 SW 해커톤").dedup_key());
+        assert_eq!(spaced.title, "제 3 회 SW 해커톤");
+    }
+
+    #[test]
+    fn series_key_ignores_round_and_year() {
+        assert_eq!(series_key("제1회 X 대회"), series_key("제2회 X 대회"));
+        assert_eq!(series_key("제 1 회 X 대회"), "x 대회");
+        assert_eq!(series_key("2025 X 대회"), series_key("2026년 X 대회"));
+        assert_eq!(series_key("X 대회 2026"), "x 대회");
+        // 다른 대회나 제목 속 숫자는 그대로
+        assert_ne!(series_key("제1회 X 대회"), series_key("제1회 Y 대회"));
+        assert_eq!(series_key("K-2026X 챌린지"), "k-2026x 챌린지");
+    }
+
+    #[test]
+    fn multi_value_joins_are_order_independent() {
+        assert_eq!(sort_join("B / A", '/', " / "), sort_join("A / B", '/', " / "));
+        assert_eq!(sort_join("B / A", '/', " / "), "A / B");
+        // 공백/빈 조각/중복은 정리
+        assert_eq!(sort_join(" 네이버/ /카카오 / 네이버", '/', " / "), "네이버 / 카카오");
+        assert_eq!(sort_join("기획, IT,디자인", ',', ", "), "IT, 기획, 디자인");
+    }
+
+    #[test]
+    fn long_running_items_are_dropped_but_open_ended_kept() {
+        let span = |title: &str, start: &str, end: Option<&str>| Notice {
+            start: Some(start.into()),
+            end: end.map(str::to_string),
+            ..notice(title)
+        };
+        let mut list = vec![
+            span("300일", "2026-01-01", Some("2026-10-28")),
+            span("180일", "2026-04-01", Some("2026-09-28")),
+            span("30일", "2026-10-01", Some("2026-10-31")),
+            span("상시", "2025-01-01", None),
+        ];
+        assert_eq!(list[0].duration_days(), Some(300));
+        drop_long_running(&mut list, 180);
+        let titles: Vec<&str> = list.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, ["180일", "30일", "상시"]);
+    }
+
+    #[test]
+    fn field_tags_split_messy_wevity_strings() {
+        let tags = |field: &str| Notice { field: Some(field.into()), ..notice("t") }.field_tags();
+        assert_eq!(tags("IT·소프트웨어, 게임"), ["IT", "소프트웨어", "게임"]);
+        assert_eq!(tags(" 기획/아이디어 ,, 디자인 | 웹/모바일/IT "), ["기획", "아이디어", "디자인", "웹", "모바일", "IT"]);
+        // 괄호 설명 제거, 경로형 구분자, 대소문자 무시 중복 제거
+        assert_eq!(tags("과학/공학 (기계·전자) > 공학"), ["과학", "공학"]);
+        assert_eq!(tags("IT ・ it・게임  개발"), ["IT", "게임 개발"]);
+        assert!(tags(" · , ").is_empty());
+        assert!(notice("t").field_tags().is_empty());
+    }
+
+    #[test]
+    fn window_bounds_are_inclusive_and_missing_end_is_dropped() {
+        let ymd = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+        let due = |title: &str, end: Option<&str>| Notice { end: end.map(str::to_string), ..notice(title) };
+        let list = vec![
+            due("before", Some("2026-10-31")),
+            due("first", Some("2026-11-01")),
+            due("last", Some("2026-11-30")),
+            due("after", Some("2026-12-01")),
+            due("none", None),
+            due("bad", Some("미정")),
+        ];
+        let titles = |v: &[Notice]| v.iter().map(|n| n.title.clone()).collect::<Vec<_>>();
+
+        let mut v = list.clone();
+        retain_in_window(&mut v, ymd("2026-11-01"), ymd("2026-11-30"));
+        assert_eq!(titles(&v), ["first", "last"]);
+        // 한쪽만 주면 다른 쪽은 열린 구간
+        let mut v = list.clone();
+        retain_in_window(&mut v, ymd("2026-11-30"), None);
+        assert_eq!(titles(&v), ["last", "after"]);
+        let mut v = list;
+        retain_in_window(&mut v, None, ymd("2026-11-01"));
+        assert_eq!(titles(&v), ["before", "first"]);
+    }
+
+    #[test]
+    fn labels_infer_education_and_recruit_kinds() {
+        for label in ["교육", "SW 부트캠프", "데이터 아카데미 3기", "Bootcamp", "Online Education"] {
+            assert_eq!(infer_kind_from_label(label, Kind::Contest), Kind::Education, "{label}");
+        }
+        for label in ["채용", "체험형 인턴", "Internship", "Hiring now", "recruit"] {
+            assert_eq!(infer_kind_from_label(label, Kind::Contest), Kind::Recruit, "{label}");
+        }
+        // 겹치는 라벨은 기존 종류 우선, 채용연계 교육은 교육
+        assert_eq!(infer_kind_from_label("교육 콘텐츠 공모전", Kind::Activity), Kind::Contest);
+        assert_eq!(infer_kind_from_label("인턴 체험 대외활동", Kind::Contest), Kind::Activity);
+        assert_eq!(infer_kind_from_label("채용연계 부트캠프", Kind::Contest), Kind::Education);
+        // 모르는 라벨은 default
+        assert_eq!(infer_kind_from_label("봉사", Kind::Contest), Kind::Contest);
+        assert_eq!(infer_kind_from_label("", Kind::Activity), Kind::Activity);
+        assert_eq!(serde_json::to_string(&Kind::Recruit).unwrap(), "\"recruit\"");
+    }
+
+    #[test]
+    fn normalize_url_strips_only_the_given_params() {
+        let url = "https://www.wevity.com/?ix=2&c=find&gp=3&utm_source=rss&ref=x";
+        assert_eq!(normalize_url(url, &default_strip_params()), "https://www.wevity.com/?c=find&ix=2&ref=x");
+        let custom = vec!["ref".to_string(), "utm_*".to_string()];
+        assert_eq!(normalize_url(url, &custom), "https://www.wevity.com/?c=find&gp=3&ix=2");
+        assert_eq!(normalize_url("not a url", &custom), "not a url");
+    }
+}
@@ -0,0 +1,85 @@
+// src/rate_limit.rs
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// 호스트별 요청 간격 제한(버킷 크기 1인 토큰 버킷, 즉 요청 사이 최소 간격)
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    /// 호스트별 다음 요청을 보낼 수 있는 시각
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl RateLimiter {
+    /// host로 보낼 요청 슬롯을 하나 예약하고 그 시각까지 대기(per_sec이 0 이하면 바로 반환)
+    /// 대기 중인 future를 drop하면(시간 예산 timeout 등) 대기도 끝남. 예약한 슬롯은 돌려받지 않음
+    /// 돌려주는 값은 실제로 기다린 시간
+    pub async fn acquire(&self, host: &str, per_sec: f64) -> Duration {
+        if per_sec.is_nan() || per_sec <= 0.0 {
+            return Duration::ZERO;
+        }
+        let interval = Duration::from_secs_f64(1.0 / per_sec);
+        let at = {
+            let mut slots = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let slot = slots.entry(host.to_string()).or_insert(now);
+            let at = (*slot).max(now);
+            *slot = at + interval;
+            at
+        };
+        let waited = at.saturating_duration_since(Instant::now());
+        tokio::time::sleep_until(at).await;
+        waited
+    }
+}
+
+/// 모든 소스가 공유하는 제한기(소스가 달라도 같은 호스트면 간격을 함께 씀)
+pub static LIMITER: Lazy<RateLimiter> = Lazy::new(RateLimiter::default);
+
+/// url의 호스트 기준으로 LIMITER.acquire(호스트를 읽을 수 없으면 바로 반환). 기다린 시간을 돌려줌
+pub async fn throttle(url: &str, per_sec: f64) -> Duration {
+    match url::Url::parse(url).ok().and_then(|u| u.host_str().map(str::to_string)) {
+        Some(host) => LIMITER.acquire(&host, per_sec).await,
+        None => Duration::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::CollectStats;
+    use crate::test_util::{MockServer, Reply};
+
+    #[tokio::test]
+    async fn requests_to_one_host_are_spaced_by_the_limit() {
+        let server = MockServer::start(vec![("/page", vec![Reply::ok("ok")])]).await;
+        let client = reqwest::Client::new();
+        let stats = CollectStats::default();
+        for _ in 0..4 {
+            crate::http::send_with_retry(client.get(server.url("/page")), 1, Duration::ZERO, 10.0, &stats).await.unwrap();
+        }
+        let at: Vec<_> = server.requests_to("/page").iter().map(|r| r.at).collect();
+        assert_eq!(at.len(), 4);
+        for pair in at.windows(2) {
+            // 초당 10회 → 100ms 간격(타이머 오차만큼 여유)
+            let gap = pair[1] - pair[0];
+            assert!(gap >= Duration::from_millis(90), "{gap:?}");
+        }
+        assert!(stats.budget().rate_limit_wait_ms >= 200, "{:?}", stats.budget());
+    }
+
+    #[tokio::test]
+    async fn hosts_are_independent_and_waiting_can_be_cancelled() {
+        let limiter = RateLimiter::default();
+        assert_eq!(limiter.acquire("a.example", 1.0).await, Duration::ZERO);
+        assert_eq!(limiter.acquire("b.example", 1.0).await, Duration::ZERO);
+        assert_eq!(limiter.acquire("a.example", 0.0).await, Duration::ZERO);
+        // 같은 호스트 두 번째 요청은 1초 대기. 시간 예산이 먼저 끝나면 대기도 끝남
+        let started = Instant::now();
+        let waited = tokio::time::timeout(Duration::from_millis(50), limiter.acquire("a.example", 1.0)).await;
+        assert!(waited.is_err());
+        assert!(started.elapsed() < Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,276 @@
+// src/robots.rs
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::OnceCell;
+use tracing::{info, warn};
+use url::Url;
+
+/// robots.txt 요청 상한(느린 서버 때문에 수집이 밀리지 않도록 짧게)
+const FETCH_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 한 호스트에서 우리에게 적용되는 규칙(보내는 UA에 맞는 그룹, 없으면 "*" 그룹)
+#[derive(Debug, Default, Clone)]
+pub struct Rules {
+    /// (allow 여부, 경로 패턴). 빈 패턴("Disallow:")은 아무것도 막지 않음
+    rules: Vec<(bool, String)>,
+}
+
+impl Rules {
+    /// robots.txt 본문 파싱(RFC 9309의 user-agent 그룹, allow/disallow, `*`와 끝의 `$`)
+    /// user_agent는 실제로 보내는 User-Agent 헤더 값. 그 UA에 맞는 그룹(agent_matches)이
+    /// 하나라도 있으면 그 그룹들만, 없으면 "*" 그룹들을 씀
+    pub fn parse(body: &str, user_agent: &str) -> Self {
+        let mut specific: Vec<(bool, String)> = Vec::new();
+        let mut wildcard: Vec<(bool, String)> = Vec::new();
+        let mut matched_specific = false;
+
+        // 현재 그룹: user-agent 줄들 + 그 뒤 규칙들
+        let mut agents: Vec<String> = Vec::new();
+        let mut group: Vec<(bool, String)> = Vec::new();
+        let mut in_rules = false;
+
+        let mut flush = |agents: &mut Vec<String>, group: &mut Vec<(bool, String)>| {
+            if agents.iter().any(|a| agent_matches(a, user_agent)) {
+                matched_specific = true;
+                specific.extend(group.iter().cloned());
+            } else if agents.iter().any(|a| a == "*") {
+                wildcard.extend(group.iter().cloned());
+            }
+            agents.clear();
+            group.clear();
+        };
+
+        for line in body.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else { continue };
+            let (key, value) = (key.trim().to_ascii_lowercase(), value.trim());
+            match key.as_str() {
+                "user-agent" => {
+                    if in_rules {
+                        flush(&mut agents, &mut group);
+                        in_rules = false;
+                    }
+                    agents.push(value.to_ascii_lowercase());
+                }
+                "allow" | "disallow" => {
+                    in_rules = true;
+                    if !agents.is_empty() {
+                        group.push((key == "allow", value.to_string()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        flush(&mut agents, &mut group);
+
+        Self { rules: if matched_specific { specific } else { wildcard } }
+    }
+
+    /// 모두 허용(robots.txt가 없거나 못 읽었을 때)
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// path(쿼리 포함)가 허용되는지. 가장 긴 패턴이 이기고, 길이가 같으면 Allow 우선
+    pub fn allowed(&self, path: &str) -> bool {
+        let mut best: Option<(usize, bool)> = None;
+        for (allow, pat) in &self.rules {
+            if pat.is_empty() || !pattern_match(pat, path) {
+                continue;
+            }
+            let len = pat.len();
+            if best.is_none_or(|(l, _)| len > l || (len == l && *allow)) {
+                best = Some((len, *allow));
+            }
+        }
+        best.is_none_or(|(_, allow)| allow)
+    }
+}
+
+/// robots.txt의 User-agent 값이 보내는 UA에 해당하는지(대소문자 무시)
+/// 제품 토큰(첫 "/" 또는 공백 앞, "Mozilla/5.0 …" → "mozilla")이 같거나 UA 문자열에 그 이름이 들어 있으면 해당
+fn agent_matches(group_agent: &str, user_agent: &str) -> bool {
+    let group = group_agent.trim().to_ascii_lowercase();
+    if group.is_empty() || group == "*" {
+        return false;
+    }
+    let ua = user_agent.to_ascii_lowercase();
+    let product = ua.split(['/', ' ']).next().unwrap_or_default();
+    product == group || ua.contains(&group)
+}
+
+/// robots 패턴 매칭: 앞에서부터 일치, `*`는 임의 문자열, 끝의 `$`는 경로 끝 고정
+fn pattern_match(pat: &str, path: &str) -> bool {
+    let (pat, anchored) = match pat.strip_suffix('$') {
+        Some(p) => (p, true),
+        None => (pat, false),
+    };
+    let parts: Vec<&str> = pat.split('*').collect();
+    let Some(mut rest) = path.strip_prefix(parts[0]) else { return false };
+    let last = parts.len() - 1;
+    for (i, part) in parts.iter().enumerate().skip(1) {
+        if i == last && anchored {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
+/// (origin, UA)별 규칙 캐시. 호스트마다 처음 물어볼 때 한 번만 받음
+/// 맵 잠금은 셀을 꺼낼 때만 잡고, 받는 동안은 그 호스트의 OnceCell에서만 기다림(다른 호스트는 막지 않음)
+type Cell = Arc<OnceCell<Arc<Rules>>>;
+static CACHE: Lazy<Mutex<HashMap<(String, String), Cell>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// url을 robots.txt가 허용하는지(해석할 수 없는 URL은 허용)
+/// client/user_agent는 그 사이트에 실제로 요청을 보내는 것과 같은 값(프록시/UA가 수집 요청과 일치)
+pub async fn allowed(client: &reqwest::Client, user_agent: &str, url: &str) -> bool {
+    let Ok(u) = Url::parse(url) else { return true };
+    if u.host_str().is_none() {
+        return true;
+    }
+    let origin = u.origin().ascii_serialization();
+    let cell: Cell = CACHE
+        .lock()
+        .unwrap()
+        .entry((origin.clone(), user_agent.to_string()))
+        .or_default()
+        .clone();
+    let rules = cell
+        .get_or_init(|| async { Arc::new(fetch_rules(client, &origin, user_agent).await) })
+        .await;
+    let path = match u.query() {
+        Some(q) => format!("{}?{q}", u.path()),
+        None => u.path().to_string(),
+    };
+    rules.allowed(&path)
+}
+
+/// {origin}/robots.txt를 받아 파싱. 요청 실패/2xx가 아닌 응답은 모두 허용(fail-open)
+async fn fetch_rules(client: &reqwest::Client, origin: &str, user_agent: &str) -> Rules {
+    let robots_url = format!("{origin}/robots.txt");
+    let req = client
+        .get(&robots_url)
+        .header(reqwest::header::USER_AGENT, user_agent)
+        .timeout(FETCH_TIMEOUT);
+    match req.send().await {
+        Ok(resp) if resp.status().is_success() => match resp.text().await {
+            Ok(body) => {
+                let rules = Rules::parse(&body, user_agent);
+                info!(url = %robots_url, rules = rules.rules.len(), "loaded robots.txt");
+                rules
+            }
+            Err(e) => {
+                warn!(url = %robots_url, error = %e, "robots.txt unreadable; allowing all");
+                Rules::allow_all()
+            }
+        },
+        Ok(resp) => {
+            info!(url = %robots_url, status = %resp.status(), "no robots.txt; allowing all");
+            Rules::allow_all()
+        }
+        Err(e) => {
+            warn!(url = %robots_url, error = %e, "robots.txt fetch failed; allowing all");
+            Rules::allow_all()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockServer, Reply};
+    use std::time::Instant;
+
+    const BROWSER_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) Chrome/127.0.0.0 Safari/537.36";
+
+    #[test]
+    fn longest_match_wins_and_allow_wins_ties() {
+        let rules = Rules::parse("User-agent: *\nDisallow: /private\nAllow: /private/open\nDisallow: /a\nAllow: /a\n", "x");
+        assert!(!rules.allowed("/private/x"));
+        assert!(rules.allowed("/private/open/1"));
+        assert!(rules.allowed("/a"));
+        assert!(rules.allowed("/public"));
+    }
+
+    #[test]
+    fn wildcards_and_end_anchor() {
+        let rules = Rules::parse("User-agent: *\nDisallow: /*.php$\nDisallow: /search*q=\n", "x");
+        assert!(!rules.allowed("/index.php"));
+        assert!(rules.allowed("/index.php?x=1"));
+        assert!(!rules.allowed("/search/all?q=ai"));
+        assert!(rules.allowed("/search/all"));
+    }
+
+    #[test]
+    fn empty_disallow_allows_everything() {
+        assert!(Rules::parse("User-agent: *\nDisallow:\n", "x").allowed("/anything"));
+    }
+
+    #[test]
+    fn group_is_chosen_by_the_user_agent_actually_sent() {
+        let body = "User-agent: Mozilla\nDisallow: /browser-only\n\nUser-agent: campuspick-filter\nDisallow: /bots\n\nUser-agent: *\nDisallow: /\n";
+        // 브라우저 UA → Mozilla 그룹
+        let browser = Rules::parse(body, BROWSER_UA);
+        assert!(!browser.allowed("/browser-only"));
+        assert!(browser.allowed("/bots"));
+        // 우리 봇 UA → 그 이름의 그룹
+        let bot = Rules::parse(body, "campuspick-filter/0.6.0 (+contact@example.com)");
+        assert!(!bot.allowed("/bots"));
+        assert!(bot.allowed("/browser-only"));
+        // 맞는 그룹이 없으면 "*"
+        let other = Rules::parse(body, "dacon-api-filter/2.0");
+        assert!(!other.allowed("/x"));
+    }
+
+    #[test]
+    fn agent_names_match_case_insensitively_and_by_substring() {
+        assert!(agent_matches("Googlebot", "Mozilla/5.0 (compatible; Googlebot/2.1)"));
+        assert!(agent_matches("MOZILLA", BROWSER_UA));
+        assert!(!agent_matches("*", BROWSER_UA));
+        assert!(!agent_matches("bingbot", BROWSER_UA));
+    }
+
+    #[tokio::test]
+    async fn fetches_once_per_origin_with_the_callers_user_agent() {
+        let server = MockServer::start(vec![("/robots.txt", vec![Reply::ok("User-agent: *\nDisallow: /secret\n")])]).await;
+        let client = reqwest::Client::new();
+        assert!(!allowed(&client, "probe-a/1.0", &server.url("/secret/1")).await);
+        assert!(allowed(&client, "probe-a/1.0", &server.url("/open")).await);
+        let hits = server.requests_to("/robots.txt");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].headers.get("user-agent").map(String::as_str), Some("probe-a/1.0"));
+    }
+
+    #[tokio::test]
+    async fn missing_or_slow_robots_fails_open() {
+        let server = MockServer::start(vec![]).await; // 모든 경로 404
+        let client = reqwest::Client::new();
+        assert!(allowed(&client, "probe-b/1.0", &server.url("/x")).await);
+        assert!(allowed(&client, "probe-b/1.0", "not a url").await);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn slow_host_does_not_block_other_hosts() {
+        let slow = MockServer::start(vec![("/robots.txt", vec![Reply::ok("").delayed(Duration::from_millis(1500))])]).await;
+        let fast = MockServer::start(vec![("/robots.txt", vec![Reply::ok("User-agent: *\nDisallow: /no\n")])]).await;
+        let client = reqwest::Client::new();
+
+        let slow_url = slow.url("/x");
+        let slow_client = client.clone();
+        let pending = tokio::spawn(async move { allowed(&slow_client, "probe-c/1.0", &slow_url).await });
+        // 느린 호스트가 robots.txt를 받는 중인지 확인한 뒤 다른 호스트 질의
+        while slow.requests_to("/robots.txt").is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let started = Instant::now();
+        assert!(!allowed(&client, "probe-c/1.0", &fast.url("/no")).await);
+        assert!(started.elapsed() < Duration::from_millis(1000), "waited {:?}", started.elapsed());
+        assert!(pending.await.unwrap());
+    }
+}
@@ -0,0 +1,126 @@
+// src/rss_ingest.rs
+use anyhow::{bail, Context, Result};
+use atom_syndication as atom;
+use chrono::DateTime;
+use rss::Channel;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::http::HttpOptions;
+use crate::source::CollectStats;
+use crate::notice::{Notice, Source, Kind, clean_title, decode_entities, infer_kind_from_label};
+
+/// 재수집할 외부 피드 하나(설정 파일 [[ingest]], env INGEST_FEEDS="라벨=URL,…")
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IngestFeed {
+    /// Source::Custom 라벨이자 소스 이름
+    pub label: String,
+    pub url: String,
+}
+
+/// 같은 호스트의 피드를 여러 개 받을 때 초당 요청 수 상한
+const INGEST_MAX_RPS: f64 = 1.0;
+
+/// 외부 RSS(실패하면 Atom으로 재시도)를 읽어 Notice로 변환
+/// - 날짜: pubDate(Atom은 published → updated)를 start로 사용, end는 없음
+/// - kind: 제목 + 카테고리에 infer_kind_from_label(기본 Contest)
+pub async fn fetch_rss_as_notices(
+    feed_url: &str,
+    source_label: &str,
+    http: &HttpOptions,
+    stats: &CollectStats,
+) -> Result<Vec<Notice>> {
+    let client = crate::http::client_builder(http)?
+        .user_agent(crate::http::USER_AGENT)
+        .build()?;
+    stats.add_rate_limit_wait(crate::rate_limit::throttle(feed_url, INGEST_MAX_RPS).await);
+    let list_started = std::time::Instant::now();
+    let resp = client.get(feed_url).send().await?;
+    let status = resp.status();
+    let body = resp.bytes().await;
+    stats.add_http(body.as_ref().map_or(0, |b| b.len()));
+    if !status.is_success() {
+        bail!("HTTP {status} for {feed_url}");
+    }
+    let body = body?;
+    stats.add_list_time(list_started.elapsed());
+    let parse_started = std::time::Instant::now();
+    let notices = parse_feed(&body, source_label).with_context(|| format!("parse {feed_url}"));
+    stats.add_parse_time(parse_started.elapsed());
+    notices
+}
+
+/// 피드 본문 → Notice 목록(RSS 2.0 → Atom 순서로 시도)
+pub fn parse_feed(body: &[u8], source_label: &str) -> Result<Vec<Notice>> {
+    let source = Source::Custom(source_label.to_string());
+
+    if let Ok(channel) = Channel::read_from(body) {
+        return Ok(channel
+            .items()
+            .iter()
+            .filter_map(|it| {
+                let title = it.title()?.trim().to_string();
+                let categories: Vec<&str> = it.categories().iter().map(|c| c.name()).collect();
+                let start = it.pub_date().and_then(rfc2822_to_ymd);
+                Some(to_notice(&source, &title, it.link().unwrap_or_default(), &categories, start))
+            })
+            .collect());
+    }
+
+    let feed = atom::Feed::read_from(body).context("neither RSS nor Atom")?;
+    Ok(feed
+        .entries()
+        .iter()
+        .map(|e| {
+            let link = e.links().first().map(|l| l.href()).unwrap_or_default();
+            let categories: Vec<&str> = e.categories().iter().map(|c| c.term()).collect();
+            let start = Some(e.published().unwrap_or(e.updated()).format("%Y-%m-%d").to_string());
+            to_notice(&source, e.title().trim(), link, &categories, start)
+        })
+        .collect())
+}
+
+fn to_notice(source: &Source, title: &str, link: &str, categories: &[&str], start: Option<String>) -> Notice {
+    let label = format!("{title} {}", categories.join(" "));
+    Notice {
+        source: source.clone(),
+        kind: infer_kind_from_label(&label, Kind::Contest),
+        title: clean_title(&decode_entities(title), source),
+        raw_title: title.to_string(),
+        url: link.trim().to_string(),
+        start,
+        end: None,
+        organizer: None,
+        field: None,
+        end_approx: false,
+        summary: None,
+        series_id: None,
+        changed_at: None,
+    }
+}
+
+fn rfc2822_to_ymd(s: &str) -> Option<String> {
+    DateTime::parse_from_rfc2822(s.trim()).ok().map(|d| d.format("%Y-%m-%d").to_string())
+}
+
+// === Crawler 구현 ===
+pub struct RssIngestCrawler {
+    pub feed: IngestFeed,
+    pub timeout: Duration,
+    pub http: HttpOptions,
+}
+
+#[async_trait::async_trait(?Send)]
+impl crate::source::Crawler for RssIngestCrawler {
+    fn name(&self) -> &str { &self.feed.label }
+    fn site_url(&self) -> &str { &self.feed.url }
+    fn own_feed(&self) -> bool { false }
+    fn timeout(&self) -> Duration { self.timeout }
+
+    async fn collect(&self, stats: &CollectStats) -> Result<Vec<Notice>> {
+        let notices = fetch_rss_as_notices(&self.feed.url, &self.feed.label, &self.http, stats).await?;
+        stats.add_raw(notices.len());
+        Ok(notices)
+    }
+}
@@ -1,74 +1,465 @@
-// src/rss_merged.rs
-use anyhow::Result;
-use chrono::NaiveDate;
-use std::collections::HashSet;
-
-use crate::notice::{Notice, Kind};
-use crate::rss_write::write_rss_feed;
-
-/// 여러 소스에서 받은 Vec<Notice>들을 합쳐서
-/// - URL 기준 중복 제거
-/// - 날짜 최신순 정렬
-/// - tie-breaker: kind → title
-pub fn merge_notices(sources: Vec<Vec<Notice>>) -> Vec<Notice> {
-    // 1) 평탄화
-    let mut all: Vec<Notice> = sources.into_iter().flatten().collect();
-
-    // 2) URL 기준 dedup (URL이 비어있으면 title+kind+source)
-    let mut seen = HashSet::new();
-    all.retain(|n| {
-        let key = if n.url.is_empty() {
-            format!("{}-{:?}-{:?}", n.title, n.source, n.kind)
-        } else {
-            n.url.clone()
-        };
-        seen.insert(key)
-    });
-
-    // 3) 정렬: start→end 최신순, 같으면 Kind→title
-    all.sort_by(|a, b| {
-        let ka = date_key(a);
-        let kb = date_key(b);
-        match kb.cmp(&ka) {
-            std::cmp::Ordering::Equal => {
-                // Contest 먼저, Activity 나중
-                kind_rank(&a.kind).cmp(&kind_rank(&b.kind)).then(a.title.cmp(&b.title))
-            }
-            other => other,
-        }
-    });
-
-    all
-}
-
-/// 정렬용 날짜 키: start(우선) → end → None
-fn date_key(n: &Notice) -> Option<NaiveDate> {
-    n.start
-        .as_deref()
-        .and_then(parse_ymd)
-        .or_else(|| n.end.as_deref().and_then(parse_ymd))
-}
-
-fn parse_ymd(s: &str) -> Option<NaiveDate> {
-    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
-}
-
-/// Kind 우선순위: Contest(0) → Activity(1)
-fn kind_rank(k: &Kind) -> u8 {
-    match k {
-        Kind::Contest  => 0,
-        Kind::Activity => 1,
-    }
-}
-
-/// 합치고 바로 RSS 파일로 저장하는 헬퍼
-pub fn write_merged_rss(
-    sources: Vec<Vec<Notice>>,
-    channel_title: &str,
-    channel_link: &str,
-    channel_desc: &str,
-    output_file: &str,
-) -> Result<()> {
-    let merged = merge_notices(sources);
-    write_rss_feed(&merged, channel_title, channel_link, channel_desc, output_file)
-}
\ No newline at end of file
+// src/rss_merged.rs
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate};
+use rss::{Channel, Item};
+use rss::extension::atom::Link as AtomLink;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::BufReader;
+use tracing::info;
+
+use crate::notice::{Notice, Kind};
+use crate::rss_write::{
+    atom_path, entry_from_item, write_atom_entries, write_atom_feed, write_channel, write_feeds,
+    write_rss_feed_with_links, FeedFormat, FeedOptions,
+};
+
+/// 통합 목록 정렬 방식(MERGE_SORT=latest|opening_soon)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeSort {
+    /// start→end 최신순(기본)
+    #[default]
+    Latest,
+    /// 시작 예정 항목을 가까운 시작일 순으로 먼저
+    OpeningSoon,
+}
+
+/// 통합 피드 옵션(설정 파일 [merge] 섹션)
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MergeOptions {
+    pub sort: MergeSort,
+    /// 같은 주최 최대 건수
+    pub max_per_org: Option<usize>,
+    /// 통합 피드 최대 항목 수(중복 제거/정렬 뒤에 자름, None이면 무제한)
+    pub max_items: Option<usize>,
+    /// max_items를 넘는 항목을 merged_rss_2.xml, _3.xml …로 이어 씀(RFC 5005 paged feed)
+    /// RSS만 페이지로 나눔. Atom(FEED_FORMAT=atom|both)은 첫 페이지 분량만 merged_atom.xml 하나로 씀
+    pub paged: bool,
+}
+
+/// 여러 소스에서 받은 Vec<Notice>들을 합쳐서
+/// - URL 기준 중복 제거(Notice::url_key)
+/// - 플랫폼 간 중복 제거(Notice::dedup_key, 먼저 나온 항목 유지)
+/// - 날짜 최신순 정렬
+/// - tie-breaker: kind → title
+pub fn merge_notices(sources: Vec<Vec<Notice>>, sort: MergeSort, strip: &[String]) -> Vec<Notice> {
+    // 1) 평탄화
+    let mut all: Vec<Notice> = sources.into_iter().flatten().collect();
+
+    // 2) URL 기준 → 플랫폼 간 dedup (main의 통합 목록과 같은 키)
+    let mut seen = HashSet::new();
+    all.retain(|n| seen.insert(n.url_key(strip)));
+    let mut seen = HashSet::new();
+    all.retain(|n| seen.insert(n.dedup_key()));
+
+    // OpeningSoon: 아직 시작 전인 항목을 가까운 시작일 순으로 먼저
+    if sort == MergeSort::OpeningSoon {
+        sort_opening_soon(&mut all, crate::clock::today());
+    } else {
+        // 3) 정렬: start→end 최신순, 같으면 Kind→title
+        all.sort_by(|a, b| {
+            let ka = date_key(a);
+            let kb = date_key(b);
+            match kb.cmp(&ka) {
+                std::cmp::Ordering::Equal => {
+                    // kind_rank 순(공모전 → 대외활동 → 교육 → 채용)
+                    kind_rank(&a.kind).cmp(&kind_rank(&b.kind)).then(a.title.cmp(&b.title))
+                }
+                other => other,
+            }
+        });
+    }
+
+    all
+}
+
+/// 같은 주최의 항목을 최대 `max`건까지만 유지(마감이 가까운 것 우선, 기존 순서 보존)
+/// 주최가 없는 항목은 제한하지 않음
+pub fn cap_per_organizer(all: &mut Vec<Notice>, max: usize) {
+    let mut by_org: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, n) in all.iter().enumerate() {
+        if let Some(org) = n.organizer.as_deref().map(|o| o.trim().to_lowercase()).filter(|o| !o.is_empty()) {
+            by_org.entry(org).or_default().push(i);
+        }
+    }
+
+    let mut drop: HashSet<usize> = HashSet::new();
+    for idxs in by_org.values_mut() {
+        if idxs.len() <= max { continue; }
+        idxs.sort_by_key(|&i| {
+            let end = all[i].end.as_deref().and_then(parse_ymd);
+            (end.is_none(), end)
+        });
+        drop.extend(idxs[max..].iter().copied());
+    }
+
+    let mut i = 0usize;
+    all.retain(|_| {
+        let keep = !drop.contains(&i);
+        i += 1;
+        keep
+    });
+}
+
+/// 시작 예정(start > today) 항목을 start 오름차순으로 먼저,
+/// 이미 열린 항목은 그 뒤에 마감일 오름차순으로 정렬
+fn sort_opening_soon(all: &mut [Notice], today: NaiveDate) {
+    all.sort_by(|a, b| {
+        let sa = a.start.as_deref().and_then(parse_ymd).filter(|d| *d > today);
+        let sb = b.start.as_deref().and_then(parse_ymd).filter(|d| *d > today);
+        let ea = a.end.as_deref().and_then(parse_ymd);
+        let eb = b.end.as_deref().and_then(parse_ymd);
+        sa.is_none().cmp(&sb.is_none())
+            .then(sa.cmp(&sb))
+            .then(ea.is_none().cmp(&eb.is_none()))
+            .then(ea.cmp(&eb))
+            .then(a.title.cmp(&b.title))
+    });
+}
+
+/// 정렬용 날짜 키: start(우선) → end → None
+fn date_key(n: &Notice) -> Option<NaiveDate> {
+    n.start
+        .as_deref()
+        .and_then(parse_ymd)
+        .or_else(|| n.end.as_deref().and_then(parse_ymd))
+}
+
+fn parse_ymd(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+}
+
+/// 마감이 가까운 n개(마감일 없는 항목은 뒤로)를 골라 원래 순서대로 돌려줌
+pub fn nearest_deadline(all: &[Notice], n: usize) -> Vec<Notice> {
+    let mut idx: Vec<usize> = (0..all.len()).collect();
+    idx.sort_by(|&a, &b| {
+        all[a].end.is_none().cmp(&all[b].end.is_none()).then(all[a].end.cmp(&all[b].end))
+    });
+    idx.truncate(n);
+    idx.sort_unstable();
+    idx.into_iter().map(|i| all[i].clone()).collect()
+}
+
+/// Kind 우선순위: Contest(0) → Activity(1) → Education(2) → Recruit(3)
+fn kind_rank(k: &Kind) -> u8 {
+    match k {
+        Kind::Contest   => 0,
+        Kind::Activity  => 1,
+        Kind::Education => 2,
+        Kind::Recruit   => 3,
+    }
+}
+
+/// 합치고 바로 RSS(FEED_FORMAT에 따라 Atom도) 파일로 저장하는 헬퍼
+pub fn write_merged_rss(
+    sources: Vec<Vec<Notice>>,
+    channel_title: &str,
+    channel_link: &str,
+    channel_desc: &str,
+    output_file: &str,
+    merge: &MergeOptions,
+    feed: &FeedOptions,
+) -> Result<()> {
+    let mut merged = merge_notices(sources, merge.sort, &feed.strip_params);
+    if let Some(max) = merge.max_per_org {
+        cap_per_organizer(&mut merged, max);
+    }
+
+    // 페이지 나누기는 RSS만(Atom은 첫 페이지 분량만 씀)
+    // 한 파일로 쓰는 경우에도 이전 실행이 남긴 _2.xml 이후 페이지는 지움(구독자가 옛 페이지를 계속 읽지 않도록)
+    let max = match merge.max_items {
+        Some(max) if merge.paged && merged.len() > max && feed.format != FeedFormat::Atom => max,
+        cap => {
+            if let Some(max) = cap {
+                merged.truncate(max);
+            }
+            write_feeds(&merged, channel_title, channel_link, channel_desc, output_file, feed)?;
+            remove_stale_pages(output_file, 2);
+            return Ok(());
+        }
+    };
+
+    let pages: Vec<&[Notice]> = merged.chunks(max).collect();
+    let paths: Vec<String> = (1..=pages.len()).map(|i| page_path(output_file, i)).collect();
+    for (i, page) in pages.iter().enumerate() {
+        let mut links = Vec::new();
+        if let Some(next) = paths.get(i + 1) {
+            links.push(page_link("next", next));
+        }
+        if i > 0 {
+            links.push(page_link("previous", &paths[i - 1]));
+        }
+        write_rss_feed_with_links(page, channel_title, channel_link, channel_desc, &paths[i], links, feed)?;
+    }
+    remove_stale_pages(output_file, pages.len() + 1);
+    info!(items = merged.len(), pages = pages.len(), "merged feed paged");
+
+    if feed.format == FeedFormat::Both {
+        write_atom_feed(pages[0], channel_title, channel_link, channel_desc, &atom_path(output_file), feed)?;
+    }
+    Ok(())
+}
+
+/// n번째 페이지 경로: 1은 그대로, 2부터 "merged_rss.xml" → "merged_rss_2.xml"
+fn page_path(output_file: &str, n: usize) -> String {
+    if n == 1 {
+        return output_file.to_string();
+    }
+    match output_file.strip_suffix(".xml") {
+        Some(base) => format!("{base}_{n}.xml"),
+        None => format!("{output_file}_{n}"),
+    }
+}
+
+/// 지금 디스크에 있는 2번째 이후 페이지 파일(history 사본용)
+pub fn extra_pages(output_file: &str) -> Vec<String> {
+    (2..)
+        .map(|n| page_path(output_file, n))
+        .take_while(|p| std::path::Path::new(p).exists())
+        .collect()
+}
+
+/// 이전 실행에서 남은 from번째 이후 페이지 파일 삭제
+fn remove_stale_pages(output_file: &str, from: usize) {
+    let mut n = from;
+    while std::fs::remove_file(page_path(output_file, n)).is_ok() {
+        n += 1;
+    }
+}
+
+/// 같은 디렉터리의 페이지 파일을 가리키는 <atom:link rel=…>(파일명만, 피드 URL 기준 상대 경로)
+fn page_link(rel: &str, path: &str) -> AtomLink {
+    let href = std::path::Path::new(path)
+        .file_name()
+        .map(|f| f.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string());
+    AtomLink {
+        href,
+        rel: rel.to_string(),
+        mime_type: Some("application/rss+xml".to_string()),
+        ..AtomLink::default()
+    }
+}
+
+/// 이미 쓴 RSS 파일들을 다시 합쳐 저장(수집 없이 재통합)
+/// - guid(없으면 link) 기준 중복 제거, pubDate 최신순
+/// - feed.format에 따라 RSS/Atom(atom_path 규칙) 파일을 씀(crawl과 같음)
+/// - 반환값: 쓴 항목 수
+pub fn merge_feed_files(
+    files: &[String],
+    channel_title: &str,
+    channel_link: &str,
+    channel_desc: &str,
+    output_file: &str,
+    feed: &FeedOptions,
+) -> Result<usize> {
+    let mut items: Vec<Item> = Vec::new();
+    for path in files {
+        let reader = BufReader::new(File::open(path).with_context(|| format!("open {path}"))?);
+        let channel = Channel::read_from(reader).with_context(|| format!("parse {path}"))?;
+        items.extend(channel.into_items());
+    }
+
+    let mut seen = HashSet::new();
+    items.retain(|it| {
+        let key = it.guid().map(|g| g.value().to_string())
+            .or_else(|| it.link().map(str::to_string))
+            .unwrap_or_else(|| it.title().unwrap_or_default().to_string());
+        seen.insert(key)
+    });
+
+    let pub_key = |it: &Item| it.pub_date().and_then(|d| DateTime::parse_from_rfc2822(d).ok());
+    items.sort_by(|a, b| pub_key(b).cmp(&pub_key(a)).then(a.title().cmp(&b.title())));
+
+    let count = items.len();
+    if feed.format != FeedFormat::Rss {
+        let entries = items.iter().map(entry_from_item).collect();
+        write_atom_entries(entries, channel_title, channel_link, channel_desc, &atom_path(output_file), feed)?;
+    }
+    if feed.format != FeedFormat::Atom {
+        write_channel(items, channel_title, channel_link, channel_desc, output_file, Vec::new(), feed)?;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::Source;
+
+    fn notice(id: u32) -> Notice {
+        Notice {
+            source: Source::Dacon,
+            kind: Kind::Contest,
+            title: format!("대회 {id}"),
+            raw_title: format!("대회 {id}"),
+            url: format!("https://dacon.io/competitions/official/{id}/overview"),
+            start: Some(format!("2026-10-{:02}", id)),
+            end: None,
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    fn dated(title: &str, start: &str, end: &str) -> Notice {
+        Notice { title: title.into(), url: format!("https://x.example/{title}"), start: Some(start.into()), end: Some(end.into()), ..notice(1) }
+    }
+
+    fn titles(list: &[Notice]) -> Vec<&str> {
+        list.iter().map(|n| n.title.as_str()).collect()
+    }
+
+    #[test]
+    fn opening_soon_puts_upcoming_starts_first() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let list = vec![
+            dated("열림-늦은마감", "2026-10-01", "2026-10-30"),
+            dated("열림-이른마감", "2026-10-10", "2026-10-20"),
+            dated("모레 시작", "2026-10-16", "2026-11-01"),
+            dated("내일 시작", "2026-10-15", "2026-11-20"),
+        ];
+        let sorted = merge_notices(vec![list.clone()], MergeSort::OpeningSoon, &crate::notice::default_strip_params());
+        assert_eq!(titles(&sorted), ["내일 시작", "모레 시작", "열림-이른마감", "열림-늦은마감"]);
+        // 기본(latest)은 start 최신순
+        let latest = merge_notices(vec![list], MergeSort::Latest, &crate::notice::default_strip_params());
+        assert_eq!(titles(&latest), ["모레 시작", "내일 시작", "열림-이른마감", "열림-늦은마감"]);
+        crate::clock::set_today(None);
+    }
+
+    fn write(out: &str, count: u32, merge: &MergeOptions) {
+        let notices: Vec<Notice> = (1..=count).map(notice).collect();
+        write_merged_rss(vec![notices], "t", "https://example.com", "d", out, merge, &FeedOptions::default()).unwrap();
+    }
+
+    #[test]
+    fn pages_link_next_and_previous() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("merged_rss.xml").display().to_string();
+        let merge = MergeOptions { max_items: Some(2), paged: true, ..MergeOptions::default() };
+        write(&out, 5, &merge);
+        let second = std::fs::read_to_string(page_path(&out, 2)).unwrap();
+        assert!(second.contains(r#"rel="next""#) && second.contains("merged_rss_3.xml"));
+        assert!(second.contains(r#"rel="previous""#) && second.contains("merged_rss.xml"));
+        assert!(std::path::Path::new(&page_path(&out, 3)).exists());
+        assert!(!std::path::Path::new(&page_path(&out, 4)).exists());
+    }
+
+    #[test]
+    fn stale_pages_are_removed_on_every_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("merged_rss.xml").display().to_string();
+        let paged = MergeOptions { max_items: Some(2), paged: true, ..MergeOptions::default() };
+        for merge in [
+            MergeOptions::default(),
+            MergeOptions { max_items: Some(2), ..MergeOptions::default() },
+            MergeOptions { max_items: Some(10), paged: true, ..MergeOptions::default() },
+        ] {
+            write(&out, 5, &paged);
+            assert!(std::path::Path::new(&page_path(&out, 3)).exists());
+            write(&out, 5, &merge);
+            assert!(std::path::Path::new(&out).exists());
+            assert!(!std::path::Path::new(&page_path(&out, 2)).exists(), "{merge:?}");
+            assert!(!std::path::Path::new(&page_path(&out, 3)).exists(), "{merge:?}");
+        }
+    }
+
+    #[test]
+    fn merge_feed_files_honors_atom_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a_rss.xml").display().to_string();
+        let b = dir.path().join("b_rss.xml").display().to_string();
+        let feed = FeedOptions::default();
+        write_feeds(&[notice(1), notice(2)], "a", "https://example.com", "d", &a, &feed).unwrap();
+        write_feeds(&[notice(2), notice(3)], "b", "https://example.com", "d", &b, &feed).unwrap();
+
+        let out = dir.path().join("merged_rss.xml").display().to_string();
+        let atom_only = FeedOptions { format: FeedFormat::Atom, ..FeedOptions::default() };
+        let n = merge_feed_files(&[a, b], "m", "https://example.com", "d", &out, &atom_only).unwrap();
+        assert_eq!(n, 3);
+        assert!(!std::path::Path::new(&out).exists());
+        let body = std::fs::read_to_string(atom_path(&out)).unwrap();
+        let parsed: atom_syndication::Feed = body.parse().unwrap();
+        let mut titles: Vec<String> = parsed.entries().iter().map(|e| e.title().value.clone()).collect();
+        titles.sort();
+        assert_eq!(titles.len(), 3);
+        assert!(titles[0].starts_with("대회 1"), "{titles:?}");
+    }
+
+    #[test]
+    fn cap_per_organizer_keeps_nearest_deadlines_and_exempts_unknown() {
+        let org = |title: &str, end: &str, organizer: Option<&str>| Notice {
+            organizer: organizer.map(str::to_string),
+            ..dated(title, "2026-10-01", end)
+        };
+        let mut list = vec![
+            org("a", "2026-11-30", Some("삼성")),
+            org("b", "2026-10-20", Some("삼성 ")),
+            org("c", "2026-10-25", Some("네이버")),
+            org("d", "2026-10-18", Some("삼성")),
+            org("e", "2026-10-30", None),
+            org("f", "2026-10-31", None),
+            org("g", "2026-10-31", Some("")),
+        ];
+        cap_per_organizer(&mut list, 2);
+        // 삼성 3건 중 마감이 먼 a만 빠지고, 주최 없는 항목은 모두 남음. 순서는 그대로
+        assert_eq!(titles(&list), ["b", "c", "d", "e", "f", "g"]);
+
+        cap_per_organizer(&mut list, 1);
+        assert_eq!(titles(&list), ["c", "d", "e", "f", "g"]);
+    }
+
+    #[test]
+    fn merged_and_paged_writes_leave_no_tmp_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("merged_rss.xml").display().to_string();
+        let feed = FeedOptions { format: crate::rss_write::FeedFormat::Both, verify: true, ..FeedOptions::default() };
+        let notices: Vec<Notice> = (1..=5).map(notice).collect();
+        let paged = MergeOptions { max_items: Some(2), paged: true, ..MergeOptions::default() };
+        write_merged_rss(vec![notices], "t", "https://example.com", "d", &out, &paged, &feed).unwrap();
+
+        let mut names: Vec<String> =
+            std::fs::read_dir(dir.path()).unwrap().map(|e| e.unwrap().file_name().to_string_lossy().into_owned()).collect();
+        names.sort();
+        assert_eq!(names, ["merged_atom.xml", "merged_rss.xml", "merged_rss_2.xml", "merged_rss_3.xml"]);
+    }
+
+    #[test]
+    fn same_date_items_are_ordered_by_kind_rank() {
+        let with_kind = |title: &str, kind: Kind| Notice { kind, ..dated(title, "2026-10-01", "2026-10-30") };
+        let merged = merge_notices(
+            vec![vec![
+                with_kind("r", Kind::Recruit),
+                with_kind("e", Kind::Education),
+                with_kind("a", Kind::Activity),
+                with_kind("c", Kind::Contest),
+            ]],
+            MergeSort::Latest,
+            &crate::notice::default_strip_params(),
+        );
+        assert_eq!(titles(&merged), ["c", "a", "e", "r"]);
+    }
+
+    #[test]
+    fn limit_total_keeps_nearest_deadlines_in_original_order() {
+        let due = |title: &str, end: Option<&str>| Notice { end: end.map(str::to_string), ..dated(title, "2026-10-01", "") };
+        let all = vec![
+            due("late", Some("2026-12-01")),
+            due("none", None),
+            due("soon", Some("2026-10-15")),
+            due("mid", Some("2026-10-30")),
+            due("sooner", Some("2026-10-14")),
+        ];
+        assert_eq!(titles(&nearest_deadline(&all, 3)), ["soon", "mid", "sooner"]);
+        // 마감일 없는 항목은 맨 뒤, n이 전체보다 크면 전부
+        assert_eq!(titles(&nearest_deadline(&all, 5)), ["late", "none", "soon", "mid", "sooner"]);
+        assert_eq!(nearest_deadline(&all, 10).len(), 5);
+        assert!(nearest_deadline(&all, 0).is_empty());
+    }
+}
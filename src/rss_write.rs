@@ -1,73 +1,746 @@
-// src/rss_write.rs
-use rss::{ChannelBuilder, ItemBuilder, CategoryBuilder};
-use std::fs::File;
-use std::io::Write;
-use chrono::{NaiveDate, Datelike, Utc, TimeZone};
-use anyhow::Result;
-
-use crate::notice::{Notice, Kind}; 
-
-pub fn write_rss_feed(
-    notices: &[Notice],
-    channel_title: &str,
-    channel_link: &str,
-    channel_desc: &str,
-    output_file: &str,
-) -> Result<()> {
-    let items = notices.iter().map(|n| {
-        // pubDate: start → end → now
-        let pub_date = n
-            .start.as_ref()
-            .and_then(|d| ymd_to_rfc2822(d))
-            .or_else(|| n.end.as_ref().and_then(|d| ymd_to_rfc2822(d)))
-            .or_else(|| Some(Utc::now().to_rfc2822()));
-
-        // 본문
-        let description = format!(
-            "주최: {}<br>기간: {} ~ {}<br>분야: {}",
-            n.organizer.as_deref().unwrap_or("-"),
-            n.start.as_deref().unwrap_or("-"),
-            n.end.as_deref().unwrap_or("-"),
-            n.field.as_deref().unwrap_or("-")
-        );
-
-        // category: kind + source (enum → 라벨)
-        let kind_label = match &n.kind {
-            Kind::Contest  => "공모전",
-            Kind::Activity => "대외활동",
-        };
-        // Source는 Debug가 파생되어 있다고 가정(기존 코드 동일)
-        let source_label = format!("{:?}", n.source);
-
-        let categories = vec![
-            CategoryBuilder::default().name(kind_label.to_string()).build(),
-            CategoryBuilder::default().name(source_label).build(),
-        ];
-
-        ItemBuilder::default()
-            .title(Some(n.title.clone()))
-            .link(Some(n.url.clone()))
-            .description(Some(description))
-            .pub_date(pub_date)
-            .categories(categories)
-            .build()
-    }).collect::<Vec<_>>();
-
-    let channel = ChannelBuilder::default()
-        .title(channel_title)
-        .link(channel_link)
-        .description(channel_desc)
-        .items(items)
-        .build();
-
-
-    let mut file = File::create(output_file)?;
-    file.write_all(channel.to_string().as_bytes())?;
-    Ok(())
-}
-
-fn ymd_to_rfc2822(ymd: &str) -> Option<String> {
-    let date = NaiveDate::parse_from_str(ymd, "%Y-%m-%d").ok()?;
-    let dt = Utc.with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0).single()?;
-    Some(dt.to_rfc2822())
-}
+// src/rss_write.rs
+use rss::{Channel, ChannelBuilder, ItemBuilder, CategoryBuilder, GuidBuilder, Item};
+use rss::extension::atom::{AtomExtension, Link as AtomLink};
+use std::fs::File;
+use std::io::BufReader;
+use chrono::{DateTime, NaiveDate, Datelike, Utc, TimeZone};
+use anyhow::{bail, Result};
+use atom_syndication as atom;
+use serde::Deserialize;
+use tracing::{error, warn};
+
+use crate::atomic_write::{write_atomic, write_atomic_checked};
+use crate::feed_state;
+use crate::labels::{self, Lang};
+use crate::notice::{self, Notice, is_expired};
+
+/// 피드 출력 형식(FEED_FORMAT=atom|rss|both, 기본 rss)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    Both,
+}
+
+/// 피드 쓰기 옵션(설정 파일 [feed] 섹션, env는 config::Config::apply_env에서 덮어씀)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeedOptions {
+    /// 고정 라벨 언어
+    pub lang: Lang,
+    /// 영어 모드에서 한글 제목에 "(KR)" 표시
+    pub kr_mark: bool,
+    /// 채널 ttl(분)
+    pub ttl_min: Option<u32>,
+    /// 채널 generator 문자열
+    pub generator: String,
+    /// 쓴 뒤 재파싱 검증, 실패 시 이전 파일 복원
+    pub verify: bool,
+    pub format: FeedFormat,
+    /// description에서 값이 없는 항목(주최/기간/분야)을 "-" 대신 생략
+    pub hide_empty: bool,
+    /// 기존 피드에만 있는 항목을 유지할 유예 일수(None이면 매번 새로 씀, feed_state 참고)
+    pub grace_days: Option<i64>,
+    /// 유예 항목을 합친 뒤 최대 항목 수
+    pub grace_max_items: usize,
+    /// 출력 디렉터리가 공개되는 URL(Atom self 링크 = 이 URL + 파일명, 없으면 파일명만)
+    pub public_url: Option<String>,
+    /// guid/Atom id의 URL 정규화에서 지울 파라미터(파일 키가 아니라 Config::url_strip_params에서 채움)
+    #[serde(skip)]
+    pub strip_params: Vec<String>,
+}
+
+impl Default for FeedOptions {
+    fn default() -> Self {
+        Self {
+            lang: Lang::Ko,
+            kr_mark: false,
+            ttl_min: None,
+            generator: default_generator(),
+            verify: false,
+            format: FeedFormat::Rss,
+            hide_empty: false,
+            grace_days: None,
+            grace_max_items: 300,
+            public_url: None,
+            strip_params: notice::default_strip_params(),
+        }
+    }
+}
+
+/// 기본 generator: 크레이트 이름/버전
+pub fn default_generator() -> String {
+    format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+}
+
+/// RSS 경로에 대응하는 Atom 경로: "xxx_rss.xml" → "xxx_atom.xml", 그 외는 ".atom.xml"을 덧붙임
+pub fn atom_path(rss_path: &str) -> String {
+    match rss_path.strip_suffix("rss.xml") {
+        Some(base) => format!("{base}atom.xml"),
+        None => format!("{rss_path}.atom.xml"),
+    }
+}
+
+/// opts.format에 따라 RSS/Atom 파일을 씀(Atom 파일명은 atom_path 규칙)
+pub fn write_feeds(
+    notices: &[Notice],
+    channel_title: &str,
+    channel_link: &str,
+    channel_desc: &str,
+    rss_file: &str,
+    opts: &FeedOptions,
+) -> Result<()> {
+    if opts.format != FeedFormat::Atom {
+        write_rss_feed(notices, channel_title, channel_link, channel_desc, rss_file, opts)?;
+    }
+    if opts.format != FeedFormat::Rss {
+        write_atom_feed(notices, channel_title, channel_link, channel_desc, &atom_path(rss_file), opts)?;
+    }
+    Ok(())
+}
+
+pub fn write_rss_feed(
+    notices: &[Notice],
+    channel_title: &str,
+    channel_link: &str,
+    channel_desc: &str,
+    output_file: &str,
+    opts: &FeedOptions,
+) -> Result<()> {
+    write_feed(notices, channel_title, channel_link, channel_desc, output_file, false, Vec::new(), opts)
+}
+
+/// 채널에 <atom:link>(페이지 피드의 next/previous 등)를 붙여 RSS 저장
+pub fn write_rss_feed_with_links(
+    notices: &[Notice],
+    channel_title: &str,
+    channel_link: &str,
+    channel_desc: &str,
+    output_file: &str,
+    atom_links: Vec<AtomLink>,
+    opts: &FeedOptions,
+) -> Result<()> {
+    write_feed(notices, channel_title, channel_link, channel_desc, output_file, false, atom_links, opts)
+}
+
+/// 아카이브 피드: 마감이 지난 항목에 "expired" 카테고리를 추가
+pub fn write_archive_feed(
+    notices: &[Notice],
+    channel_title: &str,
+    channel_link: &str,
+    channel_desc: &str,
+    output_file: &str,
+    opts: &FeedOptions,
+) -> Result<()> {
+    write_feed(notices, channel_title, channel_link, channel_desc, output_file, true, Vec::new(), opts)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_feed(
+    notices: &[Notice],
+    channel_title: &str,
+    channel_link: &str,
+    channel_desc: &str,
+    output_file: &str,
+    mark_expired: bool,
+    atom_links: Vec<AtomLink>,
+    opts: &FeedOptions,
+) -> Result<()> {
+    let today = crate::clock::today();
+    // 일반 피드만 유예 적용(아카이브는 원래 전체 기록). 유지 항목도 Notice에서 다시 만들어 제목/기간이 오늘 기준
+    let grace = opts.grace_days.filter(|_| !mark_expired);
+    let retained;
+    let notices = match grace {
+        Some(days) => {
+            retained = feed_state::retain_previous(output_file, notices.to_vec(), days, opts.grace_max_items, today, &opts.strip_params);
+            &retained[..]
+        }
+        None => notices,
+    };
+    // 한 건이 잘못되어도 피드 전체를 잃지 않도록 건별로 건너뛰고 기록
+    let mut items: Vec<Item> = Vec::with_capacity(notices.len());
+    let mut skipped = 0usize;
+    for n in notices {
+        match build_item(n, opts, channel_link) {
+            Ok(mut item) => {
+                if mark_expired && is_expired(n, today) {
+                    item.categories.push(CategoryBuilder::default().name("expired".to_string()).build());
+                }
+                items.push(item);
+            }
+            Err(e) => {
+                skipped += 1;
+                warn!(title = %n.title, error = %format!("{e:#}"), "skip item");
+            }
+        }
+    }
+    if skipped > 0 {
+        warn!(file = output_file, skipped, written = items.len(), "items skipped");
+    }
+
+    write_channel(items, channel_title, channel_link, channel_desc, output_file, atom_links, opts)?;
+    if grace.is_some() {
+        feed_state::save(output_file, notices)?;
+    }
+    Ok(())
+}
+
+/// 완성된 Item들로 채널을 만들어 저장(opts.verify면 재파싱 검증)
+/// atom_links가 있으면 채널에 <atom:link>로 추가
+pub fn write_channel(
+    items: Vec<Item>,
+    channel_title: &str,
+    channel_link: &str,
+    channel_desc: &str,
+    output_file: &str,
+    atom_links: Vec<AtomLink>,
+    opts: &FeedOptions,
+) -> Result<()> {
+    let atom_ext = (!atom_links.is_empty()).then_some(AtomExtension { links: atom_links });
+    // 채널 pubDate: 가장 최근 항목 pubDate(항목이 없으면 생략), lastBuildDate: 지금
+    let newest = items
+        .iter()
+        .filter_map(|it| it.pub_date().and_then(|d| DateTime::parse_from_rfc2822(d).ok()))
+        .max()
+        .map(|d| d.to_rfc2822());
+    let channel = ChannelBuilder::default()
+        .title(channel_title)
+        .link(channel_link)
+        .description(channel_desc)
+        .generator(Some(opts.generator.clone()))
+        .docs(Some("https://www.rssboard.org/rss-specification".to_string()))
+        .ttl(opts.ttl_min.map(|t| t.to_string()))
+        .pub_date(newest)
+        .last_build_date(Some(Utc::now().to_rfc2822()))
+        .atom_ext(atom_ext)
+        .items(items)
+        .build();
+
+    // 임시 파일에 쓰고(opts.verify면 재파싱 검증까지) rename. 실패하면 기존 파일은 그대로
+    let expected = channel.items().len();
+    write_atomic_checked(output_file, channel.to_string().as_bytes(), |tmp| {
+        if !opts.verify {
+            return Ok(());
+        }
+        verify_feed(tmp, expected).inspect_err(|e| {
+            error!(file = output_file, error = %format!("{e:#}"), "verify failed; keeping previous file");
+        })
+    })
+}
+
+/// Atom 1.0 피드 쓰기(항목 검증/건너뛰기는 RSS와 동일)
+/// - id: feed_link + "#" + 파일명(같은 링크를 쓰는 병합/분야 피드끼리도 겹치지 않음)
+/// - link rel="self": self_href(output_file, opts)
+pub fn write_atom_feed(
+    notices: &[Notice],
+    feed_title: &str,
+    feed_link: &str,
+    feed_desc: &str,
+    output_file: &str,
+    opts: &FeedOptions,
+) -> Result<()> {
+    let mut entries: Vec<atom::Entry> = Vec::with_capacity(notices.len());
+    let mut skipped = 0usize;
+    for n in notices {
+        match build_entry(n, opts, feed_link) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => {
+                skipped += 1;
+                warn!(title = %n.title, error = %format!("{e:#}"), "skip entry");
+            }
+        }
+    }
+    if skipped > 0 {
+        warn!(file = output_file, skipped, written = entries.len(), "entries skipped");
+    }
+    write_atom_entries(entries, feed_title, feed_link, feed_desc, output_file, opts)
+}
+
+/// 완성된 Entry들로 Atom 피드를 만들어 저장(write_channel의 Atom판)
+pub fn write_atom_entries(
+    entries: Vec<atom::Entry>,
+    feed_title: &str,
+    feed_link: &str,
+    feed_desc: &str,
+    output_file: &str,
+    opts: &FeedOptions,
+) -> Result<()> {
+    // feed updated: 가장 최근 entry updated(없으면 now)
+    let updated = entries
+        .iter()
+        .map(|e| e.updated)
+        .max()
+        .unwrap_or_else(|| Utc::now().fixed_offset());
+
+    let file_name = file_name(output_file);
+    let feed = atom::FeedBuilder::default()
+        .title(feed_title)
+        .id(format!("{feed_link}#{file_name}"))
+        .updated(updated)
+        .subtitle(Some(atom::Text::plain(feed_desc)))
+        .link(atom::LinkBuilder::default().href(feed_link).rel("alternate").build())
+        .link(
+            atom::LinkBuilder::default()
+                .href(self_href(file_name, opts))
+                .rel("self")
+                .mime_type(Some("application/atom+xml".to_string()))
+                .build(),
+        )
+        .generator(Some(atom::GeneratorBuilder::default().value(opts.generator.clone()).build()))
+        .entries(entries)
+        .build();
+
+    let mut body = Vec::new();
+    feed.write_to(&mut body)?;
+    write_atomic(output_file, &body)
+}
+
+/// 경로의 마지막 구성 요소
+fn file_name(output_file: &str) -> &str {
+    std::path::Path::new(output_file)
+        .file_name()
+        .and_then(|f| f.to_str())
+        .unwrap_or(output_file)
+}
+
+/// Atom self 링크: FEED_PUBLIC_URL이 있으면 "URL/파일명", 없으면 파일명(상대 링크)
+fn self_href(file_name: &str, opts: &FeedOptions) -> String {
+    match &opts.public_url {
+        Some(base) => format!("{}/{file_name}", base.trim_end_matches('/')),
+        None => file_name.to_string(),
+    }
+}
+
+/// 쓴 파일을 다시 읽어 RSS로 파싱되는지, 항목 수가 맞는지 확인
+fn verify_feed(output_file: &str, expected_items: usize) -> Result<()> {
+    let reader = BufReader::new(File::open(output_file)?);
+    let parsed = Channel::read_from(reader)?;
+    if parsed.items().len() != expected_items {
+        bail!("item count mismatch: wrote {expected_items}, parsed {}", parsed.items().len());
+    }
+    Ok(())
+}
+
+/// Notice 한 건을 RSS Item으로 변환(검증 실패 시 Err)
+/// - 제목과 URL이 모두 비어 있으면 Err
+/// - URL만 비어 있으면 채널 링크를 대신 사용
+/// - guid는 Notice::stable_guid(permalink 아님)
+fn build_item(n: &Notice, opts: &FeedOptions, placeholder_link: &str) -> Result<Item> {
+    let lang = opts.lang;
+    if n.title.trim().is_empty() && n.url.trim().is_empty() {
+        bail!("empty title and url");
+    }
+    let link = if n.url.trim().is_empty() { placeholder_link.to_string() } else { n.url.clone() };
+
+    // 날짜가 있는데 YYYY-MM-DD가 아니면 잘못된 레코드로 취급
+    for (label, d) in [("start", &n.start), ("end", &n.end)] {
+        if let Some(d) = d
+            && NaiveDate::parse_from_str(d, "%Y-%m-%d").is_err() {
+            bail!("invalid {label} date: {d}");
+        }
+    }
+
+    // pubDate: changed_at(SQLite 변경 이력) → start → end → now
+    let pub_date = changed_at(n)
+        .map(|dt| dt.to_rfc2822())
+        .or_else(|| n.start.as_ref().and_then(|d| ymd_to_rfc2822(d)))
+        .or_else(|| n.end.as_ref().and_then(|d| ymd_to_rfc2822(d)))
+        .or_else(|| Some(Utc::now().to_rfc2822()));
+
+    let description = build_description(n, opts);
+
+    // category: kind + source (enum → 라벨)
+    let kind_label = labels::kind_label(&n.kind, lang);
+    let source_label = n.source.name().to_string();
+
+    let mut categories = vec![
+        CategoryBuilder::default().name(kind_label.to_string()).build(),
+        CategoryBuilder::default().name(source_label).build(),
+    ];
+    // 표준 분야(소스와 무관하게 리더에서 필터링할 수 있도록) + 원문 분야 태그
+    let mut names: Vec<String> = notice::classify_field(&n.title, n.field.as_deref());
+    for tag in n.field_tags() {
+        if !names.iter().any(|c| c.eq_ignore_ascii_case(&tag)) {
+            names.push(tag);
+        }
+    }
+    for name in names {
+        categories.push(CategoryBuilder::default().name(name).build());
+    }
+
+    // 제목은 실행마다 같게 유지(남은 기간은 본문 기간 줄에). 제목이 매일 바뀌면 리더가 유지 항목을 새 글/수정으로 표시
+    let title = labels::mark_korean(&n.title, lang, opts.kr_mark);
+
+    Ok(ItemBuilder::default()
+        .title(Some(title))
+        .link(Some(link))
+        .guid(Some(GuidBuilder::default().value(n.stable_guid(&opts.strip_params)).permalink(false).build()))
+        .description(Some(description))
+        .pub_date(pub_date)
+        .categories(categories)
+        .build())
+}
+
+/// 본문: "주최: …<br>기간: … ~ … (D-5)<br>분야: …" (+ 소개문이 있으면 빈 줄 뒤에)
+/// 남은 기간(Notice::dday_tag)은 제목 대신 기간 줄 끝에
+/// 값이 없으면 "-", hide_empty면 그 줄을 생략(기간은 시작/마감이 모두 없을 때만)
+fn build_description(n: &Notice, opts: &FeedOptions) -> String {
+    let lang = opts.lang;
+    let l = labels::desc_labels(lang);
+    let period = (!opts.hide_empty || n.start.is_some() || n.end.is_some()).then(|| {
+        format!(
+            "{} ~ {}{}{}",
+            n.start.as_deref().unwrap_or("-"),
+            n.end.as_deref().unwrap_or("-"),
+            if n.end_approx { labels::approx_mark(lang) } else { "" },
+            n.dday_tag().map(|t| format!(" {t}")).unwrap_or_default(),
+        )
+    });
+
+    let rows = [
+        (l.organizer, n.organizer.clone()),
+        (l.period, period),
+        (l.field, n.field.clone()),
+    ];
+    let mut desc = rows.into_iter()
+        .filter(|(_, v)| !opts.hide_empty || v.is_some())
+        .map(|(label, v)| format!("{label}: {}", html_escape::encode_text(v.as_deref().unwrap_or("-"))))
+        .collect::<Vec<_>>()
+        .join("<br>");
+    // 본문은 HTML(<br>)이므로 사이트에서 가져온 글자는 이스케이프("<", "&"가 태그/엔티티로 해석되지 않게)
+    if let Some(summary) = &n.summary {
+        desc.push_str("<br><br>");
+        desc.push_str(&html_escape::encode_text(summary));
+    }
+    desc
+}
+
+/// Notice 한 건을 Atom Entry로 변환(build_item과 같은 규칙으로 검증)
+/// - id: 정규화 URL(없으면 피드 링크 + 소스 + 제목 키)이라 실행마다 같은 값
+/// - updated: changed_at → start → end → now (RFC 3339)
+fn build_entry(n: &Notice, opts: &FeedOptions, feed_link: &str) -> Result<atom::Entry> {
+    let item = build_item(n, opts, feed_link)?;
+
+    let id = if n.url.trim().is_empty() {
+        format!(
+            "{feed_link}#{}-{}",
+            n.source.name(),
+            notice::normalize_title_key(&n.title).replace(' ', "-")
+        )
+    } else {
+        notice::normalize_url(&n.url, &opts.strip_params)
+    };
+
+    let updated = changed_at(n)
+        .or_else(|| n.start.as_ref().and_then(|d| ymd_to_datetime(d)))
+        .or_else(|| n.end.as_ref().and_then(|d| ymd_to_datetime(d)))
+        .unwrap_or_else(|| Utc::now().fixed_offset());
+
+    let categories = item
+        .categories()
+        .iter()
+        .map(|c| atom::CategoryBuilder::default().term(c.name()).build())
+        .collect::<Vec<_>>();
+
+    Ok(atom::EntryBuilder::default()
+        .title(item.title().unwrap_or_default())
+        .id(id)
+        .updated(updated)
+        .link(atom::LinkBuilder::default().href(item.link().unwrap_or_default()).rel("alternate").build())
+        .summary(item.description().map(atom::Text::html))
+        .categories(categories)
+        .build())
+}
+
+/// 이미 쓴 RSS Item을 Atom Entry로 변환(merge 서브커맨드처럼 Notice 없이 피드를 다시 쓸 때)
+/// - id: guid → link, updated: pubDate → now
+pub fn entry_from_item(item: &Item) -> atom::Entry {
+    let id = item
+        .guid()
+        .map(|g| g.value().to_string())
+        .or_else(|| item.link().map(str::to_string))
+        .unwrap_or_default();
+    let updated = item
+        .pub_date()
+        .and_then(|d| DateTime::parse_from_rfc2822(d).ok())
+        .unwrap_or_else(|| Utc::now().fixed_offset());
+    let categories = item
+        .categories()
+        .iter()
+        .map(|c| atom::CategoryBuilder::default().term(c.name()).build())
+        .collect::<Vec<_>>();
+
+    atom::EntryBuilder::default()
+        .title(item.title().unwrap_or_default())
+        .id(id)
+        .updated(updated)
+        .link(atom::LinkBuilder::default().href(item.link().unwrap_or_default()).rel("alternate").build())
+        .summary(item.description().map(atom::Text::html))
+        .categories(categories)
+        .build()
+}
+
+/// YYYY-MM-DD → 해당 날짜 00:00 UTC (Atom updated용)
+fn ymd_to_datetime(ymd: &str) -> Option<atom::FixedDateTime> {
+    let date = NaiveDate::parse_from_str(ymd, "%Y-%m-%d").ok()?;
+    let dt = Utc.with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0).single()?;
+    Some(dt.fixed_offset())
+}
+
+/// store::track_changes가 채운 변경 시각(RFC 3339). 없거나 형식이 다르면 None
+fn changed_at(n: &Notice) -> Option<atom::FixedDateTime> {
+    DateTime::parse_from_rfc3339(n.changed_at.as_deref()?).ok()
+}
+
+fn ymd_to_rfc2822(ymd: &str) -> Option<String> {
+    let date = NaiveDate::parse_from_str(ymd, "%Y-%m-%d").ok()?;
+    let dt = Utc.with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0).single()?;
+    Some(dt.to_rfc2822())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::{Kind, Source};
+
+    fn notice() -> Notice {
+        Notice {
+            source: Source::Wevity,
+            kind: Kind::Contest,
+            title: "AI 공모전".into(),
+            raw_title: String::new(),
+            url: "https://www.wevity.com/?c=find&ix=1".into(),
+            start: Some("2026-10-01".into()),
+            end: Some("2026-10-24".into()),
+            organizer: Some("R&D <연구소>".into()),
+            field: None,
+            end_approx: false,
+            summary: Some("상금 <b>1억</b> & 인턴십 <script>alert(1)</script>".into()),
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    #[test]
+    fn description_escapes_scraped_text() {
+        let desc = build_description(&notice(), &FeedOptions::default());
+        assert!(desc.contains("R&amp;D &lt;연구소&gt;"), "{desc}");
+        assert!(desc.contains("상금 &lt;b&gt;1억&lt;/b&gt; &amp; 인턴십 &lt;script&gt;"), "{desc}");
+        assert!(!desc.contains("<script>") && !desc.contains("<b>"), "{desc}");
+        // 행 구분용 <br>은 그대로
+        assert!(desc.contains("<br>"));
+    }
+
+    #[test]
+    fn hide_empty_drops_missing_rows() {
+        let bare = Notice { organizer: None, field: None, summary: None, ..notice() };
+        let hidden = build_description(&bare, &FeedOptions { hide_empty: true, ..FeedOptions::default() });
+        assert!(!hidden.contains("주최:") && !hidden.contains("분야:"), "{hidden}");
+        assert!(hidden.starts_with("기간: 2026-10-01 ~ 2026-10-24") && !hidden.contains("<br>"), "{hidden}");
+        // 끄면 "-"로 행을 남김
+        let shown = build_description(&bare, &FeedOptions::default());
+        assert!(shown.contains("주최: -") && shown.contains("분야: -"), "{shown}");
+    }
+
+    #[test]
+    fn retained_items_are_rebuilt_for_today() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("test_rss.xml").display().to_string();
+        let opts = FeedOptions { grace_days: Some(7), ..FeedOptions::default() };
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        write_rss_feed(&[notice()], "t", "https://example.com", "d", &out, &opts).unwrap();
+        // 다음 실행에서 수집 실패: 마감 전이라 유지되고 D-day는 그날 기준으로 다시 계산
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 16));
+        write_rss_feed(&[], "t", "https://example.com", "d", &out, &opts).unwrap();
+        crate::clock::set_today(None);
+
+        let channel = Channel::read_from(BufReader::new(File::open(&out).unwrap())).unwrap();
+        assert_eq!(channel.items().len(), 1);
+        let item = &channel.items()[0];
+        assert_eq!(item.title(), Some("AI 공모전"));
+        assert!(item.description().unwrap().contains("기간: 2026-10-01 ~ 2026-10-24 (D-8)"), "{:?}", item.description());
+        assert_eq!(channel.items()[0].guid().unwrap().value(), notice().stable_guid(&notice::default_strip_params()));
+    }
+
+    #[test]
+    fn bad_notices_are_skipped_and_the_rest_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("test_rss.xml").display().to_string();
+        let second = Notice { title: "데이터 공모전".into(), url: "https://www.wevity.com/?c=find&ix=2".into(), ..notice() };
+        let bad_date = Notice { end: Some("2026/10/24".into()), url: "https://www.wevity.com/?c=find&ix=3".into(), ..notice() };
+        let empty = Notice { title: " ".into(), url: String::new(), ..notice() };
+        let opts = FeedOptions { verify: true, ..FeedOptions::default() };
+        write_rss_feed(&[notice(), bad_date, empty, second], "t", "https://example.com", "d", &out, &opts).unwrap();
+
+        let channel = Channel::read_from(BufReader::new(File::open(&out).unwrap())).unwrap();
+        let links: Vec<_> = channel.items().iter().filter_map(|it| it.link()).collect();
+        assert_eq!(links, ["https://www.wevity.com/?c=find&ix=1", "https://www.wevity.com/?c=find&ix=2"]);
+    }
+
+    #[test]
+    fn empty_notice_is_skipped_and_title_only_gets_placeholder_link() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("test_rss.xml").display().to_string();
+        let empty = Notice { title: String::new(), url: " ".into(), ..notice() };
+        let title_only = Notice { title: "링크 없는 공모전".into(), url: String::new(), ..notice() };
+        write_rss_feed(&[empty, title_only], "t", "https://example.com/list", "d", &out, &FeedOptions::default()).unwrap();
+
+        let channel = Channel::read_from(BufReader::new(File::open(&out).unwrap())).unwrap();
+        assert_eq!(channel.items().len(), 1);
+        assert_eq!(channel.items()[0].title(), Some("링크 없는 공모전"));
+        assert_eq!(channel.items()[0].link(), Some("https://example.com/list"));
+    }
+
+    #[test]
+    fn verify_rejects_broken_output_and_keeps_previous_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("test_rss.xml").display().to_string();
+        let opts = FeedOptions { verify: true, ..FeedOptions::default() };
+        write_rss_feed(&[notice()], "t", "https://example.com", "d", &out, &opts).unwrap();
+        let previous = std::fs::read_to_string(&out).unwrap();
+
+        // 이스케이프 수정 전 writer가 만들던 출력(제목에 날 "<", "&")은 재파싱 실패
+        let broken = dir.path().join("broken.xml").display().to_string();
+        std::fs::write(&broken, previous.replace("<title>AI 공모전</title>", "<title>R&D <AI> 공모전</title>")).unwrap();
+        assert!(verify_feed(&broken, 1).is_err());
+        // 항목 수가 다르면 실패
+        assert!(verify_feed(&out, 2).is_err());
+        assert!(verify_feed(&out, 1).is_ok());
+
+        // 검증 단계에서 실패하면 기존 파일은 그대로, 임시 파일도 남지 않음
+        let err = write_atomic_checked(&out, b"<rss><channel>", |tmp| verify_feed(tmp, 1));
+        assert!(err.is_err());
+        assert_eq!(std::fs::read_to_string(&out).unwrap(), previous);
+        assert!(!std::path::Path::new(&crate::atomic_write::tmp_path(&out)).exists());
+    }
+
+    /// lastBuildDate(실행 시각)만 고정 값으로 바꿈
+    fn normalize_build_date(xml: &str) -> String {
+        let re = regex::Regex::new(r"<lastBuildDate>[^<]*</lastBuildDate>").unwrap();
+        re.replace(xml, "<lastBuildDate>BUILD_DATE</lastBuildDate>").into_owned()
+    }
+
+    #[test]
+    fn feed_snapshot_with_fixed_clock() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("wevity_rss.xml").display().to_string();
+        let mut second = notice();
+        second.title = "데이터 분석 대회".into();
+        second.url = "https://www.wevity.com/?c=find&ix=2".into();
+        second.end = Some("2026-10-14".into());
+        second.end_approx = true;
+        second.summary = None;
+        let opts = FeedOptions { generator: "etc_crawler/test".into(), ..FeedOptions::default() };
+        write_rss_feed(&[notice(), second], "Wevity RSS", "https://www.wevity.com", "위비티", &out, &opts).unwrap();
+        crate::clock::set_today(None);
+
+        let got = normalize_build_date(&std::fs::read_to_string(&out).unwrap());
+        assert_eq!(got, include_str!("../tests/fixtures/snapshots/feed_rss.xml"), "actual:\n{got}");
+    }
+
+    #[test]
+    fn expired_items_are_flagged_only_in_the_archive_feed() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("archive_rss.xml").display().to_string();
+        let merged = dir.path().join("merged_rss.xml").display().to_string();
+        let expired = Notice { title: "지난 공모전".into(), url: "https://www.wevity.com/?c=find&ix=9".into(), end: Some("2026-10-13".into()), ..notice() };
+        let list = [notice(), expired];
+        write_archive_feed(&list, "t", "https://example.com", "d", &archive, &FeedOptions::default()).unwrap();
+        write_rss_feed(&list, "t", "https://example.com", "d", &merged, &FeedOptions::default()).unwrap();
+        crate::clock::set_today(None);
+
+        let flagged = |path: &str| -> Vec<bool> {
+            let channel = Channel::read_from(BufReader::new(File::open(path).unwrap())).unwrap();
+            channel.items().iter().map(|it| it.categories().iter().any(|c| c.name() == "expired")).collect()
+        };
+        // 마감 다음 날부터 아카이브에서만 expired, 마감 전 항목과 일반 피드는 표시 없음
+        assert_eq!(flagged(&archive), [false, true]);
+        assert_eq!(flagged(&merged), [false, false]);
+        assert!(is_expired(&list[1], NaiveDate::from_ymd_opt(2026, 10, 14).unwrap()));
+        assert!(!is_expired(&list[1], NaiveDate::from_ymd_opt(2026, 10, 13).unwrap()));
+    }
+
+    #[test]
+    fn channel_carries_ttl_docs_and_generator() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("test_rss.xml").display().to_string();
+        let opts = FeedOptions { ttl_min: Some(90), generator: "etc_crawler/test".into(), ..FeedOptions::default() };
+        write_rss_feed(&[notice()], "t", "https://example.com", "d", &out, &opts).unwrap();
+
+        let body = std::fs::read_to_string(&out).unwrap();
+        assert!(body.contains("<ttl>90</ttl>"), "{body}");
+        assert!(body.contains("<docs>https://www.rssboard.org/rss-specification</docs>"), "{body}");
+        let channel = Channel::read_from(body.as_bytes()).unwrap();
+        assert_eq!(channel.generator(), Some("etc_crawler/test"));
+
+        // ttl이 없으면 태그도 없음
+        write_rss_feed(&[notice()], "t", "https://example.com", "d", &out, &FeedOptions::default()).unwrap();
+        assert!(!std::fs::read_to_string(&out).unwrap().contains("<ttl>"));
+    }
+
+    #[test]
+    fn channel_dates_use_build_time_and_newest_item() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("test_rss.xml").display().to_string();
+        let later = Notice { url: "https://www.wevity.com/?c=find&ix=2".into(), start: Some("2026-10-09".into()), ..notice() };
+        write_rss_feed(&[notice(), later], "t", "https://example.com", "d", &out, &FeedOptions::default()).unwrap();
+
+        let channel = Channel::read_from(BufReader::new(File::open(&out).unwrap())).unwrap();
+        let built = channel.last_build_date().unwrap();
+        assert!(DateTime::parse_from_rfc2822(built).is_ok(), "{built}");
+        assert_eq!(channel.pub_date(), ymd_to_rfc2822("2026-10-09").as_deref());
+
+        // 항목이 없으면 pubDate는 생략, lastBuildDate는 그대로
+        write_rss_feed(&[], "t", "https://example.com", "d", &out, &FeedOptions::default()).unwrap();
+        let channel = Channel::read_from(BufReader::new(File::open(&out).unwrap())).unwrap();
+        assert!(channel.pub_date().is_none());
+        assert!(!channel.last_build_date().unwrap_or_default().is_empty());
+    }
+
+    #[test]
+    fn atom_feed_has_id_self_link_and_unique_entry_ids() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("wevity_atom.xml").display().to_string();
+        let opts = FeedOptions { public_url: Some("https://feeds.example.com/etc/".into()), ..FeedOptions::default() };
+        let tracked = Notice { url: "https://www.wevity.com/?c=find&ix=2&utm_source=rss".into(), ..notice() };
+        let no_url = Notice { title: "링크 없는 공모전".into(), url: String::new(), ..notice() };
+        write_atom_feed(&[notice(), tracked, no_url], "t", "https://www.wevity.com", "d", &out, &opts).unwrap();
+
+        let feed = atom::Feed::read_from(BufReader::new(File::open(&out).unwrap())).unwrap();
+        assert_eq!(feed.id(), "https://www.wevity.com#wevity_atom.xml");
+        let self_link = feed.links().iter().find(|l| l.rel() == "self").unwrap();
+        assert_eq!(self_link.href(), "https://feeds.example.com/etc/wevity_atom.xml");
+        assert_eq!(self_link.mime_type(), Some("application/atom+xml"));
+        assert!(feed.links().iter().any(|l| l.rel() == "alternate" && l.href() == "https://www.wevity.com"));
+
+        // 필수 요소(id/title/updated)와 항목 id의 유일성
+        let ids: Vec<&str> = feed.entries().iter().map(|e| e.id()).collect();
+        assert_eq!(ids, [
+            "https://www.wevity.com/?c=find&ix=1",
+            "https://www.wevity.com/?c=find&ix=2",
+            "https://www.wevity.com#Wevity-링크-없는-공모전",
+        ]);
+        assert!(feed.entries().iter().all(|e| !e.title().is_empty()));
+
+        // public_url이 없으면 self 링크는 파일명만
+        write_atom_feed(&[notice()], "t", "https://www.wevity.com", "d", &out, &FeedOptions::default()).unwrap();
+        let feed = atom::Feed::read_from(BufReader::new(File::open(&out).unwrap())).unwrap();
+        assert_eq!(feed.links().iter().find(|l| l.rel() == "self").unwrap().href(), "wevity_atom.xml");
+    }
+
+    #[test]
+    fn changed_at_wins_over_start_for_pub_date_and_updated() {
+        let opts = FeedOptions::default();
+        let changed = Notice { changed_at: Some("2026-10-14T09:30:00+00:00".into()), ..notice() };
+        let item = build_item(&changed, &opts, "https://example.com").unwrap();
+        assert_eq!(item.pub_date(), Some("Wed, 14 Oct 2026 09:30:00 +0000"));
+        let entry = build_entry(&changed, &opts, "https://example.com").unwrap();
+        assert_eq!(entry.updated().to_rfc3339(), "2026-10-14T09:30:00+00:00");
+        // 변경 이력이 없으면 시작일
+        let item = build_item(&notice(), &opts, "https://example.com").unwrap();
+        assert_eq!(item.pub_date(), Some("Thu, 1 Oct 2026 00:00:00 +0000"));
+    }
+}
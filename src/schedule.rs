@@ -0,0 +1,229 @@
+// src/schedule.rs
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, NaiveTime, Offset, TimeZone, Timelike, Utc};
+use std::future::Future;
+use tracing::{info, warn};
+
+/// 5필드 cron 식(분 시 일 월 요일, SCHEDULE). 필드마다 *, n, a-b, 그리고 /step, 쉼표 목록
+/// 요일은 0-7(0과 7은 일요일). 일과 요일이 둘 다 *가 아니면 둘 중 하나만 맞아도 실행(일반 cron과 같음)
+#[derive(Clone, Debug)]
+pub struct Schedule {
+    minutes: u64,
+    hours: u32,
+    days: u32,
+    months: u16,
+    weekdays: u8,
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl Schedule {
+    pub fn parse(expr: &str) -> Result<Self> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [min, hour, day, month, weekday] = fields[..] else {
+            bail!("expected 5 fields (minute hour day month weekday), got {}: {expr:?}", fields.len());
+        };
+        let weekdays = parse_field(weekday, 0, 7).context("weekday field")?;
+        Ok(Self {
+            minutes: parse_field(min, 0, 59).context("minute field")?,
+            hours: parse_field(hour, 0, 23).context("hour field")? as u32,
+            days: parse_field(day, 1, 31).context("day field")? as u32,
+            months: parse_field(month, 1, 12).context("month field")? as u16,
+            // 7(일요일)을 0으로 접음
+            weekdays: ((weekdays | (weekdays >> 7)) & 0x7f) as u8,
+            any_day: day == "*",
+            any_weekday: weekday == "*",
+        })
+    }
+
+    /// after보다 뒤(분 단위)의 첫 실행 시각. after의 시간대로 계산하고, 4년 안에 없으면(2월 30일 등) None
+    pub fn next_after<Tz: TimeZone>(&self, after: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let local = after.naive_local();
+        let start = local.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        for offset in 0..366 * 4 {
+            let date = start.date() + Duration::days(offset);
+            if !self.matches_date(date) {
+                continue;
+            }
+            let from = if offset == 0 { start.time() } else { NaiveTime::MIN };
+            for hour in from.hour()..24 {
+                if self.hours & (1 << hour) == 0 {
+                    continue;
+                }
+                let first = if hour == from.hour() { from.minute() } else { 0 };
+                if let Some(minute) = (first..60).find(|m| self.minutes & (1 << m) != 0)
+                    && let Some(t) = after.timezone().from_local_datetime(&date.and_hms_opt(hour, minute, 0)?).earliest() {
+                    return Some(t);
+                }
+            }
+        }
+        None
+    }
+
+    fn matches_date(&self, date: chrono::NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.any_day, self.any_weekday) {
+            (false, false) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+}
+
+/// 필드 하나를 비트마스크로(비트 n = 값 n)
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((r, s)) => (r, s.parse::<u32>().ok().filter(|&s| s > 0).ok_or_else(|| anyhow!("invalid step {s:?}"))?),
+            None => (part, 1),
+        };
+        let value = |s: &str| -> Result<u32> {
+            let v: u32 = s.parse().map_err(|_| anyhow!("invalid value {s:?}"))?;
+            if !(min..=max).contains(&v) {
+                bail!("{v} is out of range {min}-{max}");
+            }
+            Ok(v)
+        };
+        let (lo, hi) = match range {
+            "*" => (min, max),
+            r => match r.split_once('-') {
+                Some((a, b)) => (value(a)?, value(b)?),
+                // "5/15"는 5부터 끝까지 15 간격
+                None if part.contains('/') => (value(r)?, max),
+                None => (value(r)?, value(r)?),
+            },
+        };
+        if lo > hi {
+            bail!("range {lo}-{hi} is reversed");
+        }
+        for v in (lo..=hi).step_by(step as usize) {
+            mask |= 1 << v;
+        }
+    }
+    Ok(mask)
+}
+
+/// SCHEDULE_TZ 해석: "+09:00" 같은 UTC 오프셋. None이면 지금 로컬 오프셋
+pub fn parse_offset(tz: Option<&str>) -> Result<FixedOffset> {
+    match tz {
+        Some(s) => s.trim().parse().map_err(|_| anyhow!("invalid UTC offset {s:?} (expected e.g. +09:00)")),
+        None => Ok(Local::now().offset().fix()),
+    }
+}
+
+/// daemon 루프: 다음 실행 시각을 로그로 남기고 그때까지 잠든 뒤 run. shutdown이 끝나면 대기 중이든 실행 직후든 빠져나옴
+/// run 실패는 경고만 하고 다음 실행을 기다림
+pub async fn run_loop<F, Fut>(schedule: &Schedule, tz: FixedOffset, shutdown: impl Future<Output = ()>, mut run: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    tokio::pin!(shutdown);
+    loop {
+        let now = Utc::now().with_timezone(&tz);
+        let next = schedule.next_after(&now).ok_or_else(|| anyhow!("schedule never fires"))?;
+        info!(next = %next.to_rfc3339(), "next scheduled run");
+        let wait = (next - now).to_std().unwrap_or_default();
+        tokio::select! {
+            _ = &mut shutdown => {
+                info!("shutdown requested, leaving the schedule loop");
+                return Ok(());
+            }
+            _ = tokio::time::sleep(wait) => {}
+        }
+        if let Err(e) = run().await {
+            warn!(error = %format!("{e:#}"), "scheduled run failed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kst(d: u32, h: u32, m: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(9 * 3600).unwrap().with_ymd_and_hms(2026, 10, d, h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn every_two_hours_in_the_daytime() {
+        let s = Schedule::parse("0 8-23/2 * * *").unwrap();
+        assert_eq!(s.next_after(&kst(14, 7, 59)), Some(kst(14, 8, 0)));
+        // 정각 그 자체는 "뒤"가 아님
+        assert_eq!(s.next_after(&kst(14, 8, 0)), Some(kst(14, 10, 0)));
+        assert_eq!(s.next_after(&kst(14, 9, 30)), Some(kst(14, 10, 0)));
+        // 22시 다음은 이튿날 8시(8-23/2 = 8,10,…,22)
+        assert_eq!(s.next_after(&kst(14, 22, 0)), Some(kst(15, 8, 0)));
+    }
+
+    #[test]
+    fn weekday_list_and_sunday_as_seven() {
+        // 2026-10-14는 수요일
+        let weekend = Schedule::parse("0 9 * * 6,7").unwrap();
+        assert_eq!(weekend.next_after(&kst(14, 12, 0)), Some(kst(17, 9, 0)));
+        assert_eq!(weekend.next_after(&kst(17, 9, 0)), Some(kst(18, 9, 0)));
+        // 일과 요일을 둘 다 지정하면 둘 중 하나
+        let either = Schedule::parse("30 6 20 * 0").unwrap();
+        assert_eq!(either.next_after(&kst(14, 0, 0)), Some(kst(18, 6, 30)));
+        assert_eq!(either.next_after(&kst(18, 6, 30)), Some(kst(20, 6, 30)));
+    }
+
+    #[test]
+    fn evaluates_in_the_given_offset() {
+        let s = Schedule::parse("0 8 * * *").unwrap();
+        // UTC 2026-10-13 23:30 = KST 10-14 08:30 → 다음은 KST 10-15 08:00
+        let utc = Utc.with_ymd_and_hms(2026, 10, 13, 23, 30, 0).unwrap();
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        assert_eq!(s.next_after(&utc.with_timezone(&tz)), Some(kst(15, 8, 0)));
+        assert_eq!(s.next_after(&utc), Some(Utc.with_ymd_and_hms(2026, 10, 14, 8, 0, 0).unwrap()));
+        assert_eq!(parse_offset(Some("+09:00")).unwrap(), tz);
+        assert!(parse_offset(Some("KST")).is_err());
+    }
+
+    #[test]
+    fn steps_from_a_start_value_and_impossible_dates() {
+        let s = Schedule::parse("5/20 * * * *").unwrap();
+        assert_eq!(s.next_after(&kst(14, 10, 6)), Some(kst(14, 10, 25)));
+        assert_eq!(s.next_after(&kst(14, 10, 45)), Some(kst(14, 11, 5)));
+        assert_eq!(Schedule::parse("0 0 30 2 *").unwrap().next_after(&kst(14, 0, 0)), None);
+    }
+
+    #[test]
+    fn invalid_expressions_explain_the_field() {
+        let err = |e: &str| format!("{:#}", Schedule::parse(e).unwrap_err());
+        assert!(err("0 8 * *").contains("expected 5 fields"), "{}", err("0 8 * *"));
+        assert!(err("0 24 * * *").contains("hour field: 24 is out of range 0-23"), "{}", err("0 24 * * *"));
+        assert!(err("*/0 * * * *").contains("minute field: invalid step"), "{}", err("*/0 * * * *"));
+        assert!(err("0 9 * * mon").contains("weekday field: invalid value"), "{}", err("0 9 * * mon"));
+        assert!(err("0 20-8 * * *").contains("reversed"), "{}", err("0 20-8 * * *"));
+    }
+
+    #[tokio::test]
+    async fn shutdown_ends_the_wait_without_running() {
+        let s = Schedule::parse("0 0 1 1 *").unwrap();
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let mut runs = 0;
+        let started = std::time::Instant::now();
+        let stop = async move {
+            let _ = rx.await;
+        };
+        let fire = async move {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            let _ = tx.send(());
+        };
+        let (res, ()) = tokio::join!(
+            run_loop(&s, FixedOffset::east_opt(9 * 3600).unwrap(), stop, || {
+                runs += 1;
+                async { Ok(()) }
+            }),
+            fire
+        );
+        res.unwrap();
+        assert_eq!(runs, 0);
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,264 @@
+// src/source.rs
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::timeout;
+use tracing::{info, info_span, warn, Instrument};
+
+use crate::notice::Notice;
+
+/// 수집 중 소스가 기록하는 카운터(run_summary/메트릭용)
+/// 상세 요청이 여러 태스크에서 끝나므로 원자 연산. clone은 같은 카운터를 가리킴(spawn한 태스크에 넘길 때)
+/// 기록하지 않는 소스는 0으로 남음
+#[derive(Clone, Debug, Default)]
+pub struct CollectStats(Arc<Counters>);
+
+#[derive(Debug, Default)]
+struct Counters {
+    raw_items: AtomicUsize,
+    detail_failures: AtomicUsize,
+    robots_blocked: AtomicUsize,
+    http_requests: AtomicU64,
+    bytes_fetched: AtomicU64,
+    // 예산 사용(마이크로초)
+    list_us: AtomicU64,
+    detail_us: AtomicU64,
+    rate_limit_wait_us: AtomicU64,
+    parse_us: AtomicU64,
+    exhausted: AtomicBool,
+    pending: AtomicUsize,
+}
+
+/// 소스가 시간 예산을 어디에 썼는지(run_summary.json의 sources[].budget)
+/// 구간은 겹칠 수 있음(상세 시간에는 그 안의 제한기 대기가 포함되는 식)
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct BudgetUsage {
+    /// 목록 페이지/API 요청(본문 읽기까지)
+    pub list_ms: u64,
+    /// 상세 페이지/JSON 요청
+    pub detail_ms: u64,
+    /// 호스트별 제한기 대기 + 페이지 사이 고정 대기
+    pub rate_limit_wait_ms: u64,
+    /// HTML/JSON 파싱과 필터
+    pub parse_ms: u64,
+    /// 예산(소스 내부 예산 또는 소스 타임아웃)이 끝나 중단했으면 true, 끝까지 돌았으면 false
+    pub exhausted: bool,
+    /// 중단 시 요청하지 못한 상세 건수(모르면 0)
+    pub pending: usize,
+}
+
+impl CollectStats {
+    /// 목록에서 본 항목 수(키워드/마감 필터 전)
+    pub fn add_raw(&self, n: usize) {
+        self.0.raw_items.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// 상세 페이지 조회/파싱 실패 1건
+    pub fn add_detail_failure(&self) {
+        self.0.detail_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// robots.txt가 막아 요청하지 않은 URL 1건
+    pub fn add_robots_blocked(&self) {
+        self.0.robots_blocked.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 응답을 받은 HTTP 요청 1건과 읽은 본문 바이트 수(압축 해제 후)
+    pub fn add_http(&self, bytes: usize) {
+        self.0.http_requests.fetch_add(1, Ordering::Relaxed);
+        self.0.bytes_fetched.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_list_time(&self, d: Duration) {
+        self.0.list_us.fetch_add(d.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_detail_time(&self, d: Duration) {
+        self.0.detail_us.fetch_add(d.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_rate_limit_wait(&self, d: Duration) {
+        self.0.rate_limit_wait_us.fetch_add(d.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_parse_time(&self, d: Duration) {
+        self.0.parse_us.fetch_add(d.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// 요청 사이 고정 대기: 잠든 시간을 제한기 대기로 기록
+    pub async fn pace(&self, d: Duration) {
+        tokio::time::sleep(d).await;
+        self.add_rate_limit_wait(d);
+    }
+
+    /// 예산이 끝나 중단(pending: 남은 상세 건수, 여러 번 부르면 더함)
+    pub fn budget_exhausted(&self, pending: usize) {
+        self.0.exhausted.store(true, Ordering::Relaxed);
+        self.0.pending.fetch_add(pending, Ordering::Relaxed);
+    }
+
+    pub fn budget(&self) -> BudgetUsage {
+        let ms = |v: &AtomicU64| v.load(Ordering::Relaxed) / 1000;
+        BudgetUsage {
+            list_ms: ms(&self.0.list_us),
+            detail_ms: ms(&self.0.detail_us),
+            rate_limit_wait_ms: ms(&self.0.rate_limit_wait_us),
+            parse_ms: ms(&self.0.parse_us),
+            exhausted: self.0.exhausted.load(Ordering::Relaxed),
+            pending: self.0.pending.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn raw_items(&self) -> usize {
+        self.0.raw_items.load(Ordering::Relaxed)
+    }
+
+    pub fn detail_failures(&self) -> usize {
+        self.0.detail_failures.load(Ordering::Relaxed)
+    }
+
+    pub fn robots_blocked(&self) -> usize {
+        self.0.robots_blocked.load(Ordering::Relaxed)
+    }
+
+    pub fn http_requests(&self) -> u64 {
+        self.0.http_requests.load(Ordering::Relaxed)
+    }
+
+    pub fn bytes_fetched(&self) -> u64 {
+        self.0.bytes_fetched.load(Ordering::Relaxed)
+    }
+}
+
+/// 수집 소스 공통 인터페이스
+/// 새 소스는 이 트레이트를 구현하고 main의 crawlers 목록에 추가하면 된다.
+/// scraper::Html이 Send가 아니어서 ?Send(main에서 join_all로 한 태스크 안에서 실행)
+#[async_trait(?Send)]
+pub trait Crawler: Send + Sync {
+    /// 로그/메트릭/피드 키로 쓰는 이름("wevity", "campuspick", "dacon", "linkareer" …)
+    fn name(&self) -> &str;
+
+    /// 개별 피드 채널 링크
+    fn site_url(&self) -> &str;
+
+    /// 실제로 수집한 목록 페이지 URL(있으면 채널 링크로 쓸 수 있음)
+    fn list_url(&self) -> Option<&str> {
+        None
+    }
+
+    /// 개별 피드의 채널 링크: list_link(CHANNEL_LIST_LINK)가 켜져 있고 목록 URL이 있으면 목록, 아니면 사이트
+    fn channel_link(&self, list_link: bool) -> &str {
+        self.list_url().filter(|_| list_link).unwrap_or(self.site_url())
+    }
+
+    /// 개별 피드 파일을 쓸지(외부 피드 재수집처럼 원본 피드가 따로 있으면 false)
+    fn own_feed(&self) -> bool {
+        true
+    }
+
+    /// 소스 전체 타임아웃
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(25)
+    }
+
+    /// 필터를 거친 Notice 목록. 필터 전 건수/상세 실패는 stats에 기록
+    async fn collect(&self, stats: &CollectStats) -> Result<Vec<Notice>>;
+}
+
+/// 타임아웃을 걸어 한 소스를 수집
+/// source 스팬 안에서 실행하므로 수집기 내부 로그에도 source 필드가 붙는다
+pub async fn run(c: &dyn Crawler, stats: &CollectStats) -> Result<Vec<Notice>> {
+    let span = info_span!("source", source = c.name());
+    async {
+        info!("fetching");
+        let started = Instant::now();
+        let out = timeout(c.timeout(), c.collect(stats)).await;
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        if out.is_err() {
+            stats.budget_exhausted(0);
+        }
+        let budget = stats.budget();
+        if budget.exhausted {
+            warn!(pending = budget.pending, elapsed_ms, "budget exhausted, results truncated");
+        }
+        let out = out.with_context(|| format!("{} timeout", c.name()))?;
+        match &out {
+            Ok(v) => info!(
+                raw_items = stats.raw_items(),
+                items_found = v.len(),
+                detail_failures = stats.detail_failures(),
+                robots_blocked = stats.robots_blocked(),
+                http_requests = stats.http_requests(),
+                bytes_fetched = stats.bytes_fetched(),
+                list_ms = budget.list_ms,
+                detail_ms = budget.detail_ms,
+                rate_limit_wait_ms = budget.rate_limit_wait_ms,
+                parse_ms = budget.parse_ms,
+                elapsed_ms,
+                "fetched"
+            ),
+            Err(e) => warn!(elapsed_ms, error = %format!("{e:#}"), "fetch failed"),
+        }
+        out
+    }
+    .instrument(span)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 정해진 시간만큼 자고 빈 목록을 돌려주는 소스
+    struct Sleepy {
+        sleep: Duration,
+        timeout: Duration,
+    }
+
+    #[async_trait(?Send)]
+    impl Crawler for Sleepy {
+        fn name(&self) -> &str { "sleepy" }
+        fn site_url(&self) -> &str { "https://example.com" }
+        fn timeout(&self) -> Duration { self.timeout }
+
+        async fn collect(&self, stats: &CollectStats) -> Result<Vec<Notice>> {
+            stats.add_list_time(Duration::from_millis(5));
+            stats.pace(self.sleep).await;
+            Ok(Vec::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn source_timeout_marks_budget_exhausted() {
+        let stats = CollectStats::default();
+        let c = Sleepy { sleep: Duration::from_secs(5), timeout: Duration::from_millis(50) };
+        let err = run(&c, &stats).await.unwrap_err();
+        assert!(err.to_string().contains("sleepy timeout"), "{err}");
+        assert!(stats.budget().exhausted);
+    }
+
+    #[tokio::test]
+    async fn completed_source_reports_spent_budget() {
+        let stats = CollectStats::default();
+        let c = Sleepy { sleep: Duration::from_millis(20), timeout: Duration::from_secs(5) };
+        run(&c, &stats).await.unwrap();
+        let budget = stats.budget();
+        assert!(!budget.exhausted);
+        assert_eq!((budget.list_ms, budget.pending), (5, 0));
+        assert!(budget.rate_limit_wait_ms >= 20, "{budget:?}");
+    }
+
+    #[test]
+    fn clones_share_counters() {
+        let stats = CollectStats::default();
+        let other = stats.clone();
+        other.add_http(100);
+        other.budget_exhausted(2);
+        stats.budget_exhausted(1);
+        assert_eq!((stats.http_requests(), stats.bytes_fetched()), (1, 100));
+        assert_eq!(stats.budget().pending, 3);
+    }
+}
@@ -0,0 +1,237 @@
+// src/store.rs
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::labels::{self, Lang};
+use crate::notice::{series_key, Notice};
+
+/// notices 테이블(키: 정규화 URL = Notice::url_key). 시각은 RFC 3339(UTC)
+/// series_key: 회차/연도를 지운 제목 키(notice::series_key)
+/// item_changes 테이블(키: Notice::stable_guid): 마지막 content_hash와 그 해시가 바뀐 시각
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS notices (
+    url_key    TEXT PRIMARY KEY,
+    source     TEXT NOT NULL,
+    kind       TEXT NOT NULL,
+    title      TEXT NOT NULL,
+    url        TEXT NOT NULL,
+    start      TEXT,
+    \"end\"    TEXT,
+    organizer  TEXT,
+    field      TEXT,
+    first_seen TEXT NOT NULL,
+    last_seen  TEXT NOT NULL,
+    series_key TEXT
+);
+CREATE INDEX IF NOT EXISTS notices_first_seen ON notices(first_seen);
+CREATE TABLE IF NOT EXISTS item_changes (
+    guid         TEXT PRIMARY KEY,
+    content_hash TEXT NOT NULL,
+    changed_at   TEXT NOT NULL
+);
+";
+
+/// DB 열기(없으면 생성) + 스키마 준비
+pub fn open_db(path: &str) -> Result<Connection> {
+    let conn = Connection::open(path).with_context(|| format!("open {path}"))?;
+    conn.execute_batch(SCHEMA).context("create schema")?;
+    migrate_series_key(&conn)?;
+    Ok(conn)
+}
+
+/// series_key 열이 없던 DB: 열을 추가하고 기존 행의 제목으로 채움
+fn migrate_series_key(conn: &Connection) -> Result<()> {
+    let has_column = conn
+        .prepare("SELECT 1 FROM pragma_table_info('notices') WHERE name = 'series_key'")?
+        .exists([])?;
+    if !has_column {
+        conn.execute_batch("ALTER TABLE notices ADD COLUMN series_key TEXT").context("add series_key")?;
+    }
+    let missing: Vec<(String, String)> = conn
+        .prepare("SELECT url_key, title FROM notices WHERE series_key IS NULL")?
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+    for (url_key, title) in missing {
+        conn.execute("UPDATE notices SET series_key = ?1 WHERE url_key = ?2", params![series_key(&title), url_key])?;
+    }
+    conn.execute_batch("CREATE INDEX IF NOT EXISTS notices_series_key ON notices(series_key)")
+        .context("create series index")?;
+    Ok(())
+}
+
+/// 반복 공모전 표시: 같은 series_key를 가진 다른 공고(지난 회차 이력 또는 이번 목록의 다른 항목)가 있으면
+/// series_id = series_key. 표시한 건수를 반환
+pub fn assign_series(conn: &Connection, notices: &mut [Notice], strip: &[String]) -> Result<usize> {
+    let keys: Vec<(String, String)> = notices.iter().map(|n| (series_key(&n.title), n.url_key(strip))).collect();
+    let mut stmt = conn.prepare("SELECT EXISTS(SELECT 1 FROM notices WHERE series_key = ?1 AND url_key <> ?2)")?;
+    let mut marked = 0;
+    for (i, n) in notices.iter_mut().enumerate() {
+        let (key, url_key) = &keys[i];
+        let in_batch = keys.iter().enumerate().any(|(j, (k, u))| j != i && k == key && u != url_key);
+        let in_history: bool = stmt.query_row(params![key, url_key], |r| r.get(0))?;
+        n.series_id = (in_batch || in_history).then(|| key.clone());
+        marked += usize::from(n.series_id.is_some());
+    }
+    Ok(marked)
+}
+
+/// 피드 항목별 changed_at 채우기(pubDate/Atom updated로 쓰임). 한 트랜잭션으로 처리하고 now로 바뀐 건수를 반환
+/// - 처음 보는 GUID이거나 content_hash가 달라졌으면 now로 갱신
+/// - 해시가 같으면 저장된 시각 유지(매 실행 수집해도 리더에 다시 뜨지 않음)
+pub fn track_changes(conn: &mut Connection, notices: &mut [Notice], strip: &[String], now: DateTime<Utc>) -> Result<usize> {
+    let now = now.to_rfc3339();
+    let tx = conn.transaction()?;
+    let mut changed = 0;
+    {
+        let mut select = tx.prepare("SELECT content_hash, changed_at FROM item_changes WHERE guid = ?1")?;
+        let mut write = tx.prepare(
+            "INSERT INTO item_changes (guid, content_hash, changed_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(guid) DO UPDATE SET content_hash = excluded.content_hash, changed_at = excluded.changed_at",
+        )?;
+        for n in notices.iter_mut() {
+            let (guid, hash) = (n.stable_guid(strip), n.content_hash());
+            let stored: Option<(String, String)> = select
+                .query_row(params![guid], |r| Ok((r.get(0)?, r.get(1)?)))
+                .optional()?;
+            let at = match stored {
+                Some((old_hash, at)) if old_hash == hash => at,
+                _ => {
+                    write.execute(params![guid, hash, now])?;
+                    changed += 1;
+                    now.clone()
+                }
+            };
+            n.changed_at = Some(at);
+        }
+    }
+    tx.commit()?;
+    Ok(changed)
+}
+
+/// 새 항목은 추가(first_seen = last_seen = 지금), 있던 항목은 end/organizer/last_seen만 갱신
+/// 한 트랜잭션으로 처리하고 반영한 건수를 반환
+pub fn upsert(conn: &mut Connection, notices: &[Notice], strip: &[String]) -> Result<usize> {
+    let now = Utc::now().to_rfc3339();
+    let tx = conn.transaction()?;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT INTO notices (url_key, source, kind, title, url, start, \"end\", organizer, field, first_seen, last_seen, series_key)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?10, ?11)
+             ON CONFLICT(url_key) DO UPDATE SET
+                 \"end\" = excluded.\"end\",
+                 organizer = excluded.organizer,
+                 last_seen = excluded.last_seen",
+        )?;
+        for n in notices {
+            stmt.execute(params![
+                n.url_key(strip),
+                n.source.name(),
+                labels::kind_label(&n.kind, Lang::En).to_lowercase(),
+                n.title,
+                n.url,
+                n.start,
+                n.end,
+                n.organizer,
+                n.field,
+                now,
+                series_key(&n.title),
+            ])?;
+        }
+    }
+    tx.commit()?;
+    Ok(notices.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::{default_strip_params, Kind, Source};
+    use chrono::TimeZone;
+
+    fn notice(title: &str, url: &str) -> Notice {
+        Notice {
+            source: Source::Wevity,
+            kind: Kind::Contest,
+            title: title.into(),
+            raw_title: String::new(),
+            url: url.into(),
+            start: None,
+            end: None,
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    #[test]
+    fn later_round_joins_the_series_of_a_stored_round() {
+        let mut conn = open_db(":memory:").unwrap();
+        upsert(&mut conn, &[notice("제1회 X 대회", "https://a.example/1")], &default_strip_params()).unwrap();
+
+        let mut now = vec![
+            notice("제2회 X 대회", "https://a.example/2"),
+            notice("Y 해커톤", "https://a.example/3"),
+        ];
+        assert_eq!(assign_series(&conn, &mut now, &default_strip_params()).unwrap(), 1);
+        assert_eq!(now[0].series_id.as_deref(), Some("x 대회"));
+        assert_eq!(now[1].series_id, None);
+
+        // 자기 자신만 있는 이력은 반복으로 치지 않음
+        let mut again = vec![notice("제1회 X 대회", "https://a.example/1")];
+        assert_eq!(assign_series(&conn, &mut again, &default_strip_params()).unwrap(), 0);
+    }
+
+    #[test]
+    fn old_database_gets_series_column_backfilled() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("old.db");
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE notices (url_key TEXT PRIMARY KEY, source TEXT NOT NULL, kind TEXT NOT NULL, title TEXT NOT NULL,
+             url TEXT NOT NULL, start TEXT, \"end\" TEXT, organizer TEXT, field TEXT, first_seen TEXT NOT NULL, last_seen TEXT NOT NULL);
+             INSERT INTO notices VALUES ('k1', 'wevity', 'contest', '2025 Z 공모전', 'u', NULL, NULL, NULL, NULL, 't', 't');",
+        )
+        .unwrap();
+        drop(conn);
+
+        let conn = open_db(path.to_str().unwrap()).unwrap();
+        let key: String = conn.query_row("SELECT series_key FROM notices WHERE url_key = 'k1'", [], |r| r.get(0)).unwrap();
+        assert_eq!(key, "z 공모전");
+        let mut now = vec![notice("2026년 Z 공모전", "https://b.example/z")];
+        assert_eq!(assign_series(&conn, &mut now, &default_strip_params()).unwrap(), 1);
+    }
+
+    #[test]
+    fn changed_at_advances_only_when_the_content_changes() {
+        let mut conn = open_db(":memory:").unwrap();
+        let strip = default_strip_params();
+        let at = |h: u32| Utc.with_ymd_and_hms(2026, 10, 14, h, 0, 0).unwrap();
+        let listed = |end: &str| {
+            vec![
+                Notice { end: Some(end.into()), ..notice("X 대회", "https://a.example/1") },
+                Notice { end: Some("2026-11-01".into()), ..notice("Y 해커톤", "https://a.example/2") },
+            ]
+        };
+
+        let mut first = listed("2026-10-24");
+        assert_eq!(track_changes(&mut conn, &mut first, &strip, at(1)).unwrap(), 2);
+        assert!(first.iter().all(|n| n.changed_at.as_deref() == Some(at(1).to_rfc3339().as_str())));
+
+        // 마감 연장: 해당 항목만 새 시각, 그대로인 항목은 처음 시각 유지
+        let mut extended = listed("2026-10-31");
+        assert_eq!(track_changes(&mut conn, &mut extended, &strip, at(2)).unwrap(), 1);
+        assert_eq!(extended[0].changed_at, Some(at(2).to_rfc3339()));
+        assert_eq!(extended[1].changed_at, Some(at(1).to_rfc3339()));
+
+        // GUID가 같은 채 주최만 바뀌어도 갱신
+        let mut renamed = listed("2026-10-31");
+        renamed[1].organizer = Some("Y 재단".into());
+        assert_eq!(track_changes(&mut conn, &mut renamed, &strip, at(3)).unwrap(), 1);
+        assert_eq!(renamed[0].changed_at, Some(at(2).to_rfc3339()));
+        assert_eq!(renamed[1].changed_at, Some(at(3).to_rfc3339()));
+    }
+}
@@ -0,0 +1,241 @@
+// src/summary.rs
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use crate::metrics::SourceMetrics;
+use crate::notice::{Notice, Source};
+
+/// 중복 제거 단계별로 빠진 건수
+#[derive(Clone, Debug, Default)]
+pub struct DedupCounts {
+    pub url_dropped: usize,   // 1차: URL 기준
+    pub cross_dropped: usize, // 2차: 플랫폼 간(title + 기간)
+    pub pairs: BTreeMap<(String, String), usize>, // 2차에서 겹친 (소스, 소스) 쌍별 건수
+}
+
+impl DedupCounts {
+    /// 통합 목록 중복 제거(먼저 나온 항목 유지)하고 단계별 건수를 돌려줌
+    /// 1차: URL 기준(Notice::url_key, 같은 플랫폼 내부 중복)
+    /// 2차: 플랫폼 간(Notice::dedup_key, title + 기간). 겹친 소스 쌍을 pairs에 집계
+    pub fn dedup(all: &mut Vec<Notice>, strip: &[String]) -> Self {
+        let mut counts = Self::default();
+
+        let before = all.len();
+        let mut seen_url = HashSet::new();
+        all.retain(|n| seen_url.insert(n.url_key(strip)));
+        counts.url_dropped = before - all.len();
+
+        let before = all.len();
+        let mut seen_cross: HashMap<String, Source> = HashMap::new();
+        all.retain(|n| match seen_cross.entry(n.dedup_key()) {
+            Entry::Occupied(kept) => {
+                counts.record_pair(kept.get(), &n.source);
+                false
+            }
+            Entry::Vacant(slot) => {
+                slot.insert(n.source.clone());
+                true
+            }
+        });
+        counts.cross_dropped = before - all.len();
+        counts
+    }
+
+    /// 남긴 쪽/버린 쪽 소스 쌍을 순서 무관하게 집계
+    pub fn record_pair(&mut self, kept: &Source, dropped: &Source) {
+        let mut pair = [kept.name().to_string(), dropped.name().to_string()];
+        pair.sort();
+        let [a, b] = pair;
+        *self.pairs.entry((a, b)).or_default() += 1;
+    }
+}
+
+/// GitHub Actions job summary용 Markdown 렌더링(순수 함수)
+pub fn render_step_summary(
+    sources: &[SourceMetrics],
+    dedup: &DedupCounts,
+    health_warnings: &[String],
+    all: &[Notice],
+    today: NaiveDate,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "## Contest crawler run\n");
+
+    // 소스별 표
+    let _ = writeln!(out, "| source | items | duration | status |");
+    let _ = writeln!(out, "| --- | ---: | ---: | :---: |");
+    for s in sources {
+        let status = if s.ok() { "✅" } else { "❌" };
+        let _ = writeln!(
+            out,
+            "| {} | {} | {:.1}s | {} |",
+            s.name, s.items, s.duration.as_secs_f64(), status
+        );
+    }
+
+    let _ = writeln!(
+        out,
+        "\n**Merged:** {} items (URL dedup -{}, cross-source dedup -{})\n",
+        all.len(), dedup.url_dropped, dedup.cross_dropped
+    );
+
+    if !dedup.pairs.is_empty() {
+        let _ = writeln!(out, "| overlap | duplicates |");
+        let _ = writeln!(out, "| --- | ---: |");
+        for ((a, b), cnt) in &dedup.pairs {
+            let _ = writeln!(out, "| {a} ↔ {b} | {cnt} |");
+        }
+        let _ = writeln!(out);
+    }
+
+    // 마감 임박 상위 10건
+    let mut closing: Vec<(&Notice, NaiveDate)> = all
+        .iter()
+        .filter_map(|n| {
+            let end = NaiveDate::parse_from_str(n.end.as_deref()?, "%Y-%m-%d").ok()?;
+            (end >= today).then_some((n, end))
+        })
+        .collect();
+    closing.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.title.cmp(&b.0.title)));
+
+    if !closing.is_empty() {
+        let _ = writeln!(out, "### Closing soon\n");
+        for (n, end) in closing.iter().take(10) {
+            let _ = writeln!(
+                out,
+                "- [{}]({}) — ~{} (D-{})",
+                n.title.replace(['[', ']'], ""), n.url, end, (*end - today).num_days()
+            );
+        }
+        let _ = writeln!(out);
+    }
+
+    // 소스 상태 경고
+    let warnings: Vec<String> = sources
+        .iter()
+        .filter_map(|s| match &s.error {
+            Some(e) => Some(format!("{} failed: {}", s.name, e)),
+            None if s.items == 0 => Some(format!("{} returned 0 items", s.name)),
+            None => None,
+        })
+        .chain(health_warnings.iter().cloned())
+        .collect();
+    if !warnings.is_empty() {
+        let _ = writeln!(out, "### Warnings\n");
+        for w in warnings {
+            let _ = writeln!(out, "- ⚠️ {w}");
+        }
+        let _ = writeln!(out);
+    }
+
+    out
+}
+
+/// GITHUB_STEP_SUMMARY 파일에 이어쓰기
+pub fn append_step_summary(path: &str, body: &str) -> Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(body.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notice::Kind;
+    use std::time::Duration;
+
+    fn source(name: &str, items: usize, secs: u64, error: Option<&str>) -> SourceMetrics {
+        SourceMetrics {
+            name: name.into(),
+            raw_items: items,
+            items,
+            detail_failures: 0,
+            robots_blocked: 0,
+            http_requests: 0,
+            bytes_fetched: 0,
+            budget: Default::default(),
+            duration: Duration::from_millis(secs * 1000 + 250),
+            timed_out: false,
+            error: error.map(str::to_string),
+        }
+    }
+
+    fn notice(title: &str, end: &str) -> Notice {
+        Notice {
+            source: Source::Dacon,
+            kind: Kind::Contest,
+            title: title.into(),
+            raw_title: title.into(),
+            url: format!("https://dacon.io/competitions/official/{}/overview", end.replace('-', "")),
+            start: None,
+            end: Some(end.into()),
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        }
+    }
+
+    #[test]
+    fn source_pairs_are_tallied_regardless_of_order() {
+        let mut dedup = DedupCounts::default();
+        dedup.record_pair(&Source::Wevity, &Source::Campuspick);
+        dedup.record_pair(&Source::Campuspick, &Source::Wevity);
+        dedup.record_pair(&Source::Wevity, &Source::Dacon);
+        dedup.record_pair(&Source::Custom("onoffmix".into()), &Source::Wevity);
+        let got: Vec<(&str, &str, usize)> = dedup.pairs.iter().map(|((a, b), n)| (a.as_str(), b.as_str(), *n)).collect();
+        assert_eq!(
+            got,
+            [("Campuspick", "Wevity", 2), ("Dacon", "Wevity", 1), ("Wevity", "onoffmix", 1)]
+        );
+    }
+
+    #[test]
+    fn dedup_counts_url_and_cross_source_duplicates_by_pair() {
+        let with = |source: Source, title: &str, url: &str| Notice { source, url: url.into(), ..notice(title, "2026-10-20") };
+        let mut all = vec![
+            with(Source::Wevity, "AI 공모전", "https://w.example/1"),
+            with(Source::Wevity, "AI 공모전", "https://w.example/1?utm_source=x"),
+            with(Source::Campuspick, "AI  공모전", "https://c.example/1"),
+            with(Source::Dacon, "ai 공모전", "https://d.example/1"),
+            with(Source::Campuspick, "데이터 대회", "https://c.example/2"),
+            with(Source::Dacon, "데이터 대회", "https://d.example/2"),
+            with(Source::Dacon, "다른 대회", "https://d.example/3"),
+        ];
+        let dedup = DedupCounts::dedup(&mut all, &crate::notice::default_strip_params());
+        assert_eq!(dedup.url_dropped, 1);
+        assert_eq!(dedup.cross_dropped, 3);
+        let pairs: Vec<(&str, &str, usize)> = dedup.pairs.iter().map(|((a, b), n)| (a.as_str(), b.as_str(), *n)).collect();
+        assert_eq!(pairs, [("Campuspick", "Dacon", 1), ("Campuspick", "Wevity", 1), ("Dacon", "Wevity", 1)]);
+        let kept: Vec<&str> = all.iter().map(|n| n.url.as_str()).collect();
+        assert_eq!(kept, ["https://w.example/1", "https://c.example/2", "https://d.example/3"]);
+    }
+
+    #[test]
+    fn step_summary_snapshot() {
+        let sources = [
+            source("wevity", 12, 3, None),
+            source("dacon", 0, 1, None),
+            source("linkareer", 0, 30, Some("timed out")),
+        ];
+        let mut dedup = DedupCounts { url_dropped: 2, ..Default::default() };
+        dedup.record_pair(&Source::Wevity, &Source::Dacon);
+        dedup.record_pair(&Source::Dacon, &Source::Wevity);
+        dedup.cross_dropped = 2;
+        let all = [
+            notice("[공식] AI 대회", "2026-10-20"),
+            notice("지난 대회", "2026-10-01"),
+            notice("오늘 마감", "2026-10-14"),
+        ];
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let got = render_step_summary(&sources, &dedup, &["wevity dropped below baseline".into()], &all, today);
+        assert_eq!(got, include_str!("../tests/fixtures/snapshots/step_summary.md"), "actual:\n{got}");
+    }
+}
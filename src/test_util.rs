@@ -0,0 +1,147 @@
+// src/test_util.rs
+//! 단위 테스트용 로컬 HTTP 서버(네트워크 없이 수집기/재시도/robots를 검증)
+#![allow(dead_code)] // 테스트마다 쓰는 도우미가 달라 일부는 안 쓰일 수 있음
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// 응답 하나(상태/본문/지연)
+#[derive(Clone, Debug)]
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+    pub content_type: &'static str,
+    pub delay: Duration,
+}
+
+impl Reply {
+    pub fn ok(body: &str) -> Self {
+        Self { status: 200, body: body.to_string(), content_type: "text/html; charset=utf-8", delay: Duration::ZERO }
+    }
+
+    pub fn json(body: &str) -> Self {
+        Self { content_type: "application/json", ..Self::ok(body) }
+    }
+
+    pub fn status(status: u16) -> Self {
+        Self { status, ..Self::ok("") }
+    }
+
+    pub fn delayed(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+}
+
+/// 서버가 받은 요청 기록
+#[derive(Clone, Debug)]
+pub struct Recorded {
+    pub method: String,
+    /// 경로 + 쿼리
+    pub target: String,
+    /// 헤더 이름은 소문자
+    pub headers: HashMap<String, String>,
+    pub body: String,
+    pub at: Instant,
+}
+
+/// 경로별 응답 목록을 순서대로 돌려주는 서버. 마지막 응답은 계속 반복, 없는 경로는 404
+/// 라우트 키는 "경로?쿼리"(정확히 일치) 또는 "경로"(쿼리 무시). 정확히 일치하는 쪽이 먼저
+pub struct MockServer {
+    pub base: String,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl MockServer {
+    pub async fn start(routes: Vec<(&str, Vec<Reply>)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let routes: Arc<Mutex<HashMap<String, Vec<Reply>>>> =
+            Arc::new(Mutex::new(routes.into_iter().map(|(p, r)| (p.to_string(), r)).collect()));
+        let requests: Arc<Mutex<Vec<Recorded>>> = Arc::default();
+
+        let (routes_cl, requests_cl) = (routes.clone(), requests.clone());
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut sock, _)) = listener.accept().await else { break };
+                let (routes, requests) = (routes_cl.clone(), requests_cl.clone());
+                tokio::spawn(async move {
+                    let Some(req) = read_request(&mut sock).await else { return };
+                    let path = req.target.split('?').next().unwrap_or_default().to_string();
+                    let target = req.target.clone();
+                    requests.lock().unwrap().push(req);
+                    let reply = {
+                        let mut routes = routes.lock().unwrap();
+                        let key = if routes.contains_key(&target) { target } else { path };
+                        match routes.get_mut(&key) {
+                            Some(list) if list.len() > 1 => list.remove(0),
+                            Some(list) if !list.is_empty() => list[0].clone(),
+                            _ => Reply::status(404),
+                        }
+                    };
+                    tokio::time::sleep(reply.delay).await;
+                    let head = format!(
+                        "HTTP/1.1 {} X\r\ncontent-type: {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                        reply.status,
+                        reply.content_type,
+                        reply.body.len()
+                    );
+                    let _ = sock.write_all(head.as_bytes()).await;
+                    let _ = sock.write_all(reply.body.as_bytes()).await;
+                    let _ = sock.shutdown().await;
+                });
+            }
+        });
+        Self { base, requests }
+    }
+
+    /// 받은 요청(도착 순)
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// 경로(쿼리 제외)가 path인 요청들
+    pub fn requests_to(&self, path: &str) -> Vec<Recorded> {
+        self.requests().into_iter().filter(|r| r.target.split('?').next() == Some(path)).collect()
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base)
+    }
+}
+
+async fn read_request(sock: &mut tokio::net::TcpStream) -> Option<Recorded> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let head_end = loop {
+        let n = sock.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+    let at = Instant::now();
+    let head = String::from_utf8_lossy(&buf[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut first = lines.next()?.split(' ');
+    let method = first.next()?.to_string();
+    let target = first.next()?.to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_ascii_lowercase(), v.trim().to_string()))
+        .collect();
+    let len: usize = headers.get("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+    while buf.len() < head_end + len {
+        let n = sock.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    let body = String::from_utf8_lossy(&buf[head_end..]).into_owned();
+    Some(Recorded { method, target, headers, body, at })
+}
@@ -1,341 +1,816 @@
-// src/wevity.rs
-use anyhow::Result;
-use chrono::{NaiveDate, Local};
-use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT, ACCEPT, ACCEPT_LANGUAGE, CACHE_CONTROL, PRAGMA, REFERER};
-use reqwest::redirect::Policy;
-use scraper::{Html, Selector, ElementRef};
-use std::collections::HashSet;
-use std::time::{Duration, Instant};
-use tokio::{task::JoinSet, time::{sleep, timeout}};
-use url::Url;
-
-#[derive(Debug, Clone)]
-pub struct Contest {
-    pub title: String,
-    pub organizer: String,
-    pub url: String,
-    pub start: Option<String>,
-    pub end: Option<String>,
-    pub category: String,      // "공모전" or "대외활동"
-    pub field: Option<String>, // 리스트의 "div.sub-tit" 원문
-}
-
-/* ================= HTTP 공통 ================= */
-
-fn build_client() -> Result<reqwest::Client> {
-    let mut headers = HeaderMap::new();
-    headers.insert(USER_AGENT, HeaderValue::from_static(
-        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
-         (KHTML, like Gecko) Chrome/127.0.0.0 Safari/537.36"
-    ));
-    headers.insert(ACCEPT, HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"));
-    headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("ko-KR,ko;q=0.9,en-US;q=0.8,en;q=0.7"));
-    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
-    headers.insert(PRAGMA, HeaderValue::from_static("no-cache"));
-
-    Ok(reqwest::Client::builder()
-        .pool_max_idle_per_host(2)
-        .tcp_keepalive(Duration::from_secs(20))
-        .connect_timeout(Duration::from_secs(4))
-        .timeout(Duration::from_secs(3)) // 개별 요청 상한(추가로 아래 timeout()으로 더 타이트하게 감쌈)
-        .redirect(Policy::limited(10))
-        .default_headers(headers)
-        .build()?)
-}
-
-async fn prewarm_home(client: &reqwest::Client) {
-    let _ = timeout(Duration::from_secs(2), client.get("https://www.wevity.com/").send()).await;
-}
-
-fn looks_like_bot(status: reqwest::StatusCode, body: &str) -> bool {
-    status.as_u16() == 403
-        || status.as_u16() == 503
-        || body.contains("cf-ray")
-        || body.contains("Attention Required")
-        || body.contains("Please wait while your request is being verified")
-}
-
-async fn fetch_html_with_retry(client: &reqwest::Client, url: &str, referer: &str) -> Option<String> {
-    let mut backoff = 300u64;
-    for _ in 0..3 {
-        let fut = client.get(url).header(REFERER, referer).send();
-        match timeout(Duration::from_millis(2200), fut).await {
-            Ok(Ok(resp)) => {
-                let status = resp.status();
-                let text = match resp.text().await { Ok(t) => t, Err(_) => String::new() };
-                if status.is_success() && !looks_like_bot(status, &text) && !text.is_empty() {
-                    return Some(text);
-                }
-            }
-            _ => {}
-        }
-        sleep(Duration::from_millis(backoff)).await;
-        backoff = (backoff * 2).min(1500);
-    }
-    None
-}
-
-/* ================= 상세 파싱 ================= */
-
-async fn fetch_detail_and_build_contest(
-    client: reqwest::Client,
-    url_abs: String,
-    title: String,
-    field_text: Option<String>,
-    category_label: &str,
-    list_referer: &str,
-) -> Option<Contest> {
-    let html = fetch_html_with_retry(&client, &url_abs, list_referer).await?;
-    let doc = Html::parse_document(&html);
-
-    // 기간
-    let sel_during = Selector::parse(r#"input[name="during"]"#).ok()?;
-    let raw = doc.select(&sel_during).next()
-        .and_then(|n| n.value().attr("value")).unwrap_or("");
-    let (apply_start, apply_end) = parse_period_value(raw);
-
-    // 주최/주관
-    let mut organizer = String::new();
-    let sel_li  = Selector::parse("ul.cd-info-list > li").ok()?;
-    let sel_tit = Selector::parse("span.tit").ok()?;
-    for li in doc.select(&sel_li) {
-        let label = li.select(&sel_tit).next()
-            .map(|n| norm_text(&n.text().collect::<String>())).unwrap_or_default();
-        if label.contains("주최") || label.contains("주관") {
-            let full = norm_text(&li.text().collect::<String>());
-            let mut value = full.replacen(&label, "", 1);
-            value = value.trim_start().to_string();
-            organizer = norm_text(&value);
-            break;
-        }
-    }
-
-    Some(Contest {
-        title,
-        organizer,
-        url: url_abs,
-        start: apply_start,
-        end: apply_end,
-        category: category_label.to_string(),
-        field: field_text,
-    })
-}
-
-/* ================= 카테고리 크롤러(시간예산 보장) ================= */
-
-async fn scrape_wevity_category(base_url: &str, category_label: &str) -> Result<Vec<Contest>> {
-    let client = build_client()?;
-    prewarm_home(&client).await;
-
-    // ===== 시간/페이지/동시성 파라미터 =====
-    let budget_secs: u64 = std::env::var("WEVITY_BUDGET_SECS").ok().and_then(|s| s.parse().ok()).unwrap_or(9);
-    let max_pages: usize = std::env::var("WEVITY_MAX_PAGES").ok().and_then(|s| s.parse().ok()).unwrap_or(3);
-    let max_conc: usize  = std::env::var("WEVITY_MAX_CONC").ok().and_then(|s| s.parse().ok()).unwrap_or(4);
-
-    let started = Instant::now();
-    let budget  = Duration::from_secs(budget_secs);
-
-    let sel_tit_link = Selector::parse("div.hide-tit > a, div.tit > a").unwrap();
-    let sel_subtit   = Selector::parse("div.sub-tit").unwrap();
-
-    let mut items: Vec<Contest> = Vec::new();
-    let mut seen: HashSet<String> = HashSet::new();
-
-    'page_loop: for page in 1..=max_pages {
-        if started.elapsed() >= budget { break; }
-
-        let url = format!("{}&gp={}", base_url, page);
-        let html = match fetch_html_with_retry(&client, &url, base_url).await {
-            Some(h) => h,
-            None => { sleep(Duration::from_millis(200)).await; continue; }
-        };
-        let doc = Html::parse_document(&html);
-
-        // 리스트에서 후보 수집
-        let mut entries: Vec<(String, String, Option<String>)> = Vec::new();
-        for a in doc.select(&sel_tit_link) {
-            let title = norm_text(&a.text().collect::<String>());
-            let href  = a.value().attr("href").unwrap_or("").trim();
-            if title.is_empty() || href.is_empty() { continue; }
-            let url_abs = match Url::parse("https://www.wevity.com").and_then(|u| u.join(href)) {
-                Ok(u) => u.to_string(),
-                Err(_) => continue,
-            };
-
-            let mut field_text: Option<String> = None;
-            if let Some(li) = find_ancestor_li(&a) {
-                if let Some(sub) = li.select(&sel_subtit).next() {
-                    field_text = Some(norm_text(&sub.text().collect::<String>()));
-                }
-            }
-
-            if !seen.insert(url_abs.clone()) { continue; }
-            entries.push((title, url_abs, field_text));
-        }
-
-        // 상세 병렬 (시간예산 체크)
-        let mut join = JoinSet::new();
-        let mut i = 0usize;
-        let total = entries.len();
-        let mut got = 0usize;
-
-        while i < total {
-            // 슬롯 채우기
-            while join.len() < max_conc && i < total {
-                if started.elapsed() >= budget { break 'page_loop; }
-                let (title, url_abs, field_text) = entries[i].clone();
-                i += 1;
-
-                let client_cl = client.clone();
-                let cat = category_label.to_owned();
-                let referer = url.clone();
-                join.spawn(async move {
-                    fetch_detail_and_build_contest(client_cl, url_abs, title, field_text, &cat, &referer).await
-                });
-            }
-
-            if started.elapsed() >= budget { break 'page_loop; }
-
-            if let Some(res) = join.join_next().await {
-                if let Ok(Some(contest)) = res {
-                    items.push(contest);
-                    got += 1;
-                }
-            } else { break; }
-        }
-
-        // 남은 작업 수거
-        while let Some(res) = join.join_next().await {
-            if started.elapsed() >= budget { break 'page_loop; }
-            if let Ok(Some(contest)) = res {
-                items.push(contest);
-                got += 1;
-            }
-        }
-
-        // 페이지 이동 간 살짝 쉼
-        sleep(Duration::from_millis(150)).await;
-
-        // 이 페이지에서 아무 것도 못 얻었으면 다음으로
-        if got == 0 && started.elapsed() >= budget {
-            break;
-        }
-    }
-
-    // 오늘 이후만 남기기
-    let today = Local::now().date_naive();
-    items.retain(|c| {
-        if let Some(ref end_str) = c.end {
-            if let Ok(end_date) = NaiveDate::parse_from_str(end_str, "%Y-%m-%d") {
-                if end_date < today { return false; }
-            }
-        }
-        true
-    });
-
-    // === 마감이 20일 이내인 것만 남기기 ===
-    let cutoff = today
-        .checked_add_signed(chrono::Duration::days(20))
-        .unwrap();
-
-    items.retain(|c| {
-        if let Some(ref end_str) = c.end {
-            if let Ok(end_date) = NaiveDate::parse_from_str(end_str, "%Y-%m-%d") {
-            // 오늘 포함 ~ 20일 이내만 남김
-            return end_date <= cutoff;
-            }
-        }
-        false // end가 없는 경우는 제외
-    });
-
-    Ok(items)
-}
-
-// 활동 제목 키워드(전부 소문자)
-const ACTIVITY_KEYWORDS: &[&str] = &[
-    "it","sw","코딩","소프트웨어","컴퓨터","보안","정보보호","kisia",
-    "개인정보","개발자","ai","엔지니어","부트캠프",
-];
-
-fn matches_activity_keywords(title: &str) -> bool {
-    let t = title.to_lowercase(); // 한글은 소문자 영향 없음, 영문만 통일
-    ACTIVITY_KEYWORDS.iter().any(|k| t.contains(*k))
-}
-
-/* ================= 외부 공개 함수 ================= */
-
-pub async fn scrape_wevity_contests() -> Result<Vec<Contest>> {
-    let urls = [
-        "https://www.wevity.com/?c=find&s=1&gub=1&cidx=20",
-        "https://www.wevity.com/?c=find&s=1&gub=1&cidx=21",
-    ];
-    let mut all = Vec::new();
-    let mut seen = HashSet::new();
-    for u in urls {
-        let mut batch = scrape_wevity_category(u, "공모전").await?;
-        batch.retain(|c| seen.insert(c.url.clone()));
-        all.extend(batch);
-    }
-    Ok(all)
-}
-
-pub async fn scrape_wevity_activities() -> Result<Vec<Contest>> {
-    let mut items = scrape_wevity_category("https://www.wevity.com/?c=active&s=1", "대외활동").await?;
-
-    // 제목 필터링
-    items.retain(|c| matches_activity_keywords(&c.title));
-
-    Ok(items)
-}
-
-/* ================= 유틸 ================= */
-
-fn norm_text(s: &str) -> String {
-    let t = s.replace('\u{00A0}', " ")
-        .replace('\r', " ")
-        .replace('\n', " ")
-        .replace('\t', " ");
-    t.split_whitespace().collect::<Vec<_>>().join(" ").trim().to_string()
-}
-
-fn parse_period_value(v: &str) -> (Option<String>, Option<String>) {
-    let parts: Vec<&str> = v.split('~').collect();
-    let start = parts.get(0).and_then(|s| parse_ymd_str(s));
-    let end   = parts.get(1).and_then(|s| parse_ymd_str(s));
-    (start, end)
-}
-
-fn parse_ymd_str(s: &str) -> Option<String> {
-    let keep: String = s.chars().filter(|&c| c.is_ascii_digit() || c == '-').collect();
-    if keep.len() < 10 { return None; }
-    let ymd = &keep[..10];
-    NaiveDate::parse_from_str(ymd, "%Y-%m-%d").ok()?;
-    Some(ymd.to_string())
-}
-
-fn find_ancestor_li<'a>(a: &ElementRef<'a>) -> Option<ElementRef<'a>> {
-    for node in a.ancestors() {
-        if let Some(el) = ElementRef::wrap(node) {
-            if el.value().name() == "li" { return Some(el); }
-        }
-    }
-    None
-}
-
-// === Notice 어댑터 ===
-use crate::notice::{Notice, Source, Kind};
-pub fn to_notice_from_wevity(c: &Contest) -> Notice {
-    Notice {
-        source: Source::Wevity,
-        kind: if c.category == "대외활동" { Kind::Activity } else { Kind::Contest },
-        title: c.title.clone(),
-        url: c.url.clone(),
-        start: c.start.clone(),
-        end: c.end.clone(),
-        organizer: if c.organizer.trim().is_empty() { None } else { Some(c.organizer.clone()) },
-        field: c.field.clone(),
-    }
-}
-
-
-
+// src/wevity.rs
+use anyhow::Result;
+use chrono::NaiveDate;
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT, ACCEPT, ACCEPT_LANGUAGE, CACHE_CONTROL, PRAGMA, REFERER};
+use reqwest::redirect::Policy;
+use scraper::{Html, Selector, ElementRef};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use tokio::{task::JoinSet, time::{sleep, timeout}};
+use url::Url;
+use serde::Deserialize;
+
+use tracing::{info, warn};
+
+use crate::dates;
+use crate::source::CollectStats;
+use crate::keywords::KeywordMatcher;
+
+#[derive(Debug, Clone)]
+pub struct Contest {
+    pub title: String,
+    pub organizer: String,
+    pub url: String,
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub category: String,      // "공모전" or "대외활동"
+    pub field: Option<String>, // 리스트의 "div.sub-tit" 원문
+    pub summary: Option<String>, // 상세 본문 소개문(notice::make_summary)
+}
+
+/// wevity 수집 설정(설정 파일 [wevity] 섹션)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WevityOptions {
+    /// 오늘부터 마감까지 남은 일수 상한([deadline_days]에서 결정)
+    #[serde(skip)]
+    pub deadline_days: i64,
+    /// 마감까지 최소 남은 일수(config의 min_deadline_days)
+    #[serde(skip)]
+    pub min_deadline_days: i64,
+    /// 카테고리별 시간 예산(초)
+    pub budget_secs: u64,
+    /// 목록 최대 페이지 수(새 항목이 없는 페이지가 나오면 그 전에 멈춤)
+    pub max_pages: usize,
+    /// 상세 요청 동시성
+    pub max_conc: usize,
+    /// 호스트당 초당 최대 요청 수(목록/상세 공통, 0이면 제한 없음)
+    pub max_rps: f64,
+    /// robots.txt의 Disallow를 따름(IGNORE_ROBOTS=1 / --ignore-robots면 끔)
+    pub respect_robots: bool,
+    /// 지난 마감도 유지(아카이브 피드용)
+    #[serde(skip)]
+    pub archive: bool,
+    /// 공통 HTTP 설정(config의 [http])
+    #[serde(skip)]
+    pub http: crate::http::HttpOptions,
+    /// 요청마다 덮어쓸 헤더([wevity.headers], 같은 이름의 기본 헤더를 대체)
+    pub headers: std::collections::HashMap<String, String>,
+    /// 대외활동 제목 키워드(문법은 keywords 모듈 참고)
+    pub activity_keywords: Vec<String>,
+    /// 사이트 주소(홈 예열, 상대 링크 기준). 테스트/미러용으로 바꿀 수 있음
+    pub base_url: String,
+    /// 공모전 목록 URL들
+    pub contest_list_urls: Vec<String>,
+    /// 대외활동 목록 URL
+    pub activity_list_url: String,
+}
+
+impl Default for WevityOptions {
+    fn default() -> Self {
+        Self {
+            deadline_days: 20,
+            min_deadline_days: 0,
+            budget_secs: 9,
+            max_pages: 3,
+            max_conc: 4,
+            max_rps: 4.0,
+            respect_robots: true,
+            archive: false,
+            http: crate::http::HttpOptions::default(),
+            headers: std::collections::HashMap::new(),
+            activity_keywords: ACTIVITY_KEYWORDS.iter().map(|k| k.to_string()).collect(),
+            base_url: BASE_URL.into(),
+            contest_list_urls: CONTEST_LIST_URLS.iter().map(|u| u.to_string()).collect(),
+            activity_list_url: ACTIVITY_LIST_URL.into(),
+        }
+    }
+}
+
+/* ================= HTTP 공통 ================= */
+
+/// 위비티는 봇 UA를 막아 브라우저 UA로 요청(robots.txt도 이 UA 기준으로 판단)
+const UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
+    (KHTML, like Gecko) Chrome/127.0.0.0 Safari/537.36";
+
+fn build_client(http: &crate::http::HttpOptions) -> Result<reqwest::Client> {
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, HeaderValue::from_static(UA));
+    headers.insert(ACCEPT, HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"));
+    headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("ko-KR,ko;q=0.9,en-US;q=0.8,en;q=0.7"));
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    headers.insert(PRAGMA, HeaderValue::from_static("no-cache"));
+
+    Ok(crate::http::client_builder(http)?
+        .tcp_keepalive(Duration::from_secs(20))
+        .connect_timeout(Duration::from_secs(4))
+        .timeout(Duration::from_secs(3)) // 개별 요청 상한(추가로 아래 timeout()으로 더 타이트하게 감쌈)
+        .redirect(Policy::limited(10))
+        .default_headers(headers)
+        .build()?)
+}
+
+async fn prewarm_home(client: &reqwest::Client, base_url: &str, stats: &CollectStats) {
+    let home = client.get(format!("{}/", base_url.trim_end_matches('/'))).send();
+    if let Ok(Ok(resp)) = timeout(Duration::from_secs(2), home).await {
+        let _ = crate::http::read_text(resp, stats).await;
+    }
+}
+
+fn looks_like_bot(status: reqwest::StatusCode, body: &str) -> bool {
+    status.as_u16() == 403
+        || status.as_u16() == 503
+        || body.contains("cf-ray")
+        || body.contains("Attention Required")
+        || body.contains("Please wait while your request is being verified")
+}
+
+async fn fetch_html_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    referer: &str,
+    headers: &HeaderMap,
+    max_rps: f64,
+    stats: &CollectStats,
+) -> Option<String> {
+    let mut backoff = 300u64;
+    for _ in 0..3 {
+        stats.add_rate_limit_wait(crate::rate_limit::throttle(url, max_rps).await);
+        let fut = crate::http::with_headers(client.get(url).header(REFERER, referer), headers).send();
+        if let Ok(Ok(resp)) = timeout(Duration::from_millis(2200), fut).await {
+            let status = resp.status();
+            let text = crate::http::read_text(resp, stats).await.unwrap_or_default();
+            if status.is_success() && !looks_like_bot(status, &text) && !text.is_empty() {
+                return Some(text);
+            }
+        }
+        sleep(Duration::from_millis(backoff)).await;
+        backoff = (backoff * 2).min(1500);
+    }
+    None
+}
+
+/* ================= 상세 파싱 ================= */
+
+/// 한 목록 페이지의 상세 요청들이 함께 쓰는 값
+#[derive(Clone)]
+struct DetailFetch {
+    client: reqwest::Client,
+    /// 목록 페이지 URL(Referer)
+    referer: String,
+    /// 설정 헤더([wevity.headers])
+    headers: HeaderMap,
+    max_rps: f64,
+    stats: CollectStats,
+}
+
+async fn fetch_detail_and_build_contest(
+    ctx: DetailFetch,
+    url_abs: String,
+    title: String,
+    field_text: Option<String>,
+    category_label: &str,
+) -> Option<Contest> {
+    let html = fetch_html_with_retry(&ctx.client, &url_abs, &ctx.referer, &ctx.headers, ctx.max_rps, &ctx.stats).await?;
+    let parse_started = Instant::now();
+    let contest = parse_detail_page(&html, url_abs, title, field_text, category_label);
+    ctx.stats.add_parse_time(parse_started.elapsed());
+    contest
+}
+
+/// 상세 페이지 HTML → Contest(네트워크 없음, 벤치/테스트에서도 사용)
+/// 기간은 input[name=during] → 본문의 "접수기간" 문구 순서로 찾음
+pub fn parse_detail_page(
+    html: &str,
+    url_abs: String,
+    title: String,
+    field_text: Option<String>,
+    category_label: &str,
+) -> Option<Contest> {
+    let doc = Html::parse_document(html);
+
+    // 기간: input[name=during] → (없으면) 본문 텍스트의 날짜 범위
+    let sel_during = Selector::parse(r#"input[name="during"]"#).ok()?;
+    let raw = doc.select(&sel_during).next()
+        .and_then(|n| n.value().attr("value")).unwrap_or("");
+    let (mut apply_start, mut apply_end) = parse_period_value(raw);
+    if apply_end.is_none() {
+        warn!(url = %url_abs, "period selector missed, falling back to page text");
+        if let Some((s, e)) = period_from_text(&doc) {
+            apply_start = s;
+            apply_end = e;
+        }
+    }
+
+    // 주최/주관
+    let mut organizer = String::new();
+    let sel_li  = Selector::parse("ul.cd-info-list > li").ok()?;
+    let sel_tit = Selector::parse("span.tit").ok()?;
+    let mut info_rows = 0usize;
+    for li in doc.select(&sel_li) {
+        info_rows += 1;
+        let label = li.select(&sel_tit).next()
+            .map(|n| norm_text(&n.text().collect::<String>())).unwrap_or_default();
+        if label.contains("주최") || label.contains("주관") {
+            organizer = organizer_from_row(&label, &li.text().collect::<String>());
+            break;
+        }
+    }
+    if info_rows == 0 {
+        warn!(url = %url_abs, "info list selector missed (organizer unknown)");
+    }
+
+    // 본문 소개문(상세 내용 블록)
+    let sel_desc = Selector::parse("div.comm-desc, #viewContents").ok()?;
+    let summary = doc.select(&sel_desc).next()
+        .and_then(|n| make_summary(&n.text().collect::<Vec<_>>().join(" ")));
+
+    Some(Contest {
+        title,
+        organizer,
+        url: url_abs,
+        start: apply_start,
+        end: apply_end,
+        category: category_label.to_string(),
+        field: field_text,
+        summary,
+    })
+}
+
+/// 본문 텍스트에서 기간 추정("접수기간" 뒤를 먼저, 없으면 전체)
+fn period_from_text(doc: &Html) -> Option<(Option<String>, Option<String>)> {
+    let text = norm_text(&doc.root_element().text().collect::<Vec<_>>().join(" "));
+    let near_label = ["접수기간", "접수 기간", "모집기간", "기간"]
+        .iter()
+        .find_map(|kw| text.find(kw).map(|i| text[i..].chars().take(120).collect::<String>()));
+    near_label
+        .map(|t| dates::parse_range(&t, None))
+        .filter(|(_, e)| e.is_some())
+        .or_else(|| Some(dates::parse_range(&text, None)))
+        .map(|(s, e)| (s.as_deref().and_then(parse_ymd_str), e.as_deref().and_then(parse_ymd_str)))
+        .filter(|(_, e)| e.is_some())
+}
+
+/// 목록 페이지 HTML → (제목, 절대 URL, 분야 원문) 후보들(페이지 안 순서, 상대 링크는 base 기준)
+pub fn parse_list_page(html: &str, base: &Url) -> Vec<(String, String, Option<String>)> {
+    let sel_tit_link = Selector::parse("div.hide-tit > a, div.tit > a").unwrap();
+    let sel_subtit   = Selector::parse("div.sub-tit").unwrap();
+    let doc = Html::parse_document(html);
+
+    let mut entries = Vec::new();
+    for a in doc.select(&sel_tit_link) {
+        let title = norm_text(&a.text().collect::<String>());
+        let href  = a.value().attr("href").unwrap_or("").trim();
+        if title.is_empty() || href.is_empty() { continue; }
+        let url_abs = match base.join(href) {
+            Ok(u) => u.to_string(),
+            Err(_) => continue,
+        };
+
+        let field_text = find_ancestor_li(&a)
+            .and_then(|li| li.select(&sel_subtit).next())
+            .map(|sub| norm_text(&sub.text().collect::<String>()));
+        entries.push((title, url_abs, field_text));
+    }
+    entries
+}
+
+/* ================= 카테고리 크롤러(시간예산 보장) ================= */
+
+/// 시간예산 사용 내역(리스트/상세 소요 시간, 예산 소진 여부)
+#[derive(Debug, Default)]
+struct BudgetReport {
+    list_time: Duration,
+    detail_time: Duration,
+    pages_done: usize,
+    exhausted: Option<usize>, // 예산 소진으로 중단된 경우 남은 상세 건수
+}
+
+impl BudgetReport {
+    /// 카테고리 결과를 로그로 남기고 소스 단위 예산 사용량(stats)에 더함
+    fn finish(&self, category_label: &str, max_pages: usize, stats: &CollectStats) {
+        stats.add_list_time(self.list_time);
+        stats.add_detail_time(self.detail_time);
+        if let Some(pending) = self.exhausted {
+            stats.budget_exhausted(pending);
+        }
+        info!(
+            category = category_label,
+            list_ms = self.list_time.as_millis() as u64,
+            detail_ms = self.detail_time.as_millis() as u64,
+            pages = self.pages_done,
+            max_pages,
+            "category done"
+        );
+        if let Some(pending) = self.exhausted {
+            warn!(
+                category = category_label,
+                page = self.pages_done,
+                max_pages,
+                pending,
+                "budget exhausted, details pending"
+            );
+        }
+    }
+}
+
+async fn scrape_wevity_category(
+    list_url: &str,
+    category_label: &str,
+    opts: &WevityOptions,
+    stats: &CollectStats,
+) -> Result<Vec<Contest>> {
+    let client = build_client(&opts.http)?;
+    let custom_headers = crate::http::header_map(&opts.headers)?;
+    if !opts.respect_robots || crate::robots::allowed(&client, UA, &format!("{}/", opts.base_url.trim_end_matches('/'))).await {
+        prewarm_home(&client, &opts.base_url, stats).await;
+    }
+    let base = Url::parse(&opts.base_url)?;
+
+    // ===== 시간/페이지/동시성 파라미터 =====
+    let (budget_secs, max_pages, max_conc) = (opts.budget_secs, opts.max_pages, opts.max_conc);
+
+    let started = Instant::now();
+    let budget  = Duration::from_secs(budget_secs);
+
+    let mut items: Vec<Contest> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut report = BudgetReport::default();
+
+    'page_loop: for page in 1..=max_pages {
+        if started.elapsed() >= budget {
+            report.exhausted = Some(0);
+            break;
+        }
+
+        let list_started = Instant::now();
+        let url = format!("{}&gp={}", list_url, page);
+        if opts.respect_robots && !crate::robots::allowed(&client, UA, &url).await {
+            warn!(url = %url, "list page disallowed by robots.txt");
+            stats.add_robots_blocked();
+            break;
+        }
+        // 제한기 대기까지 포함해 남은 예산 안에서만 기다림
+        let list_fetch = timeout(budget.saturating_sub(started.elapsed()), fetch_html_with_retry(&client, &url, list_url, &custom_headers, opts.max_rps, stats));
+        let html = match list_fetch.await {
+            Ok(Some(h)) => h,
+            Err(_) => {
+                report.list_time += list_started.elapsed();
+                report.exhausted = Some(0);
+                break;
+            }
+            Ok(None) => {
+                report.list_time += list_started.elapsed();
+                stats.pace(Duration::from_millis(200)).await;
+                continue;
+            }
+        };
+        report.list_time += list_started.elapsed();
+        // 리스트에서 후보 수집(이미 본 URL 제외)
+        let parse_started = Instant::now();
+        let entries: Vec<(String, String, Option<String>)> = parse_list_page(&html, &base)
+            .into_iter()
+            .filter(|(_, url_abs, _)| seen.insert(url_abs.clone()))
+            .collect();
+        stats.add_parse_time(parse_started.elapsed());
+        // 새 항목이 없는 페이지면 마지막 페이지를 지난 것(max_pages는 상한일 뿐)
+        if entries.is_empty() {
+            info!(category = category_label, page, "no new entries, stopping pagination");
+            break;
+        }
+        report.pages_done = page;
+        stats.add_raw(entries.len());
+
+        // 상세 병렬 (시간예산 체크)
+        let detail_started = Instant::now();
+        let mut join = JoinSet::new();
+        let mut i = 0usize;
+        let total = entries.len();
+        let mut got = 0usize;
+
+        while i < total {
+            // 슬롯 채우기
+            while join.len() < max_conc && i < total {
+                if started.elapsed() >= budget {
+                    report.exhausted = Some(total - i + join.len());
+                    report.detail_time += detail_started.elapsed();
+                    break 'page_loop;
+                }
+                let (title, url_abs, field_text) = entries[i].clone();
+                i += 1;
+                if opts.respect_robots && !crate::robots::allowed(&client, UA, &url_abs).await {
+                    stats.add_robots_blocked();
+                    continue;
+                }
+
+                let ctx = DetailFetch {
+                    client: client.clone(),
+                    referer: url.clone(),
+                    headers: custom_headers.clone(),
+                    max_rps: opts.max_rps,
+                    stats: stats.clone(),
+                };
+                let cat = category_label.to_owned();
+                join.spawn(async move {
+                    fetch_detail_and_build_contest(ctx, url_abs, title, field_text, &cat).await
+                });
+            }
+
+            if started.elapsed() >= budget {
+                report.exhausted = Some(total - i + join.len());
+                report.detail_time += detail_started.elapsed();
+                break 'page_loop;
+            }
+
+            // 예산이 끝나면 제한기에서 기다리는 작업도 JoinSet drop으로 취소됨
+            let Ok(next) = timeout(budget.saturating_sub(started.elapsed()), join.join_next()).await else {
+                report.exhausted = Some(total - i + join.len());
+                report.detail_time += detail_started.elapsed();
+                break 'page_loop;
+            };
+            if let Some(res) = next {
+                if let Ok(Some(contest)) = res {
+                    items.push(contest);
+                    got += 1;
+                } else {
+                    stats.add_detail_failure();
+                }
+            } else { break; }
+        }
+
+        // 남은 작업 수거
+        loop {
+            let Ok(next) = timeout(budget.saturating_sub(started.elapsed()), join.join_next()).await else {
+                report.exhausted = Some(join.len());
+                report.detail_time += detail_started.elapsed();
+                break 'page_loop;
+            };
+            let Some(res) = next else { break };
+            if let Ok(Some(contest)) = res {
+                items.push(contest);
+                got += 1;
+            } else {
+                stats.add_detail_failure();
+            }
+        }
+        report.detail_time += detail_started.elapsed();
+
+        // 페이지 이동 간 살짝 쉼
+        stats.pace(Duration::from_millis(150)).await;
+
+        // 이 페이지에서 아무 것도 못 얻었으면 다음으로
+        if got == 0 && started.elapsed() >= budget {
+            break;
+        }
+    }
+
+    report.finish(category_label, max_pages, stats);
+
+    // 오늘 + min_deadline_days 이후만 남기기(ARCHIVE 모드에서는 지난 마감도 유지)
+    let today = crate::clock::today();
+    items.retain(|c| {
+        if let Some(ref end_str) = c.end
+            && let Ok(end_date) = NaiveDate::parse_from_str(end_str, "%Y-%m-%d") {
+            let days = (end_date - today).num_days();
+            return dates::within_deadline(days, opts.min_deadline_days, i64::MAX, opts.archive);
+        }
+        true
+    });
+
+    // === 마감이 deadline_days일 이내인 것만 남기기 ===
+    let cutoff = today
+        .checked_add_signed(chrono::Duration::days(opts.deadline_days))
+        .unwrap();
+
+    items.retain(|c| {
+        if let Some(ref end_str) = c.end
+            && let Ok(end_date) = NaiveDate::parse_from_str(end_str, "%Y-%m-%d") {
+            // 오늘 포함 ~ deadline_days일 이내만 남김
+            return end_date <= cutoff;
+        }
+        false // end가 없는 경우는 제외
+    });
+
+    Ok(items)
+}
+
+// 활동 제목 키워드(전부 소문자)
+const ACTIVITY_KEYWORDS: &[&str] = &[
+    "it","sw","코딩","소프트웨어","컴퓨터","보안","정보보호","kisia",
+    "개인정보","개발자","ai","엔지니어","부트캠프",
+];
+
+
+/* ================= 외부 공개 함수 ================= */
+
+/// 사이트 주소 기본값
+pub const BASE_URL: &str = "https://www.wevity.com";
+/// 공모전 목록 URL 기본값(IT/SW 관련 분야)
+pub const CONTEST_LIST_URLS: &[&str] = &[
+    "https://www.wevity.com/?c=find&s=1&gub=1&cidx=20",
+    "https://www.wevity.com/?c=find&s=1&gub=1&cidx=21",
+];
+/// 대외활동 목록 URL 기본값
+pub const ACTIVITY_LIST_URL: &str = "https://www.wevity.com/?c=active&s=1";
+
+pub async fn scrape_wevity_contests(opts: &WevityOptions, stats: &CollectStats) -> Result<Vec<Contest>> {
+    let mut all = Vec::new();
+    let mut seen = HashSet::new();
+    for u in &opts.contest_list_urls {
+        let mut batch = scrape_wevity_category(u, "공모전", opts, stats).await?;
+        batch.retain(|c| seen.insert(c.url.clone()));
+        all.extend(batch);
+    }
+    Ok(all)
+}
+
+pub async fn scrape_wevity_activities(opts: &WevityOptions, stats: &CollectStats) -> Result<Vec<Contest>> {
+    let mut items = scrape_wevity_category(&opts.activity_list_url, "대외활동", opts, stats).await?;
+
+    // 제목 필터링
+    let matcher = KeywordMatcher::new(&opts.activity_keywords)?;
+    items.retain(|c| matcher.is_match(&c.title));
+
+    Ok(items)
+}
+
+/* ================= 유틸 ================= */
+
+/// 공백 정리만(NBSP/개행/탭 → 공백, 연속 공백 축약)
+/// 엔티티는 scraper가 이미 디코딩했으므로 여기서 다시 디코딩하면 "&amp;lt;"가 "<"까지 풀림
+fn norm_text(s: &str) -> String {
+    let t = s.replace(['\u{00A0}', '\r', '\n', '\t'], " ");
+    t.split_whitespace().collect::<Vec<_>>().join(" ").trim().to_string()
+}
+
+/// 정보 행 텍스트에서 라벨을 떼고 clean_organizer로 정리
+fn organizer_from_row(label: &str, row_text: &str) -> String {
+    clean_organizer(&norm_text(row_text).replacen(label, "", 1))
+}
+
+/// 라벨 제거 후 남은 앞쪽 구분자(":", "-", "：" 등)와 공백 정리
+/// "주최 : ㈜ABC" → (라벨 제거) ": ㈜ABC" → "㈜ABC"
+fn clean_organizer(s: &str) -> String {
+    let t = norm_text(s);
+    t.trim_start_matches(|c: char| matches!(c, ':' | '：' | '-' | '|' | '·') || c.is_whitespace())
+        .to_string()
+}
+
+fn parse_period_value(v: &str) -> (Option<String>, Option<String>) {
+    let parts: Vec<&str> = v.split('~').collect();
+    let start = parts.first().and_then(|s| parse_ymd_str(s));
+    let end   = parts.get(1).and_then(|s| parse_ymd_str(s));
+    (start, end)
+}
+
+/// "2024.05.03" / "2024-5-3" 등 → "2024-05-03"
+fn parse_ymd_str(s: &str) -> Option<String> {
+    dates::parse_ymd(s).map(|d| d.format("%Y-%m-%d").to_string())
+}
+
+fn find_ancestor_li<'a>(a: &ElementRef<'a>) -> Option<ElementRef<'a>> {
+    for node in a.ancestors() {
+        if let Some(el) = ElementRef::wrap(node)
+            && el.value().name() == "li" { return Some(el); }
+    }
+    None
+}
+
+// === Notice 어댑터 ===
+use crate::notice::{Notice, Source, Kind, clean_title, make_summary, sort_join};
+pub fn to_notice_from_wevity(c: &Contest) -> Notice {
+    Notice {
+        source: Source::Wevity,
+        kind: if c.category == "대외활동" { Kind::Activity } else { Kind::Contest },
+        title: clean_title(&c.title, &Source::Wevity),
+        raw_title: c.title.clone(),
+        url: c.url.clone(),
+        start: c.start.clone(),
+        end: c.end.clone(),
+        organizer: if c.organizer.trim().is_empty() { None } else { Some(sort_join(&c.organizer, '/', " / ")) },
+        field: c.field.as_deref().map(|f| sort_join(f, ',', ", ")),
+        end_approx: false,
+        summary: c.summary.clone(),
+        series_id: None,
+        changed_at: None,
+    }
+}
+
+/// 공모전 목록 URL들의 분야 루트: 분야 파라미터(cidx)를 뺀 URL이 모두 같으면 그 URL
+/// ("?c=find&s=1&gub=1&cidx=20", "…&cidx=21" → "?c=find&s=1&gub=1"). 목록이 없거나 서로 다르면 None
+pub fn category_root(list_urls: &[String]) -> Option<String> {
+    let mut roots = list_urls.iter().map(|u| {
+        let mut url = Url::parse(u).ok()?;
+        let pairs: Vec<(String, String)> =
+            url.query_pairs().filter(|(k, _)| k != "cidx").map(|(k, v)| (k.into_owned(), v.into_owned())).collect();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+        Some(url.to_string())
+    });
+    let first = roots.next()??;
+    roots.all(|r| r.as_deref() == Some(first.as_str())).then_some(first)
+}
+
+// === Crawler 구현 ===
+pub struct WevityCrawler {
+    pub opts: WevityOptions,
+    pub timeout: Duration,
+    /// 채널 링크용 분야 루트(category_root, 여러 분야 목록을 한 링크로)
+    list_root: Option<String>,
+}
+
+impl WevityCrawler {
+    pub fn new(opts: WevityOptions, timeout: Duration) -> Self {
+        let list_root = category_root(&opts.contest_list_urls);
+        Self { opts, timeout, list_root }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl crate::source::Crawler for WevityCrawler {
+    fn name(&self) -> &str { "wevity" }
+    fn site_url(&self) -> &str { &self.opts.base_url }
+    fn list_url(&self) -> Option<&str> { self.list_root.as_deref() }
+    fn timeout(&self) -> Duration { self.timeout }
+
+    /// 공모전/대외활동 동시에 수집
+    async fn collect(&self, stats: &CollectStats) -> Result<Vec<Notice>> {
+        let (contests, activities) = tokio::join!(
+            scrape_wevity_contests(&self.opts, stats),
+            scrape_wevity_activities(&self.opts, stats),
+        );
+        let (contests, activities) = (contests?, activities?);
+
+        let mut out: Vec<Notice> = Vec::with_capacity(contests.len() + activities.len());
+        out.extend(contests.iter().map(to_notice_from_wevity));
+        out.extend(activities.iter().map(to_notice_from_wevity));
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockServer, Reply};
+
+    const LIST: &str = "/?c=find&s=1&gub=1&cidx=20";
+
+    fn detail(ix: u32) -> String {
+        format!("{LIST}&gbn=viewok&ix={ix}")
+    }
+
+    #[tokio::test]
+    async fn scrapes_list_and_detail_fixtures() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let list_page = format!("{LIST}&gp=1");
+        let server = MockServer::start(vec![
+            (list_page.as_str(), vec![Reply::ok(include_str!("../tests/fixtures/wevity_list.html"))]),
+            (detail(88001).as_str(), vec![Reply::ok(include_str!("../tests/fixtures/wevity_detail_88001.html"))]),
+            (detail(88002).as_str(), vec![Reply::ok(include_str!("../tests/fixtures/wevity_detail_88002.html"))]),
+            (detail(88003).as_str(), vec![Reply::ok(include_str!("../tests/fixtures/wevity_detail_88003.html"))]),
+        ])
+        .await;
+        let opts = WevityOptions {
+            base_url: server.base.clone(),
+            contest_list_urls: vec![server.url(LIST)],
+            max_pages: 1,
+            ..WevityOptions::default()
+        };
+
+        let mut got = scrape_wevity_contests(&opts, &CollectStats::default()).await.unwrap();
+        got.sort_by(|a, b| a.url.cmp(&b.url));
+        // 88003은 마감(12-31)이 deadline_days(20일) 밖
+        assert_eq!(got.len(), 2, "{got:?}");
+
+        let n = to_notice_from_wevity(&got[0]);
+        assert_eq!(n.url, server.url(&detail(88001)));
+        assert_eq!(n.title, "제5회 AI 데이터 활용 공모전");
+        assert_eq!(n.kind, Kind::Contest);
+        assert_eq!(n.start.as_deref(), Some("2026-10-01"));
+        assert_eq!(n.end.as_deref(), Some("2026-10-24"));
+        assert_eq!(n.organizer.as_deref(), Some("과학기술정보통신부 / 한국데이터산업진흥원"));
+        assert_eq!(n.field.as_deref(), Some("과학/공학, 웹/모바일/IT"));
+        let summary = n.summary.unwrap();
+        assert!(summary.starts_with("공공데이터와 AI 기술을"), "{summary}");
+        assert!(summary.contains("홈페이지에서 & 이메일로"), "{summary}");
+
+        // 기간 입력이 없는 상세는 본문의 "접수기간" 문구에서 추정
+        let n = to_notice_from_wevity(&got[1]);
+        assert_eq!(n.organizer.as_deref(), Some("정보통신산업진흥원"));
+        assert_eq!((n.start.as_deref(), n.end.as_deref()), (Some("2026-10-05"), Some("2026-10-30")));
+
+        // 상세 요청에는 목록 페이지가 Referer로 붙음
+        let req = &server.requests_to("/").into_iter().find(|r| r.target == detail(88001)).unwrap();
+        assert_eq!(req.headers.get("referer").map(String::as_str), Some(server.url(&list_page).as_str()));
+    }
+
+    #[tokio::test]
+    async fn tiny_budget_reports_exhaustion_and_pending_details() {
+        crate::clock::set_today(NaiveDate::from_ymd_opt(2026, 10, 14));
+        let list_page = format!("{LIST}&gp=1");
+        let slow = Reply::ok(include_str!("../tests/fixtures/wevity_detail_88001.html")).delayed(Duration::from_secs(3));
+        let server = MockServer::start(vec![
+            (list_page.as_str(), vec![Reply::ok(include_str!("../tests/fixtures/wevity_list.html"))]),
+            (detail(88001).as_str(), vec![slow.clone()]),
+            (detail(88002).as_str(), vec![slow.clone()]),
+            (detail(88003).as_str(), vec![slow]),
+        ])
+        .await;
+        let opts = WevityOptions {
+            base_url: server.base.clone(),
+            contest_list_urls: vec![server.url(LIST)],
+            max_pages: 3,
+            max_conc: 1,
+            budget_secs: 1,
+            ..WevityOptions::default()
+        };
+
+        let stats = CollectStats::default();
+        let got = scrape_wevity_contests(&opts, &stats).await.unwrap();
+        assert!(got.is_empty());
+        let budget = stats.budget();
+        // 1건은 요청 중에 끊겼고 2건은 시작도 못 함
+        assert!(budget.exhausted);
+        assert_eq!(budget.pending, 3);
+        assert!(budget.detail_ms >= 500, "{budget:?}");
+        assert!(stats.http_requests() >= 1);
+    }
+
+    #[test]
+    fn period_value_and_organizer_cleanup() {
+        assert_eq!(
+            parse_period_value("2026.10.01 ~ 2026.10.24"),
+            (Some("2026-10-01".to_string()), Some("2026-10-24".to_string()))
+        );
+        assert_eq!(parse_period_value(""), (None, None));
+        assert_eq!(clean_organizer(" : ㈜ABC"), "㈜ABC");
+        assert_eq!(organizer_from_row("주최", "주최 : ㈜ABC"), "㈜ABC");
+        assert_eq!(organizer_from_row("주최/주관", "주최/주관\n - ㈜ABC / XYZ재단"), "㈜ABC / XYZ재단");
+        assert_eq!(organizer_from_row("주관", "주관 ：　㈜ABC"), "㈜ABC");
+        assert!(looks_like_bot(reqwest::StatusCode::OK, "<p>Attention Required</p>"));
+    }
+
+    #[test]
+    fn html_text_is_not_decoded_twice() {
+        // "&amp;lt;b&amp;gt;"를 HTML 파서가 한 번 디코딩한 값
+        let doc = Html::parse_fragment("<a>R&amp;D &amp;lt;b&amp;gt; 공모전 - 위비티</a>");
+        let text = norm_text(&doc.root_element().text().collect::<String>());
+        assert_eq!(text, "R&D &lt;b&gt; 공모전 - 위비티");
+        let n = to_notice_from_wevity(&Contest {
+            title: text.clone(),
+            organizer: String::new(),
+            url: "https://www.wevity.com/?c=find&ix=1".into(),
+            start: None,
+            end: None,
+            category: "공모전".into(),
+            field: None,
+            summary: None,
+        });
+        assert_eq!(n.title, "R&D &lt;b&gt; 공모전");
+        assert_eq!(n.raw_title, text);
+    }
+
+    #[test]
+    fn list_url_is_the_category_root() {
+        use crate::source::Crawler;
+        let crawler = WevityCrawler::new(WevityOptions::default(), Duration::from_secs(1));
+        assert_eq!(crawler.list_url(), Some("https://www.wevity.com/?c=find&s=1&gub=1"));
+
+        let urls = |list: &[&str]| list.iter().map(|u| u.to_string()).collect::<Vec<_>>();
+        assert_eq!(category_root(&urls(&["https://www.wevity.com/?c=find&s=1&gub=1&cidx=20"])).as_deref(), Some("https://www.wevity.com/?c=find&s=1&gub=1"));
+        assert_eq!(category_root(&urls(&["https://a.example/?c=find&cidx=1", "https://b.example/?c=find&cidx=2"])), None);
+        assert_eq!(category_root(&[]), None);
+    }
+
+    #[test]
+    fn channel_list_link_puts_the_list_root_in_the_rendered_feed() {
+        use crate::source::Crawler;
+        let crawler = WevityCrawler::new(WevityOptions::default(), Duration::from_secs(1));
+        let notice = Notice {
+            source: Source::Wevity,
+            kind: Kind::Contest,
+            title: "AI 공모전".into(),
+            raw_title: String::new(),
+            url: "https://www.wevity.com/?c=find&s=1&gub=1&gbn=viewok&ix=1".into(),
+            start: None,
+            end: Some("2026-10-24".into()),
+            organizer: None,
+            field: None,
+            end_approx: false,
+            summary: None,
+            series_id: None,
+            changed_at: None,
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let channel_link = |vars: &[(&str, &str)]| {
+            let mut cfg = crate::config::Config::default();
+            cfg.apply_env_from(&|k| vars.iter().find(|(key, _)| *key == k).map(|(_, v)| v.to_string()));
+            let path = dir.path().join("wevity_rss.xml").display().to_string();
+            let link = crawler.channel_link(cfg.output.list_link);
+            crate::rss_write::write_feeds(std::slice::from_ref(&notice), "Wevity", link, "d", &path, &cfg.feed).unwrap();
+            let body = std::fs::read_to_string(&path).unwrap();
+            rss::Channel::read_from(body.as_bytes()).unwrap().link().to_string()
+        };
+        assert_eq!(channel_link(&[("CHANNEL_LIST_LINK", "1")]), "https://www.wevity.com/?c=find&s=1&gub=1");
+        assert_eq!(channel_link(&[]), crawler.site_url());
+    }
+}
@@ -0,0 +1,76 @@
+// tests/library.rs
+//! 라이브러리 공개 API만으로(env/네트워크 없이) 통합 → 피드 쓰기까지 도는지 확인
+use etc_crawler::notice::{self, Kind, Notice, Source};
+use etc_crawler::rss_merged::{merge_notices, MergeSort};
+use etc_crawler::rss_write::{write_rss_feed, FeedOptions};
+
+fn notice(source: Source, title: &str, url: &str, end: &str) -> Notice {
+    Notice {
+        source,
+        kind: Kind::Contest,
+        title: title.to_string(),
+        raw_title: String::new(),
+        url: url.to_string(),
+        start: Some("2099-10-01".to_string()),
+        end: Some(end.to_string()),
+        organizer: Some("한국데이터진흥원".to_string()),
+        field: None,
+        end_approx: false,
+        summary: None,
+        series_id: None,
+        changed_at: None,
+    }
+}
+
+#[test]
+fn merge_and_write_feed_from_in_memory_notices() {
+    let wevity = vec![
+        notice(Source::Wevity, "AI 아이디어 공모전", "https://www.wevity.com/?c=find&gbn=viewok&ix=1", "2099-11-01"),
+        notice(Source::Wevity, "UX 디자인 공모전", "https://www.wevity.com/?c=find&gbn=viewok&ix=2", "2099-11-05"),
+    ];
+    // 추적 파라미터만 다른 같은 URL은 하나로 합쳐짐
+    let dacon = vec![
+        notice(Source::Wevity, "AI 아이디어 공모전", "https://www.wevity.com/?c=find&gbn=viewok&ix=1&utm_source=x", "2099-11-01"),
+        notice(Source::Dacon, "수요 예측 경진대회", "https://dacon.io/competitions/official/1", "2099-10-30"),
+    ];
+    let merged = merge_notices(vec![wevity, dacon], MergeSort::Latest, &notice::default_strip_params());
+    assert_eq!(merged.len(), 3);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("merged.xml");
+    let path = path.to_str().unwrap();
+    write_rss_feed(&merged, "통합", "https://example.com/", "테스트 피드", path, &FeedOptions::default()).unwrap();
+
+    let body = std::fs::read_to_string(path).unwrap();
+    let channel = rss::Channel::read_from(body.as_bytes()).unwrap();
+    assert_eq!(channel.title(), "통합");
+    assert_eq!(channel.items().len(), 3);
+    assert!(channel.items().iter().any(|i| i.link() == Some("https://dacon.io/competitions/official/1")));
+}
+
+#[test]
+fn within_source_duplicate_is_absent_from_per_source_and_merged_feeds() {
+    // main과 같은 순서: 소스별 dedup_by_url → 개별 피드 → 통합
+    let mut wevity = vec![
+        notice(Source::Wevity, "AI 아이디어 공모전", "https://www.wevity.com/?c=find&gbn=viewok&ix=1", "2099-11-01"),
+        notice(Source::Wevity, "AI 아이디어 공모전 (재공고)", "https://www.wevity.com/?c=find&gbn=viewok&ix=1&utm_campaign=x", "2099-11-01"),
+        notice(Source::Wevity, "UX 디자인 공모전", "https://www.wevity.com/?c=find&gbn=viewok&ix=2", "2099-11-05"),
+    ];
+    let strip = notice::default_strip_params();
+    notice::dedup_by_url(&mut wevity, &strip);
+
+    let dir = tempfile::tempdir().unwrap();
+    let source_path = dir.path().join("wevity_rss.xml");
+    let merged_path = dir.path().join("merged_rss.xml");
+    let (source_path, merged_path) = (source_path.to_str().unwrap(), merged_path.to_str().unwrap());
+    write_rss_feed(&wevity, "Wevity", "https://www.wevity.com/", "d", source_path, &FeedOptions::default()).unwrap();
+    let merged = merge_notices(vec![wevity], MergeSort::Latest, &strip);
+    write_rss_feed(&merged, "통합", "https://example.com/", "d", merged_path, &FeedOptions::default()).unwrap();
+
+    for path in [source_path, merged_path] {
+        let channel = rss::Channel::read_from(std::fs::read_to_string(path).unwrap().as_bytes()).unwrap();
+        let titles: Vec<_> = channel.items().iter().filter_map(|i| i.title()).collect();
+        assert_eq!(titles.len(), 2, "{path}: {titles:?}");
+        assert!(!titles.contains(&"AI 아이디어 공모전 (재공고)"), "{path}: {titles:?}");
+    }
+}
@@ -0,0 +1,24 @@
+//! clean_title 말뭉치 회귀 테스트(tests/fixtures/title_corpus.tsv)
+use etc_crawler::notice::{clean_title, Source};
+
+#[test]
+fn title_corpus() {
+    let corpus = include_str!("fixtures/title_corpus.tsv");
+    let mut failures = Vec::new();
+    let mut cases = 0;
+    for (lineno, line) in corpus.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let cols: Vec<&str> = line.split('\t').collect();
+        assert_eq!(cols.len(), 3, "line {}: expected 3 tab-separated columns", lineno + 1);
+        let source: Source = serde_json::from_value(serde_json::Value::from(cols[0])).unwrap();
+        let got = clean_title(cols[1], &source);
+        if got != cols[2] {
+            failures.push(format!("line {}: {:?} → {got:?}, expected {:?}", lineno + 1, cols[1], cols[2]));
+        }
+        cases += 1;
+    }
+    assert!(cases >= 10, "corpus too small: {cases}");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}